@@ -1,7 +1,7 @@
 #[macro_use]
 extern crate criterion;
 use criterion::Criterion;
-use veloxx::series::Series;
+use veloxx::series::{Interpolation, Series};
 
 fn bench_quantile_i32(c: &mut Criterion) {
     let data: Vec<i32> = (0..100_000).map(|x| x % 1000).collect();
@@ -9,7 +9,7 @@ fn bench_quantile_i32(c: &mut Criterion) {
     let series = Series::I32("test".to_string(), data, bitmap);
     c.bench_function("quantile_i32_parallel", |b| {
         b.iter(|| {
-            let _ = series.quantile(0.75);
+            let _ = series.quantile(0.75, Interpolation::Linear);
         })
     });
 }
@@ -20,7 +20,7 @@ fn bench_quantile_f64(c: &mut Criterion) {
     let series = Series::F64("test".to_string(), data, bitmap);
     c.bench_function("quantile_f64_parallel", |b| {
         b.iter(|| {
-            let _ = series.quantile(0.75);
+            let _ = series.quantile(0.75, Interpolation::Linear);
         })
     });
 }
@@ -90,9 +90,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let price_series = df.get_column("price").unwrap();
 
     // Rolling operations on individual series
-    let rolling_mean = price_series.rolling_mean(3)?;
-    let rolling_min = price_series.rolling_min(3)?;
-    let rolling_max = price_series.rolling_max(3)?;
+    let rolling_mean = price_series.rolling_mean(3, 3)?;
+    let rolling_min = price_series.rolling_min(3, 3)?;
+    let rolling_max = price_series.rolling_max(3, 3)?;
     let pct_change = price_series.pct_change()?;
 
     println!("Price Series Analysis:");
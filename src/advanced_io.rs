@@ -540,6 +540,7 @@ impl DatabaseConnector {
             if let Some(series) = dataframe.get_column(column_name) {
                 let sql_type = match series.data_type() {
                     DataType::I32 => "INTEGER",
+                    DataType::I64 => "BIGINT",
                     DataType::F64 => "REAL",
                     DataType::Bool => "BOOLEAN",
                     DataType::String => "TEXT",
@@ -667,6 +668,7 @@ impl AsyncFileOps {
                 let series = dataframe.get_column(col_name).unwrap();
                 let value_str = match series.get_value(i) {
                     Some(crate::types::Value::I32(v)) => v.to_string(),
+                    Some(crate::types::Value::I64(v)) => v.to_string(),
                     Some(crate::types::Value::F64(v)) => v.to_string(),
                     Some(crate::types::Value::Bool(v)) => v.to_string(),
                     Some(crate::types::Value::String(v)) => v,
@@ -761,6 +763,7 @@ impl AsyncFileOps {
 
                 match series.get_value(i) {
                     Some(crate::types::Value::I32(v)) => json_content.push_str(&v.to_string()),
+                    Some(crate::types::Value::I64(v)) => json_content.push_str(&v.to_string()),
                     Some(crate::types::Value::F64(v)) => json_content.push_str(&v.to_string()),
                     Some(crate::types::Value::Bool(v)) => json_content.push_str(&v.to_string()),
                     Some(crate::types::Value::String(v)) => {
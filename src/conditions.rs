@@ -1,6 +1,7 @@
 use crate::dataframe::DataFrame;
 use crate::types::Value;
 use crate::VeloxxError;
+use std::cmp::Ordering;
 
 /// Defines conditions that can be used to filter rows in a `DataFrame`.
 ///
@@ -83,6 +84,21 @@ use crate::VeloxxError;
 /// let condition = Condition::Not(Box::new(Condition::Eq("is_admin".to_string(), Value::Bool(true))));
 /// // This condition can then be used with a DataFrame's filter method.
 /// ```
+///
+/// ## Set Membership
+///
+/// Filter rows where "city" is either "London" or "Paris":
+///
+/// ```rust
+/// use veloxx::conditions::Condition;
+/// use veloxx::types::Value;
+///
+/// let condition = Condition::In(
+///     "city".to_string(),
+///     vec![Value::String("London".to_string()), Value::String("Paris".to_string())],
+/// );
+/// // This condition can then be used with a DataFrame's filter method.
+/// ```
 #[derive(Debug, Clone)]
 pub enum Condition {
     /// Represents an equality comparison (column == value).
@@ -126,6 +142,23 @@ pub enum Condition {
     /// # Arguments
     /// - `Box<Condition>`: The condition to negate.
     Not(Box<Condition>),
+    /// Represents set membership (column value is one of the listed values).
+    ///
+    /// A null cell only matches if `Value::Null` is listed. Every other listed value must have
+    /// the column's data type.
+    ///
+    /// # Arguments
+    /// - `String`: The name of the column to check.
+    /// - `Vec<Value>`: The values to match against.
+    In(String, Vec<Value>),
+    /// Represents the negation of set membership, the exact inverse of `In`.
+    ///
+    /// A null cell is kept unless `Value::Null` is listed.
+    ///
+    /// # Arguments
+    /// - `String`: The name of the column to check.
+    /// - `Vec<Value>`: The values to exclude.
+    NotIn(String, Vec<Value>),
 }
 
 impl Condition {
@@ -133,6 +166,10 @@ impl Condition {
     ///
     /// Returns `true` if the condition is met, `false` otherwise.
     /// Returns an error if a specified column is not found or if types are incomparable.
+    ///
+    /// `Eq`, `Gt` and `Lt` compare numeric values across types, so an `I32` value can be
+    /// compared with an `F64` column (both are promoted to `F64`). `Gt` and `Lt` against a
+    /// non-numeric value of a different type is still an error, and a null cell never matches.
     pub fn evaluate(&self, df: &DataFrame, row_index: usize) -> Result<bool, VeloxxError> {
         match self {
             Condition::Eq(col_name, value) => {
@@ -140,33 +177,18 @@ impl Condition {
                     .get_column(col_name)
                     .ok_or(VeloxxError::ColumnNotFound(col_name.to_string()))?;
                 let cell_value = series.get_value(row_index);
-                Ok(cell_value.as_ref() == Some(value))
+                match cell_value.as_ref() {
+                    Some(cell) if is_numeric(cell) && is_numeric(value) => {
+                        Ok(cell.partial_cmp_numeric(value) == Some(Ordering::Equal))
+                    }
+                    _ => Ok(cell_value.as_ref() == Some(value)),
+                }
             }
             Condition::Gt(col_name, value) => {
-                let series = df
-                    .get_column(col_name)
-                    .ok_or(VeloxxError::ColumnNotFound(col_name.to_string()))?;
-                let cell_value = series.get_value(row_index);
-                match (cell_value.as_ref(), value) {
-                    (Some(Value::I32(a)), Value::I32(b)) => Ok(a > b),
-                    (Some(Value::F64(a)), Value::F64(b)) => Ok(a > b),
-                    _ => Err(VeloxxError::InvalidOperation(format!(
-                        "Cannot compare {cell_value:?} and {value:?}"
-                    ))),
-                }
+                Ok(compare_cell(df, col_name, value, row_index)? == Some(Ordering::Greater))
             }
             Condition::Lt(col_name, value) => {
-                let series = df
-                    .get_column(col_name)
-                    .ok_or(VeloxxError::ColumnNotFound(col_name.to_string()))?;
-                let cell_value = series.get_value(row_index);
-                match (cell_value.as_ref(), value) {
-                    (Some(Value::I32(a)), Value::I32(b)) => Ok(a < b),
-                    (Some(Value::F64(a)), Value::F64(b)) => Ok(a < b),
-                    _ => Err(VeloxxError::InvalidOperation(format!(
-                        "Cannot compare {cell_value:?} and {value:?}"
-                    ))),
-                }
+                Ok(compare_cell(df, col_name, value, row_index)? == Some(Ordering::Less))
             }
             Condition::And(left, right) => {
                 Ok(left.evaluate(df, row_index)? && right.evaluate(df, row_index)?)
@@ -175,6 +197,61 @@ impl Condition {
                 Ok(left.evaluate(df, row_index)? || right.evaluate(df, row_index)?)
             }
             Condition::Not(cond) => Ok(!cond.evaluate(df, row_index)?),
+            Condition::In(col_name, values) => is_member(df, col_name, values, row_index),
+            Condition::NotIn(col_name, values) => Ok(!is_member(df, col_name, values, row_index)?),
         }
     }
 }
+
+/// Checks whether the cell at `row_index` of `col_name` is one of `values`.
+fn is_member(
+    df: &DataFrame,
+    col_name: &str,
+    values: &[Value],
+    row_index: usize,
+) -> Result<bool, VeloxxError> {
+    let series = df
+        .get_column(col_name)
+        .ok_or(VeloxxError::ColumnNotFound(col_name.to_string()))?;
+    let data_type = series.data_type();
+    if let Some(value) = values
+        .iter()
+        .find(|value| **value != Value::Null && value.data_type() != data_type)
+    {
+        return Err(VeloxxError::DataTypeMismatch(format!(
+            "Cannot test membership of {:?} column '{}' against {:?} value {}",
+            data_type,
+            col_name,
+            value.data_type(),
+            value
+        )));
+    }
+    let cell_value = series.get_value(row_index).unwrap_or(Value::Null);
+    Ok(values.contains(&cell_value))
+}
+
+/// Orders the cell at `row_index` of `col_name` against `value` for `Gt` and `Lt`.
+///
+/// Returns `None` for a null cell or a NaN comparison.
+fn compare_cell(
+    df: &DataFrame,
+    col_name: &str,
+    value: &Value,
+    row_index: usize,
+) -> Result<Option<Ordering>, VeloxxError> {
+    let series = df
+        .get_column(col_name)
+        .ok_or(VeloxxError::ColumnNotFound(col_name.to_string()))?;
+    match series.get_value(row_index) {
+        None => Ok(None),
+        Some(cell) if is_numeric(&cell) && is_numeric(value) => Ok(cell.partial_cmp_numeric(value)),
+        Some(cell) => Err(VeloxxError::InvalidOperation(format!(
+            "Cannot compare {cell:?} and {value:?}"
+        ))),
+    }
+}
+
+/// Whether `value` takes part in cross-type numeric comparison.
+fn is_numeric(value: &Value) -> bool {
+    matches!(value, Value::I32(_) | Value::I64(_) | Value::F64(_))
+}
@@ -265,7 +265,7 @@ impl SchemaValidator {
                                     row: Some(i),
                                     error_type: ValidationErrorType::ConstraintViolation,
                                     message: format!(
-                                        "Value {:?} is below minimum {:?}",
+                                        "Value {} is below minimum {}",
                                         value, min_val
                                     ),
                                 });
@@ -282,7 +282,7 @@ impl SchemaValidator {
                                     row: Some(i),
                                     error_type: ValidationErrorType::ConstraintViolation,
                                     message: format!(
-                                        "Value {:?} is above maximum {:?}",
+                                        "Value {} is above maximum {}",
                                         value, max_val
                                     ),
                                 });
@@ -341,7 +341,7 @@ impl SchemaValidator {
                                     column: column_schema.name.clone(),
                                     row: Some(i),
                                     error_type: ValidationErrorType::DuplicateValue,
-                                    message: format!("Duplicate value {:?} found", value),
+                                    message: format!("Duplicate value {} found", value),
                                 });
                             }
                         }
@@ -1,6 +1,54 @@
+use crate::types::DataType;
 use crate::VeloxxError;
 use crate::{dataframe::DataFrame, series::Series, types::Value};
 use indexmap::IndexMap;
+use std::collections::HashSet;
+
+/// Which occurrence of a duplicated row [`DataFrame::drop_duplicates`] keeps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Keep {
+    /// Keep the first occurrence of each row.
+    First,
+    /// Keep the last occurrence of each row.
+    Last,
+}
+
+/// The statistics fitted by [`DataFrame::fit_standardize`], reusable to scale other frames
+/// (such as a test set) exactly like the frame they were fitted on.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Standardization {
+    /// The `(mean, standard deviation)` of each standardized column, in the order given.
+    pub params: IndexMap<String, (f64, f64)>,
+}
+
+impl Standardization {
+    /// Replaces each fitted column of `df` with its z-scores under the fitted statistics,
+    /// leaving other columns untouched and in place.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok(DataFrame)` with the standardized columns,
+    /// `Err(VeloxxError::ColumnNotFound)` if `df` lacks a fitted column, or
+    /// `Err(VeloxxError::Unsupported)` if a fitted column is not numeric in `df`.
+    pub fn transform(&self, df: &DataFrame) -> Result<DataFrame, VeloxxError> {
+        if let Some(missing) = self
+            .params
+            .keys()
+            .find(|name| df.get_column(name).is_none())
+        {
+            return Err(VeloxxError::ColumnNotFound(missing.clone()));
+        }
+        let mut new_columns: IndexMap<String, Series> = IndexMap::new();
+        for (col_name, series) in df.columns.iter() {
+            let new_series = match self.params.get(col_name) {
+                Some(&(mean, std_dev)) => series.zscore_with(mean, std_dev)?,
+                None => series.clone(),
+            };
+            new_columns.insert(col_name.clone(), new_series);
+        }
+        Ok(DataFrame::new(new_columns))
+    }
+}
 
 impl DataFrame {
     /// Removes rows from the `DataFrame` that contain any null values.
@@ -63,6 +111,120 @@ impl DataFrame {
         Ok(DataFrame::new(new_columns))
     }
 
+    /// Removes duplicate rows, comparing only the `subset` columns (all columns if `None`).
+    ///
+    /// Of each set of rows with equal values in the compared columns, only the first or last
+    /// occurrence is kept, as chosen by `keep`. Surviving rows keep their original order.
+    /// Nulls compare equal to each other.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok(DataFrame)` containing the de-duplicated rows, or
+    /// `Err(VeloxxError::ColumnNotFound)` if a `subset` column does not exist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::dataframe::cleaning::Keep;
+    /// use veloxx::series::Series;
+    /// use indexmap::IndexMap;
+    /// use veloxx::types::Value;
+    ///
+    /// let mut columns = IndexMap::new();
+    /// columns.insert("id".to_string(), Series::new_i32("id", vec![Some(1), Some(2), Some(1)]));
+    /// columns.insert("version".to_string(), Series::new_i32("version", vec![Some(1), Some(1), Some(2)]));
+    /// let df = DataFrame::new(columns);
+    ///
+    /// let latest = df.drop_duplicates(Some(&["id".to_string()]), Keep::Last).unwrap();
+    /// assert_eq!(latest.row_count(), 2);
+    /// assert_eq!(latest.get_column("version").unwrap().get_value(1), Some(Value::I32(2)));
+    /// ```
+    pub fn drop_duplicates(
+        &self,
+        subset: Option<&[String]>,
+        keep: Keep,
+    ) -> Result<Self, VeloxxError> {
+        let duplicates = self.duplicate_flags(subset, keep)?;
+        let rows_to_keep: Vec<usize> = (0..self.row_count())
+            .filter(|&row| !duplicates[row])
+            .collect();
+        self.filter_by_indices(&rows_to_keep)
+    }
+
+    /// Marks the rows that repeat an earlier row, comparing only the `subset` columns (all
+    /// columns if `None`).
+    ///
+    /// The first occurrence of each row is `false` and every later occurrence is `true`, so
+    /// the mask can be passed to [`DataFrame::filter_by_mask`] to inspect the duplicates, or
+    /// inverted to keep the rows [`DataFrame::drop_duplicates`] with `Keep::First` would keep.
+    /// Nulls compare equal to each other, as in `drop_duplicates`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok(Series)` containing a Bool series named `"is_duplicate"`, or
+    /// `Err(VeloxxError::ColumnNotFound)` if a `subset` column does not exist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::series::Series;
+    /// use indexmap::IndexMap;
+    /// use veloxx::types::Value;
+    ///
+    /// let mut columns = IndexMap::new();
+    /// columns.insert("id".to_string(), Series::new_i32("id", vec![Some(1), Some(2), Some(1)]));
+    /// let df = DataFrame::new(columns);
+    ///
+    /// let mask = df.duplicate_rows(None).unwrap();
+    /// assert_eq!(mask.get_value(0), Some(Value::Bool(false)));
+    /// assert_eq!(mask.get_value(2), Some(Value::Bool(true)));
+    /// assert_eq!(df.filter_by_mask(&mask).unwrap().row_count(), 1);
+    /// ```
+    pub fn duplicate_rows(&self, subset: Option<&[String]>) -> Result<Series, VeloxxError> {
+        let duplicates = self.duplicate_flags(subset, Keep::First)?;
+        Ok(Series::new_bool(
+            "is_duplicate",
+            duplicates.into_iter().map(Some).collect(),
+        ))
+    }
+
+    /// Flags every row whose `subset` key was already seen, scanning from the end when
+    /// keeping the last occurrence.
+    fn duplicate_flags(
+        &self,
+        subset: Option<&[String]>,
+        keep: Keep,
+    ) -> Result<Vec<bool>, VeloxxError> {
+        let compared: Vec<&Series> = match subset {
+            Some(names) => names
+                .iter()
+                .map(|name| {
+                    self.get_column(name)
+                        .ok_or_else(|| VeloxxError::ColumnNotFound(name.clone()))
+                })
+                .collect::<Result<_, _>>()?,
+            None => self.columns.values().collect(),
+        };
+
+        let row_count = self.row_count();
+        let mut seen: HashSet<Vec<Value>> = HashSet::with_capacity(row_count);
+        let mut duplicates = vec![false; row_count];
+        let rows: Box<dyn Iterator<Item = usize>> = match keep {
+            Keep::First => Box::new(0..row_count),
+            Keep::Last => Box::new((0..row_count).rev()),
+        };
+        for row in rows {
+            let key: Vec<Value> = compared
+                .iter()
+                .map(|series| series.get_value(row).unwrap_or(Value::Null))
+                .collect();
+            duplicates[row] = !seen.insert(key);
+        }
+        Ok(duplicates)
+    }
+
     /// Fills null values in the `DataFrame` with a specified `Value`.
     ///
     /// This method creates a new `DataFrame` where `None` (null) values in each column
@@ -117,6 +279,178 @@ impl DataFrame {
         Ok(DataFrame::new(new_columns))
     }
 
+    /// Replaces each null with the closest non-null value above it in the same column.
+    ///
+    /// Only the `subset` columns are filled (all columns if `None`); columns of every type are
+    /// supported. Nulls before the first non-null value of a column stay null.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok(DataFrame)` containing the filled frame, or
+    /// `Err(VeloxxError::ColumnNotFound)` if a `subset` column does not exist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::series::Series;
+    /// use indexmap::IndexMap;
+    /// use veloxx::types::Value;
+    ///
+    /// let mut columns = IndexMap::new();
+    /// columns.insert("reading".to_string(), Series::new_f64("reading", vec![None, Some(1.5), None]));
+    /// let df = DataFrame::new(columns);
+    ///
+    /// let filled = df.fill_nulls_forward(None).unwrap();
+    /// let reading = filled.get_column("reading").unwrap();
+    /// assert_eq!(reading.get_value(0), None);
+    /// assert_eq!(reading.get_value(2), Some(Value::F64(1.5)));
+    /// ```
+    pub fn fill_nulls_forward(&self, subset: Option<&[String]>) -> Result<Self, VeloxxError> {
+        self.fill_nulls_from_neighbours(subset, false)
+    }
+
+    /// Replaces each null with the closest non-null value below it in the same column.
+    ///
+    /// The mirror image of [`DataFrame::fill_nulls_forward`]: nulls after the last non-null
+    /// value of a column stay null.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok(DataFrame)` containing the filled frame, or
+    /// `Err(VeloxxError::ColumnNotFound)` if a `subset` column does not exist.
+    pub fn fill_nulls_backward(&self, subset: Option<&[String]>) -> Result<Self, VeloxxError> {
+        self.fill_nulls_from_neighbours(subset, true)
+    }
+
+    fn fill_nulls_from_neighbours(
+        &self,
+        subset: Option<&[String]>,
+        backward: bool,
+    ) -> Result<Self, VeloxxError> {
+        if let Some(missing) = subset
+            .unwrap_or_default()
+            .iter()
+            .find(|name| !self.columns.contains_key(*name))
+        {
+            return Err(VeloxxError::ColumnNotFound(missing.clone()));
+        }
+
+        let mut new_columns: IndexMap<String, Series> = IndexMap::new();
+        for (col_name, series) in self.columns.iter() {
+            let selected = subset.is_none_or(|names| names.contains(col_name));
+            let new_series = if selected {
+                carry_over_nulls(series, backward)
+            } else {
+                series.clone()
+            };
+            new_columns.insert(col_name.clone(), new_series);
+        }
+
+        Ok(DataFrame::new(new_columns))
+    }
+
+    /// Clips every numeric column (I32, I64 and F64) to the range `[min, max]`.
+    ///
+    /// Applies [`Series::clip`] to each numeric column and leaves other columns untouched.
+    /// A `None` bound leaves that side open, e.g. `clip_numeric(Some(0.0), None)` floors
+    /// all negative values to zero.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok(DataFrame)` containing the clipped frame, or
+    /// `Err(VeloxxError::InvalidOperation)` if the bounds are invalid for a numeric column.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::series::Series;
+    /// use indexmap::IndexMap;
+    /// use veloxx::types::Value;
+    ///
+    /// let mut columns = IndexMap::new();
+    /// columns.insert("delta".to_string(), Series::new_f64("delta", vec![Some(-1.5), Some(2.0)]));
+    /// columns.insert("label".to_string(), Series::new_string("label", vec![Some("a".to_string()), None]));
+    /// let df = DataFrame::new(columns);
+    ///
+    /// let floored = df.clip_numeric(Some(0.0), None).unwrap();
+    /// assert_eq!(floored.get_column("delta").unwrap().get_value(0), Some(Value::F64(0.0)));
+    /// assert_eq!(floored.get_column("label").unwrap(), df.get_column("label").unwrap());
+    /// ```
+    pub fn clip_numeric(&self, min: Option<f64>, max: Option<f64>) -> Result<Self, VeloxxError> {
+        let mut new_columns: IndexMap<String, Series> = IndexMap::new();
+
+        for (col_name, series) in self.columns.iter() {
+            let new_series = if series.is_numeric() {
+                series.clip(min, max)?
+            } else {
+                series.clone()
+            };
+            new_columns.insert(col_name.clone(), new_series);
+        }
+
+        Ok(DataFrame::new(new_columns))
+    }
+
+    /// Replaces each of `columns` with its z-scores, `(x - mean) / std_dev`.
+    ///
+    /// Every listed column is standardized with [`Series::zscore`], becoming an F64 column
+    /// with mean 0 and sample standard deviation 1. Other columns are untouched and every
+    /// column keeps its position. Use [`DataFrame::fit_standardize`] to also get the fitted
+    /// statistics.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok(DataFrame)` with the standardized columns,
+    /// `Err(VeloxxError::ColumnNotFound)` if a column does not exist,
+    /// `Err(VeloxxError::Unsupported)` if a column is not numeric, or
+    /// `Err(VeloxxError::InvalidOperation)` if a column has fewer than two values or zero
+    /// variance.
+    pub fn standardize(&self, columns: &[&str]) -> Result<Self, VeloxxError> {
+        Ok(self.fit_standardize(columns)?.0)
+    }
+
+    /// Standardizes `columns` like [`DataFrame::standardize`] and returns the fitted
+    /// statistics, so the same transform can be applied to other data with
+    /// [`Standardization::transform`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::series::Series;
+    /// use veloxx::types::Value;
+    /// use indexmap::IndexMap;
+    ///
+    /// let mut train = IndexMap::new();
+    /// train.insert("age".to_string(), Series::new_f64("age", vec![Some(20.0), Some(30.0), Some(40.0)]));
+    /// let train = DataFrame::new(train);
+    ///
+    /// let (scaled, fitted) = train.fit_standardize(&["age"]).unwrap();
+    /// assert_eq!(fitted.params["age"], (30.0, 10.0));
+    /// assert_eq!(scaled.get_column("age").unwrap().get_value(0), Some(Value::F64(-1.0)));
+    ///
+    /// let mut test = IndexMap::new();
+    /// test.insert("age".to_string(), Series::new_f64("age", vec![Some(50.0)]));
+    /// let test = fitted.transform(&DataFrame::new(test)).unwrap();
+    /// assert_eq!(test.get_column("age").unwrap().get_value(0), Some(Value::F64(2.0)));
+    /// ```
+    pub fn fit_standardize(
+        &self,
+        columns: &[&str],
+    ) -> Result<(Self, Standardization), VeloxxError> {
+        let mut params = IndexMap::new();
+        for &col_name in columns {
+            let series = self
+                .get_column(col_name)
+                .ok_or_else(|| VeloxxError::ColumnNotFound(col_name.to_string()))?;
+            params.insert(col_name.to_string(), series.zscore_params()?);
+        }
+        let fitted = Standardization { params };
+        Ok((fitted.transform(self)?, fitted))
+    }
+
     /// Interpolates null values in a specific column using linear interpolation.
     ///
     /// This method performs linear interpolation on null values in the specified column.
@@ -156,4 +490,165 @@ impl DataFrame {
         new_columns.insert(column_name.to_string(), interpolated);
         Ok(DataFrame::new(new_columns))
     }
+
+    /// Merges several columns into one, taking the first non-null value of each row.
+    ///
+    /// For every row, `target` holds the value of the first column in `sources` that is not
+    /// null there, or null when all of them are. The sources must have compatible types: the
+    /// same type, or numeric types that can be widened (`I32` with `I64` to `I64`, any integer
+    /// with `F64` to `F64`), as for [`DataFrame::melt`]. With `drop_sources`, the sources are
+    /// removed and `target` takes the position of the first of them; otherwise `target` is
+    /// appended.
+    ///
+    /// # Arguments
+    ///
+    /// * `sources` - The columns to merge, in order of preference.
+    /// * `target` - The name of the merged column.
+    /// * `drop_sources` - Whether to remove the source columns.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok(DataFrame)` with the merged column,
+    /// `Err(VeloxxError::ColumnNotFound)` if a source column does not exist,
+    /// `Err(VeloxxError::DataTypeMismatch)` if the sources have incompatible types, or
+    /// `Err(VeloxxError::InvalidOperation)` if `sources` is empty or `target` names an existing
+    /// column that is not being dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::series::Series;
+    /// use indexmap::IndexMap;
+    /// use veloxx::types::Value;
+    ///
+    /// let mut columns = IndexMap::new();
+    /// columns.insert("phone_home".to_string(), Series::new_string("phone_home", vec![None, Some("555-1000".to_string())]));
+    /// columns.insert("phone_work".to_string(), Series::new_string("phone_work", vec![Some("555-2000".to_string()), Some("555-2001".to_string())]));
+    /// let df = DataFrame::new(columns);
+    ///
+    /// let merged = df.coalesce_columns(&["phone_home", "phone_work"], "phone", true).unwrap();
+    /// assert_eq!(merged.column_names(), vec!["phone"]);
+    /// let phone = merged.get_column("phone").unwrap();
+    /// assert_eq!(phone.get_value(0), Some(Value::String("555-2000".to_string())));
+    /// assert_eq!(phone.get_value(1), Some(Value::String("555-1000".to_string())));
+    /// ```
+    pub fn coalesce_columns(
+        &self,
+        sources: &[&str],
+        target: &str,
+        drop_sources: bool,
+    ) -> Result<Self, VeloxxError> {
+        if sources.is_empty() {
+            return Err(VeloxxError::InvalidOperation(
+                "coalesce_columns requires at least one source column".to_string(),
+            ));
+        }
+        let source_series = sources
+            .iter()
+            .map(|name| {
+                self.get_column(name)
+                    .ok_or_else(|| VeloxxError::ColumnNotFound(name.to_string()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let dtype = super::pivot::compatible_value_type(&source_series).ok_or_else(|| {
+            VeloxxError::DataTypeMismatch(format!(
+                "Cannot coalesce columns {} of types {:?}",
+                sources.join(", "),
+                source_series
+                    .iter()
+                    .map(|series| series.data_type())
+                    .collect::<Vec<_>>()
+            ))
+        })?;
+        if self.columns.contains_key(target) && !(drop_sources && sources.contains(&target)) {
+            return Err(VeloxxError::InvalidOperation(format!(
+                "Column '{target}' already exists."
+            )));
+        }
+
+        let values: Vec<Option<Value>> = (0..self.row_count())
+            .map(|i| {
+                let value = source_series
+                    .iter()
+                    .find_map(|series| series.get_value(i))?;
+                Some(match (&dtype, value) {
+                    (DataType::F64, Value::I32(n)) => Value::F64(n as f64),
+                    (DataType::F64, Value::I64(n)) => Value::F64(n as f64),
+                    (DataType::I64, Value::I32(n)) => Value::I64(n as i64),
+                    (_, value) => value,
+                })
+            })
+            .collect();
+        let merged = Series::from_values(target, values, dtype)?;
+
+        if !drop_sources {
+            let mut new_columns = self.columns.clone();
+            new_columns.insert(target.to_string(), merged);
+            return Ok(DataFrame::new(new_columns));
+        }
+        let mut new_columns: IndexMap<String, Series> = IndexMap::new();
+        let mut merged = Some(merged);
+        for (name, series) in &self.columns {
+            if !sources.contains(&name.as_str()) {
+                new_columns.insert(name.clone(), series.clone());
+            } else if let Some(merged) = merged.take() {
+                new_columns.insert(target.to_string(), merged);
+            }
+        }
+        Ok(DataFrame::new(new_columns))
+    }
+}
+
+/// Copies the nearest earlier (or, when `backward`, later) non-null value into each null.
+fn carry_over_nulls(series: &Series, backward: bool) -> Series {
+    fn fill<T: Clone>(data: &[T], validity: &[bool], backward: bool) -> (Vec<T>, Vec<bool>) {
+        let mut data = data.to_vec();
+        let mut validity = validity.to_vec();
+        if backward {
+            data.reverse();
+            validity.reverse();
+        }
+        let mut last_valid: Option<T> = None;
+        for (value, is_valid) in data.iter_mut().zip(validity.iter_mut()) {
+            if *is_valid {
+                last_valid = Some(value.clone());
+            } else if let Some(fill) = &last_valid {
+                *value = fill.clone();
+                *is_valid = true;
+            }
+        }
+        if backward {
+            data.reverse();
+            validity.reverse();
+        }
+        (data, validity)
+    }
+
+    match series {
+        Series::I32(name, data, validity) => {
+            let (data, validity) = fill(data, validity, backward);
+            Series::I32(name.clone(), data, validity)
+        }
+        Series::I64(name, data, validity) => {
+            let (data, validity) = fill(data, validity, backward);
+            Series::I64(name.clone(), data, validity)
+        }
+        Series::F64(name, data, validity) => {
+            let (data, validity) = fill(data, validity, backward);
+            Series::F64(name.clone(), data, validity)
+        }
+        Series::Bool(name, data, validity) => {
+            let (data, validity) = fill(data, validity, backward);
+            Series::Bool(name.clone(), data, validity)
+        }
+        Series::String(name, data, validity) => {
+            let (data, validity) = fill(data, validity, backward);
+            Series::String(name.clone(), data, validity)
+        }
+        Series::DateTime(name, data, validity) => {
+            let (data, validity) = fill(data, validity, backward);
+            Series::DateTime(name.clone(), data, validity)
+        }
+    }
 }
@@ -0,0 +1,146 @@
+use crate::dataframe::DataFrame;
+use crate::types::Value;
+use crate::VeloxxError;
+use indexmap::IndexMap;
+
+/// The differences between two snapshots of a `DataFrame`, keyed by one or more columns.
+///
+/// Produced by [`DataFrame::diff_against`].
+#[derive(Debug, Clone)]
+pub struct ChangeSet {
+    /// Rows of the new frame whose key does not appear in the old frame.
+    pub added: DataFrame,
+    /// Rows of the old frame whose key does not appear in the new frame.
+    pub removed: DataFrame,
+    /// Rows of the new frame whose key appears in both frames but whose values differ.
+    pub changed: DataFrame,
+    /// For each row of `changed`, the names of the columns whose values differ.
+    pub changed_columns: Vec<Vec<String>>,
+}
+
+impl DataFrame {
+    /// Compares this `DataFrame` against an older snapshot, matching rows by `key`.
+    ///
+    /// Every key is classified as added (only in `self`), removed (only in `old`) or changed
+    /// (in both, with different values). Changes are detected with [`DataFrame::row_hashes`]
+    /// over the non-key columns present in both frames, and the differing columns of each
+    /// changed row are listed in [`ChangeSet::changed_columns`].
+    ///
+    /// # Arguments
+    ///
+    /// * `old` - The previous snapshot.
+    /// * `key` - The columns that uniquely identify a row in both frames.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok(ChangeSet)` describing the differences,
+    /// `Err(VeloxxError::ColumnNotFound)` if a key column is missing from either frame, or
+    /// `Err(VeloxxError::InvalidOperation)` if a key is duplicated within a frame.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::series::Series;
+    /// use indexmap::IndexMap;
+    ///
+    /// let mut old_cols = IndexMap::new();
+    /// old_cols.insert("id".to_string(), Series::new_i32("id", vec![Some(1), Some(2)]));
+    /// old_cols.insert("price".to_string(), Series::new_f64("price", vec![Some(9.5), Some(3.0)]));
+    /// let old = DataFrame::new(old_cols);
+    ///
+    /// let mut new_cols = IndexMap::new();
+    /// new_cols.insert("id".to_string(), Series::new_i32("id", vec![Some(2), Some(3)]));
+    /// new_cols.insert("price".to_string(), Series::new_f64("price", vec![Some(3.5), Some(1.0)]));
+    /// let new = DataFrame::new(new_cols);
+    ///
+    /// let changes = new.diff_against(&old, &["id"]).unwrap();
+    /// assert_eq!(changes.added.row_count(), 1);
+    /// assert_eq!(changes.removed.row_count(), 1);
+    /// assert_eq!(changes.changed_columns, vec![vec!["price".to_string()]]);
+    /// ```
+    pub fn diff_against(&self, old: &DataFrame, key: &[&str]) -> Result<ChangeSet, VeloxxError> {
+        let compared: Vec<&str> = self
+            .columns
+            .keys()
+            .map(|name| name.as_str())
+            .filter(|name| !key.contains(name) && old.get_column(name).is_some())
+            .collect();
+
+        let new_index = key_index(self, key)?;
+        let old_index = key_index(old, key)?;
+        let new_hashes = self.row_hashes(Some(compared.as_slice()))?;
+        let old_hashes = old.row_hashes(Some(compared.as_slice()))?;
+
+        let mut added = Vec::new();
+        let mut changed = Vec::new();
+        let mut changed_columns = Vec::new();
+        for (key_values, &new_row) in &new_index {
+            let Some(&old_row) = old_index.get(key_values) else {
+                added.push(new_row);
+                continue;
+            };
+            if new_hashes.get_value(new_row) == old_hashes.get_value(old_row) {
+                continue;
+            }
+            let differing: Vec<String> = compared
+                .iter()
+                .filter(|name| {
+                    let new_value = self.get_column(name).and_then(|s| s.get_value(new_row));
+                    let old_value = old.get_column(name).and_then(|s| s.get_value(old_row));
+                    new_value != old_value
+                })
+                .map(|name| name.to_string())
+                .collect();
+            changed.push(new_row);
+            changed_columns.push(differing);
+        }
+        let removed: Vec<usize> = old_index
+            .iter()
+            .filter(|(key_values, _)| !new_index.contains_key(*key_values))
+            .map(|(_, &row)| row)
+            .collect();
+
+        Ok(ChangeSet {
+            added: take_rows(self, &added)?,
+            removed: take_rows(old, &removed)?,
+            changed: take_rows(self, &changed)?,
+            changed_columns,
+        })
+    }
+}
+
+/// Maps each key to its row, rejecting duplicate keys.
+fn key_index(df: &DataFrame, key: &[&str]) -> Result<IndexMap<Vec<Value>, usize>, VeloxxError> {
+    let key_series = key
+        .iter()
+        .map(|name| {
+            df.get_column(name)
+                .ok_or_else(|| VeloxxError::ColumnNotFound(name.to_string()))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut index = IndexMap::with_capacity(df.row_count());
+    for row in 0..df.row_count() {
+        let key_values: Vec<Value> = key_series
+            .iter()
+            .map(|series| series.get_value(row).unwrap_or(Value::Null))
+            .collect();
+        if index.insert(key_values, row).is_some() {
+            return Err(VeloxxError::InvalidOperation(format!(
+                "Duplicate key at row {} in diff",
+                row
+            )));
+        }
+    }
+    Ok(index)
+}
+
+/// Gathers `rows` from every column, keeping the schema even when `rows` is empty.
+fn take_rows(df: &DataFrame, rows: &[usize]) -> Result<DataFrame, VeloxxError> {
+    let mut columns = IndexMap::new();
+    for (name, series) in &df.columns {
+        columns.insert(name.clone(), series.filter(rows)?);
+    }
+    Ok(DataFrame::new(columns))
+}
@@ -59,6 +59,7 @@ impl fmt::Display for DataFrame {
                 let series = self.columns.get(*name).unwrap();
                 let value_str = match series {
                     Series::I32(_, v, _) => v.get(i).map_or("null".to_string(), |i| i.to_string()),
+                    Series::I64(_, v, _) => v.get(i).map_or("null".to_string(), |i| i.to_string()),
                     Series::F64(_, v, _) => v.get(i).map_or("null".to_string(), |f| f.to_string()),
                     Series::Bool(_, v, _) => v.get(i).map_or("null".to_string(), |b| b.to_string()),
                     Series::String(_, v, _) => v.get(i).map_or("null".to_string(), |s| s.clone()),
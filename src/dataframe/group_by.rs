@@ -88,7 +88,12 @@ fn simd_max_f64(values: &[f64]) -> f64 {
 use crate::performance::simd_eq_str;
 #[cfg(not(all(feature = "simd", not(target_arch = "wasm32"))))]
 use crate::performance::simd_string::simd_eq_str;
-use crate::{dataframe::DataFrame, series::Series, types::Value, VeloxxError};
+use crate::{
+    dataframe::DataFrame,
+    series::Series,
+    types::{DataType, Value},
+    VeloxxError,
+};
 // use bincode::{config, decode_from_slice, encode_to_vec};
 use indexmap::IndexMap;
 use std::collections::HashMap;
@@ -130,8 +135,7 @@ struct DenseGroupByParams<'a> {
 pub struct GroupedDataFrame<'a> {
     dataframe: &'a DataFrame,
     group_columns: Vec<String>,
-    // Use contiguous Vecs for group storage for cache locality
-    group_keys: Vec<Vec<String>>,   // direct keys
+    // Use a contiguous Vec for group storage for cache locality
     group_indices: Vec<Vec<usize>>, // row indices for each group
 }
 
@@ -204,7 +208,7 @@ impl<'a> GroupedDataFrame<'a> {
                             }
                         }
                         _ => {
-                            key.push(format!("{:?}", series.get_value(i).unwrap_or(Value::Null)));
+                            key.push(series.get_value(i).unwrap_or(Value::Null).to_string());
                         }
                     }
                 }
@@ -236,39 +240,90 @@ impl<'a> GroupedDataFrame<'a> {
             }
         }
 
-        let mut group_keys = Vec::with_capacity(groups.len());
-        let mut group_indices = Vec::with_capacity(groups.len());
-        for (k, v) in groups.into_iter() {
-            group_keys.push(k); // Direct key representation
-            group_indices.push(v);
-        }
+        let group_indices: Vec<Vec<usize>> = groups.into_values().collect();
         Ok(GroupedDataFrame {
             dataframe,
             group_columns,
-            group_keys,
             group_indices,
         })
     }
 
+    /// Iterates over the groups, yielding each group's key and its rows as a `DataFrame`.
+    ///
+    /// The key holds one `Value` per grouping column (`Value::Null` for null keys). Each
+    /// sub-frame is built with `DataFrame::filter_by_indices` from the precomputed group
+    /// index, so rows keep their original relative order. As with `agg`, the order of the
+    /// groups themselves is unspecified.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::series::Series;
+    /// use indexmap::IndexMap;
+    ///
+    /// let mut columns = IndexMap::new();
+    /// columns.insert("city".to_string(), Series::new_string("city", vec![Some("Paris".to_string()), Some("Oslo".to_string()), Some("Paris".to_string())]));
+    /// columns.insert("sales".to_string(), Series::new_f64("sales", vec![Some(100.0), Some(150.0), Some(200.0)]));
+    /// let df = DataFrame::new(columns);
+    ///
+    /// let grouped_df = df.group_by(vec!["city".to_string()]).unwrap();
+    /// for (key, group) in grouped_df.iter_groups() {
+    ///     println!("{:?}: {} rows", key, group.row_count());
+    /// }
+    /// ```
+    pub fn iter_groups(&self) -> impl Iterator<Item = (Vec<Value>, DataFrame)> + '_ {
+        self.group_indices.iter().map(move |indices| {
+            let key = self
+                .group_columns
+                .iter()
+                .map(|name| {
+                    self.dataframe
+                        .get_column(name)
+                        .and_then(|series| series.get_value(indices[0]))
+                        .unwrap_or(Value::Null)
+                })
+                .collect();
+            let group = self
+                .dataframe
+                .filter_by_indices(indices)
+                .expect("group indices are within the grouped DataFrame");
+            (key, group)
+        })
+    }
+
     /// Performs aggregation operations on the grouped data.
     ///
     /// This method takes a list of aggregation instructions, where each instruction specifies
     /// a column to aggregate and the aggregation function to apply (e.g., "sum", "mean", "count",
-    /// "min", "max", "median", "std_dev"). It returns a new `DataFrame` where each row represents
-    /// a unique group, and the aggregated values form new columns.
+    /// "min", "max", "median", "std", "var", "nunique", "first", "last"). It returns a new
+    /// `DataFrame` where each row represents a unique group, and the aggregated values form
+    /// new columns.
+    ///
+    /// Each aggregation produces a column named `{column}_{function}`, so the same column can
+    /// be aggregated several ways at once, e.g. `("sales", "sum")` and `("sales", "mean")`
+    /// give `sales_sum` and `sales_mean`. The groups are computed once and shared by every
+    /// aggregation.
+    ///
+    /// `mean`, `median`, `std` and `var` always produce F64 columns. `std` and `var` are the
+    /// sample statistics, so a group with fewer than two non-null values gets a null.
+    /// `nunique` counts the distinct non-null values of a column of any type as an I32.
+    /// `first` and `last` take the first and last non-null value of each group in original
+    /// row order, keeping the column's type; a group of nulls gets a null.
     ///
     /// # Arguments
     ///
     /// * `aggregations` - A `Vec` of tuples, where each tuple contains:
     ///   - `&str`: The name of the column on which to perform the aggregation.
-    ///   - `&str`: The aggregation function to apply (e.g., "sum", "mean", "count", "min", "max", "median", "std_dev").
+    ///   - `&str`: The aggregation function to apply (e.g., "sum", "mean", "count", "min", "max", "median", "std", "var", "nunique", "first", "last").
     ///
     /// # Returns
     ///
     /// A `Result` which is `Ok(DataFrame)` containing a new `DataFrame` with the aggregated results,
     /// or `Err(VeloxxError::ColumnNotFound)` if an aggregation column does not exist,
     /// or `Err(VeloxxError::Unsupported)` if an unsupported aggregation function is specified,
-    /// or `Err(VeloxxError::InvalidOperation)` if there's an issue with key deserialization.
+    /// or `Err(VeloxxError::InvalidOperation)` if two aggregations would produce the same
+    /// output column, or one would clash with a group column.
     ///
     /// # Examples
     ///
@@ -301,6 +356,17 @@ impl<'a> GroupedDataFrame<'a> {
     /// // New York       300.00          15.00          2              
     /// ```
     pub fn agg(&self, aggregations: Vec<(&str, &str)>) -> Result<DataFrame, VeloxxError> {
+        let mut output_names: std::collections::HashSet<String> =
+            self.group_columns.iter().cloned().collect();
+        for (col_name, agg_func) in &aggregations {
+            let name = format!("{col_name}_{agg_func}");
+            if !output_names.insert(name.clone()) {
+                return Err(VeloxxError::InvalidOperation(format!(
+                    "Aggregation output column '{name}' is produced more than once or clashes with a group column"
+                )));
+            }
+        }
+
         // Try the super-fast path that avoids GroupedDataFrame creation entirely
         // This should only be reached if we're already in a GroupedDataFrame, which means
         // the expensive setup already happened. In that case, use our existing fast path.
@@ -643,28 +709,17 @@ impl<'a> GroupedDataFrame<'a> {
         }
         use rayon::prelude::*;
         let mut new_columns: IndexMap<String, Series> = IndexMap::new();
-        // Use direct Vec<String> group keys
-        let group_keys: &Vec<Vec<String>> = &self.group_keys;
 
         // Add group columns to new_columns
         for col_name in self.group_columns.iter() {
             let original_series = self.dataframe.get_column(col_name).unwrap();
-            let col_idx = self
-                .group_columns
+            // Keys are stored as display strings, so take each group's typed key from its
+            // first row instead of parsing them back.
+            let data_for_new_series: Vec<Option<Value>> = self
+                .group_indices
                 .iter()
-                .position(|x| x == col_name)
-                .unwrap();
-            let mut data_for_new_series: Vec<Option<Value>> =
-                Vec::with_capacity(self.group_keys.len());
-            for key in self.group_keys.iter() {
-                // All keys are Vec<String>, so wrap as Value::String
-                let v = if key[col_idx] == "<NULL>" {
-                    None
-                } else {
-                    Some(Value::String(key[col_idx].clone()))
-                };
-                data_for_new_series.push(v);
-            }
+                .map(|indices| original_series.get_value(indices[0]))
+                .collect();
             let new_series = match original_series.data_type() {
                 crate::types::DataType::I32 => Series::new_i32(
                     col_name,
@@ -681,6 +736,21 @@ impl<'a> GroupedDataFrame<'a> {
                         })
                         .collect(),
                 ),
+                crate::types::DataType::I64 => Series::new_i64(
+                    col_name,
+                    data_for_new_series
+                        .into_iter()
+                        .map(|x| {
+                            x.and_then(|v| {
+                                if let Value::I64(val) = v {
+                                    Some(val)
+                                } else {
+                                    None
+                                }
+                            })
+                        })
+                        .collect(),
+                ),
                 crate::types::DataType::F64 => Series::new_f64(
                     col_name,
                     data_for_new_series
@@ -751,49 +821,104 @@ impl<'a> GroupedDataFrame<'a> {
                 .get_column(col_name)
                 .ok_or(VeloxxError::ColumnNotFound(col_name.to_string()))?;
 
-            // Parallel aggregation for each group
-            let aggregated_data: Vec<Option<Value>> = group_keys
+            // Parallel aggregation for each group, reusing the row indices found when grouping
+            let aggregated_data: Vec<Option<Value>> = self
+                .group_indices
                 .par_iter()
-                .map(|key| {
-                    // Find the index of this key in self.group_keys using direct comparison
-                    let key_idx = self.group_keys.iter().position(|k| k == key)?;
-                    let row_indices = &self.group_indices[key_idx];
-                    match original_series.data_type() {
-                        crate::types::DataType::I32 => {
-                            let values: Vec<i32> = row_indices
-                                .iter()
-                                .filter_map(|&i| original_series.get_i32(i))
-                                .collect();
-                            match agg_func {
-                                "sum" => Some(Value::I32(simd_sum_i32(&values))),
-                                "mean" => Some(Value::F64(simd_mean_i32(&values))),
-                                "min" => Some(Value::I32(simd_min_i32(&values))),
-                                "max" => Some(Value::I32(simd_max_i32(&values))),
-                                "count" => Some(Value::I32(values.len() as i32)),
-                                _ => None,
-                            }
+                .map(|row_indices| -> Result<Option<Value>, VeloxxError> {
+                    Ok(match agg_func {
+                        // Null for groups the statistic is undefined on, e.g. std of one value
+                        "median" | "std" | "var" => {
+                            original_series.filter(row_indices).ok().and_then(|group| {
+                                let stat = match agg_func {
+                                    "median" => group.median(),
+                                    "std" => group.std_dev(),
+                                    _ => group.variance(),
+                                };
+                                stat.ok()
+                            })
                         }
-                        crate::types::DataType::F64 => {
-                            let values: Vec<f64> = row_indices
+                        // Group indices are in row order, so these follow the original frame
+                        "first" => row_indices
+                            .iter()
+                            .find_map(|&i| original_series.get_value(i)),
+                        "last" => row_indices
+                            .iter()
+                            .rev()
+                            .find_map(|&i| original_series.get_value(i)),
+                        "nunique" => {
+                            let distinct: std::collections::HashSet<Value> = row_indices
                                 .iter()
-                                .filter_map(|&i| original_series.get_f64(i))
+                                .filter_map(|&i| original_series.get_value(i))
                                 .collect();
-                            match agg_func {
-                                "sum" => Some(Value::F64(simd_sum_f64(&values))),
-                                "mean" => Some(Value::F64(simd_mean_f64(&values))),
-                                "min" => Some(Value::F64(simd_min_f64(&values))),
-                                "max" => Some(Value::F64(simd_max_f64(&values))),
-                                "count" => Some(Value::I32(values.len() as i32)),
-                                _ => None,
-                            }
+                            Some(Value::I32(distinct.len() as i32))
                         }
-                        _ => None,
-                    }
+                        _ => match original_series.data_type() {
+                            crate::types::DataType::I32 => {
+                                let values: Vec<i32> = row_indices
+                                    .iter()
+                                    .filter_map(|&i| original_series.get_i32(i))
+                                    .collect();
+                                match agg_func {
+                                    "sum" => Some(Value::I32(simd_sum_i32(&values))),
+                                    "mean" => Some(Value::F64(simd_mean_i32(&values))),
+                                    "min" => Some(Value::I32(simd_min_i32(&values))),
+                                    "max" => Some(Value::I32(simd_max_i32(&values))),
+                                    "count" => Some(Value::I32(values.len() as i32)),
+                                    _ => None,
+                                }
+                            }
+                            crate::types::DataType::I64 => {
+                                let values: Vec<i64> = row_indices
+                                    .iter()
+                                    .filter_map(|&i| original_series.get_i64(i))
+                                    .collect();
+                                match agg_func {
+                                    "sum" => Some(
+                                        values
+                                            .iter()
+                                            .try_fold(0i64, |acc, &v| acc.checked_add(v))
+                                            .map(Value::I64)
+                                            .ok_or_else(|| {
+                                                VeloxxError::InvalidOperation(format!(
+                                                "Sum of column '{col_name}' overflows the I64 range"
+                                            ))
+                                            })?,
+                                    ),
+                                    "mean" if !values.is_empty() => Some(Value::F64(
+                                        values.iter().map(|&v| v as f64).sum::<f64>()
+                                            / values.len() as f64,
+                                    )),
+                                    "min" => values.iter().min().copied().map(Value::I64),
+                                    "max" => values.iter().max().copied().map(Value::I64),
+                                    "count" => Some(Value::I32(values.len() as i32)),
+                                    _ => None,
+                                }
+                            }
+                            crate::types::DataType::F64 => {
+                                let values: Vec<f64> = row_indices
+                                    .iter()
+                                    .filter_map(|&i| original_series.get_f64(i))
+                                    .collect();
+                                match agg_func {
+                                    "sum" => Some(Value::F64(simd_sum_f64(&values))),
+                                    "mean" => Some(Value::F64(simd_mean_f64(&values))),
+                                    "min" => Some(Value::F64(simd_min_f64(&values))),
+                                    "max" => Some(Value::F64(simd_max_f64(&values))),
+                                    "count" => Some(Value::I32(values.len() as i32)),
+                                    _ => None,
+                                }
+                            }
+                            _ => None,
+                        },
+                    })
                 })
-                .collect();
+                .collect::<Result<_, _>>()?;
 
             let new_series_name = format!("{col_name}_{agg_func}");
-            let new_series = if agg_func == "mean" {
+            let new_series = if matches!(agg_func, "count" | "nunique") {
+                Series::from_values(&new_series_name, aggregated_data, DataType::I32)?
+            } else if matches!(agg_func, "mean" | "median" | "std" | "var") {
                 Series::new_f64(
                     &new_series_name,
                     aggregated_data
@@ -826,6 +951,21 @@ impl<'a> GroupedDataFrame<'a> {
                             })
                             .collect(),
                     ),
+                    crate::types::DataType::I64 => Series::new_i64(
+                        &new_series_name,
+                        aggregated_data
+                            .into_iter()
+                            .map(|x| {
+                                x.and_then(|v| {
+                                    if let Value::I64(val) = v {
+                                        Some(val)
+                                    } else {
+                                        None
+                                    }
+                                })
+                            })
+                            .collect(),
+                    ),
                     crate::types::DataType::F64 => Series::new_f64(
                         &new_series_name,
                         aggregated_data
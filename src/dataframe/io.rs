@@ -1,10 +1,60 @@
 use crate::dataframe::DataFrame;
 use crate::series::Series;
+use crate::types::{DataType, Value};
 use crate::VeloxxError;
 use csv_core::{ReadFieldResult, Reader};
 use indexmap::IndexMap;
 use microjson::JSONValue;
-use std::io::Read;
+use std::io::{BufRead, BufReader, BufWriter, Read, Write};
+
+/// Formatting options for [`DataFrame::to_csv_with_options`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CsvWriteOptions {
+    /// Field delimiter (default: comma)
+    delimiter: u8,
+    /// Quote character (default: double quote)
+    quote: u8,
+}
+
+impl Default for CsvWriteOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: b',',
+            quote: b'"',
+        }
+    }
+}
+
+impl CsvWriteOptions {
+    /// Create write options with the RFC 4180 defaults
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the field delimiter
+    pub fn delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Set quote character
+    pub fn quote(mut self, quote: u8) -> Self {
+        self.quote = quote;
+        self
+    }
+
+    /// Quotes `field` if it contains the delimiter, the quote character or a line break,
+    /// doubling any embedded quote characters.
+    fn escape_field(&self, field: &str) -> String {
+        let delimiter = self.delimiter as char;
+        let quote = self.quote as char;
+        if !field.contains([delimiter, quote, '\n', '\r']) {
+            return field.to_string();
+        }
+        let escaped = field.replace(quote, &format!("{}{}", quote, quote));
+        format!("{}{}{}", quote, escaped, quote)
+    }
+}
 
 impl DataFrame {
     #[cfg(all(feature = "arrow", not(target_arch = "wasm32")))]
@@ -38,11 +88,46 @@ impl DataFrame {
             "Parquet support requires advanced_io and arrow features on native targets".to_string(),
         ))
     }
+
+    /// Reads a CSV file with a header row into a `DataFrame`, inferring column types.
+    ///
+    /// This opens `path` and parses it with [`DataFrame::read_csv`], so the quoting, null and
+    /// row-length rules are the same.
     pub fn from_csv(path: &str) -> Result<Self, VeloxxError> {
-        let mut file = std::fs::File::open(path).map_err(|e| VeloxxError::FileIO(e.to_string()))?;
+        let file = std::fs::File::open(path)?;
+        Self::read_csv(file)
+    }
+
+    /// Reads CSV data with a header row from any reader into a `DataFrame`, inferring column
+    /// types.
+    ///
+    /// The reader can be a file, stdin, a decompressor or an in-memory `&[u8]`. Fields follow
+    /// RFC 4180 quoting: a quoted field may contain commas, line breaks and doubled quotes
+    /// (`""`), so `"Smith, John"` is read as the single value `Smith, John`. Empty fields
+    /// become nulls, and every row must have as many fields as the header.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok(DataFrame)` with the parsed data, `Err(VeloxxError::Io)` if
+    /// reading fails, or `Err(VeloxxError::Parsing)` if the input is not valid UTF-8 or a row
+    /// has the wrong number of fields.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::types::Value;
+    ///
+    /// let data = "id,name\n1,\"Smith, John\"\n2,\n";
+    /// let df = DataFrame::read_csv(data.as_bytes()).unwrap();
+    /// assert_eq!(df.row_count(), 2);
+    /// let name = df.get_column("name").unwrap();
+    /// assert_eq!(name.get_value(0), Some(Value::String("Smith, John".to_string())));
+    /// assert_eq!(name.get_value(1), None);
+    /// ```
+    pub fn read_csv<R: Read>(mut reader: R) -> Result<Self, VeloxxError> {
         let mut contents = Vec::new();
-        file.read_to_end(&mut contents)
-            .map_err(|e| VeloxxError::FileIO(e.to_string()))?;
+        reader.read_to_end(&mut contents)?;
 
         let mut trimmed_bytes = contents.as_slice();
         if let Some(i) = trimmed_bytes
@@ -57,7 +142,7 @@ impl DataFrame {
         }
 
         let mut rdr = Reader::new();
-        let mut field_buf = [0; 8192]; // Buffer for a single field
+        let mut field_buf = [0; 8192]; // Buffer for a chunk of a single field
 
         let mut column_names: Vec<String> = Vec::new();
         let mut all_rows_as_strings: Vec<Vec<String>> = Vec::new();
@@ -66,52 +151,38 @@ impl DataFrame {
         let mut bytes = trimmed_bytes;
         let mut is_header = true;
 
+        let mut field_bytes: Vec<u8> = Vec::new();
+
         loop {
             let (result, bytes_consumed, bytes_written) = rdr.read_field(bytes, &mut field_buf);
 
-            let field_str = String::from_utf8(field_buf[..bytes_written].to_vec())
-                .map_err(|e| VeloxxError::Parsing(e.to_string()))?;
-            current_row_fields.push(field_str);
-
+            field_bytes.extend_from_slice(&field_buf[..bytes_written]);
             bytes = &bytes[bytes_consumed..];
 
             match result {
                 ReadFieldResult::InputEmpty => {
-                    if !current_row_fields.is_empty() {
-                        if is_header {
-                            column_names = current_row_fields.clone();
-                        } else {
-                            all_rows_as_strings.push(current_row_fields.clone());
-                        }
-                    }
-                    break;
+                    // All input is consumed; the next call with an empty slice signals end of
+                    // input and flushes the final field and record.
+                    continue;
                 }
                 ReadFieldResult::OutputFull => {
-                    return Err(VeloxxError::Parsing(
-                        "CSV field too large for buffer.".to_string(),
-                    ));
+                    // Long (typically quoted, multi-line) fields span several buffer fills.
+                    continue;
                 }
                 ReadFieldResult::Field { record_end } => {
+                    let field_str = String::from_utf8(std::mem::take(&mut field_bytes))
+                        .map_err(|e| VeloxxError::Parsing(e.to_string()))?;
+                    current_row_fields.push(field_str);
                     if record_end {
                         if is_header {
-                            column_names = current_row_fields.clone();
+                            column_names = std::mem::take(&mut current_row_fields);
                             is_header = false;
                         } else {
-                            all_rows_as_strings.push(current_row_fields.clone());
+                            all_rows_as_strings.push(std::mem::take(&mut current_row_fields));
                         }
-                        current_row_fields.clear();
                     }
                 }
-                ReadFieldResult::End => {
-                    if !current_row_fields.is_empty() {
-                        if is_header {
-                            column_names = current_row_fields.clone();
-                        } else {
-                            all_rows_as_strings.push(current_row_fields.clone());
-                        }
-                    }
-                    break;
-                }
+                ReadFieldResult::End => break,
             }
         }
 
@@ -146,6 +217,117 @@ impl DataFrame {
         DataFrame::from_vec_of_vec(data_rows, header)
     }
 
+    /// Streams a CSV file with a header row through `f` in `DataFrame` chunks.
+    ///
+    /// The file is read incrementally, so only one chunk of at most `chunk_rows` rows is held in
+    /// memory at a time, which makes this suitable for files larger than memory. Fields follow
+    /// the same quoting and null rules as [`DataFrame::from_csv`]. Column types are inferred
+    /// from the first chunk and every later chunk is parsed with that schema, so all chunks
+    /// have the same column types. A file with no data rows produces no chunks.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the CSV file.
+    /// * `chunk_rows` - The maximum number of rows per chunk, at least 1.
+    /// * `f` - Called with each chunk in file order; an error stops reading and is returned.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok(())` once every chunk has been processed,
+    /// `Err(VeloxxError::InvalidOperation)` if `chunk_rows` is 0, `Err(VeloxxError::Io)` if
+    /// the file cannot be read, `Err(VeloxxError::Parsing)` if a row has the wrong number of
+    /// fields or a value does not match the schema of the first chunk, or the first error
+    /// returned by `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use veloxx::dataframe::DataFrame;
+    ///
+    /// let mut total_rows = 0;
+    /// DataFrame::from_csv_chunked("large.csv", 10_000, |chunk| {
+    ///     total_rows += chunk.row_count();
+    ///     Ok(())
+    /// })
+    /// .unwrap();
+    /// ```
+    pub fn from_csv_chunked(
+        path: &str,
+        chunk_rows: usize,
+        mut f: impl FnMut(DataFrame) -> Result<(), VeloxxError>,
+    ) -> Result<(), VeloxxError> {
+        if chunk_rows == 0 {
+            return Err(VeloxxError::InvalidOperation(
+                "CSV chunk size must be at least 1 row".to_string(),
+            ));
+        }
+        let file = std::fs::File::open(path)?;
+        let mut input = BufReader::new(file);
+
+        let mut rdr = Reader::new();
+        let mut field_buf = [0; 8192];
+        let mut field_bytes: Vec<u8> = Vec::new();
+        let mut current_row_fields: Vec<String> = Vec::new();
+
+        let mut header: Option<Vec<String>> = None;
+        let mut schema: Option<Vec<DataType>> = None;
+        let mut chunk: Vec<Vec<String>> = Vec::with_capacity(chunk_rows);
+        let mut rows_read = 0;
+
+        loop {
+            // An empty buffer means end of file, which `read_field` uses to finish the last
+            // record.
+            let bytes = input.fill_buf()?;
+            let (result, bytes_consumed, bytes_written) = rdr.read_field(bytes, &mut field_buf);
+            input.consume(bytes_consumed);
+            field_bytes.extend_from_slice(&field_buf[..bytes_written]);
+
+            match result {
+                ReadFieldResult::InputEmpty | ReadFieldResult::OutputFull => continue,
+                ReadFieldResult::End => break,
+                ReadFieldResult::Field { record_end } => {
+                    let field_str = String::from_utf8(std::mem::take(&mut field_bytes))
+                        .map_err(|e| VeloxxError::Parsing(e.to_string()))?;
+                    current_row_fields.push(field_str);
+                    if !record_end {
+                        continue;
+                    }
+                }
+            }
+
+            let row = std::mem::take(&mut current_row_fields);
+            let Some(columns) = &header else {
+                header = Some(row);
+                continue;
+            };
+            rows_read += 1;
+            if row.len() != columns.len() {
+                return Err(VeloxxError::Parsing(format!(
+                    "CSV row {} has {} columns, expected {} (header: {:?}, row: {:?})",
+                    rows_read,
+                    row.len(),
+                    columns.len(),
+                    columns,
+                    row
+                )));
+            }
+            chunk.push(row);
+            if chunk.len() == chunk_rows {
+                let rows = std::mem::replace(&mut chunk, Vec::with_capacity(chunk_rows));
+                let first_row = rows_read + 1 - rows.len();
+                f(csv_chunk(rows, columns, &mut schema, first_row)?)?;
+            }
+        }
+
+        if let Some(columns) = &header {
+            if !chunk.is_empty() {
+                let first_row = rows_read + 1 - chunk.len();
+                f(csv_chunk(chunk, columns, &mut schema, first_row)?)?;
+            }
+        }
+        Ok(())
+    }
+
     pub fn from_vec_of_vec(
         data: Vec<Vec<String>>,
         column_names: Vec<String>,
@@ -275,20 +457,111 @@ impl DataFrame {
     }
 
     pub fn to_csv(&self, path: &str) -> Result<(), VeloxxError> {
-        use std::io::Write;
-        let mut file =
-            std::fs::File::create(path).map_err(|e| VeloxxError::FileIO(e.to_string()))?;
+        self.to_csv_with_options(path, &CsvWriteOptions::default())
+    }
+
+    /// Writes the `DataFrame` to a CSV file using the given delimiter and quote character.
+    ///
+    /// Fields (including header names) that contain the delimiter, the quote character, or a
+    /// line break are wrapped in quotes, and embedded quote characters are doubled, following
+    /// RFC 4180. Null values are written as empty fields.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file to create.
+    /// * `options` - The delimiter and quote character to use.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok(())` on success or `Err(VeloxxError::Io)` if the file
+    /// cannot be written.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::dataframe::io::CsvWriteOptions;
+    /// use veloxx::series::Series;
+    /// use indexmap::IndexMap;
+    ///
+    /// let mut columns = IndexMap::new();
+    /// columns.insert(
+    ///     "name".to_string(),
+    ///     Series::new_string("name", vec![Some("Smith; John".to_string())]),
+    /// );
+    /// let df = DataFrame::new(columns);
+    ///
+    /// let options = CsvWriteOptions::new().delimiter(b';');
+    /// df.to_csv_with_options("names.csv", &options).unwrap();
+    /// // Writes: name\n"Smith; John"\n
+    /// # std::fs::remove_file("names.csv").unwrap();
+    /// ```
+    pub fn to_csv_with_options(
+        &self,
+        path: &str,
+        options: &CsvWriteOptions,
+    ) -> Result<(), VeloxxError> {
+        let file = std::fs::File::create(path)?;
+        self.write_csv_with_options(file, options)
+    }
+
+    /// Writes the `DataFrame` as CSV to any writer, such as a file, a socket, a compression
+    /// encoder or an in-memory buffer.
+    ///
+    /// Output is identical to [`DataFrame::to_csv`], which is a wrapper around this method.
+    /// Writes are buffered internally and flushed before returning.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok(())` on success or `Err(VeloxxError::Io)` if writing fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::series::Series;
+    /// use indexmap::IndexMap;
+    ///
+    /// let mut columns = IndexMap::new();
+    /// columns.insert("id".to_string(), Series::new_i32("id", vec![Some(1), None]));
+    /// let df = DataFrame::new(columns);
+    ///
+    /// let mut buffer = Vec::new();
+    /// df.write_csv(&mut buffer).unwrap();
+    /// assert_eq!(String::from_utf8(buffer).unwrap(), "id\n1\n\n");
+    /// ```
+    pub fn write_csv<W: Write>(&self, writer: W) -> Result<(), VeloxxError> {
+        self.write_csv_with_options(writer, &CsvWriteOptions::default())
+    }
 
+    /// Writes the `DataFrame` as CSV to any writer using the given delimiter and quote
+    /// character.
+    ///
+    /// Quoting and null handling follow [`DataFrame::to_csv_with_options`].
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok(())` on success or `Err(VeloxxError::Io)` if writing fails.
+    pub fn write_csv_with_options<W: Write>(
+        &self,
+        writer: W,
+        options: &CsvWriteOptions,
+    ) -> Result<(), VeloxxError> {
         if self.column_count() == 0 {
             return Ok(());
         }
+        let mut writer = BufWriter::new(writer);
 
+        let delimiter = (options.delimiter as char).to_string();
         let header_names = self.column_names();
         let mut column_names: Vec<&str> = header_names.iter().map(|s| s.as_str()).collect();
         // Sort column names to ensure consistent ordering
         column_names.sort();
-        writeln!(file, "{}", column_names.join(","))
-            .map_err(|e| VeloxxError::FileIO(e.to_string()))?;
+        let header: Vec<String> = column_names
+            .iter()
+            .map(|name| options.escape_field(name))
+            .collect();
+        writeln!(writer, "{}", header.join(&delimiter))?;
 
         for i in 0..self.row_count() {
             let mut row_values: Vec<String> = Vec::new();
@@ -296,25 +569,25 @@ impl DataFrame {
                 let series = self.get_column(col_name).unwrap();
                 let value_str = match series.get_value(i) {
                     Some(crate::types::Value::I32(v)) => v.to_string(),
+                    Some(crate::types::Value::I64(v)) => v.to_string(),
                     Some(crate::types::Value::F64(v)) => v.to_string(),
                     Some(crate::types::Value::Bool(v)) => v.to_string(),
-                    Some(crate::types::Value::String(v)) => v.clone(),
+                    Some(crate::types::Value::String(v)) => options.escape_field(&v),
                     Some(crate::types::Value::DateTime(v)) => v.to_string(),
                     Some(crate::types::Value::Null) => "".to_string(),
                     None => "".to_string(),
                 };
                 row_values.push(value_str);
             }
-            writeln!(file, "{}", row_values.join(","))
-                .map_err(|e| VeloxxError::FileIO(e.to_string()))?;
+            writeln!(writer, "{}", row_values.join(&delimiter))?;
         }
 
+        writer.flush()?;
         Ok(())
     }
 
     pub fn from_json(path: &str) -> Result<Self, VeloxxError> {
-        let contents =
-            std::fs::read_to_string(path).map_err(|e| VeloxxError::FileIO(e.to_string()))?;
+        let contents = std::fs::read_to_string(path)?;
         let json = JSONValue::load(&contents);
         let arr_iter = match json.iter_array() {
             Ok(arr) => arr,
@@ -326,126 +599,672 @@ impl DataFrame {
         };
         let mut rows = Vec::new();
         for row_val in arr_iter {
-            let obj_iter = match row_val.iter_object() {
-                Ok(obj) => obj,
-                Err(_) => {
-                    return Err(VeloxxError::Parsing(
-                        "Each row must be a JSON object".to_string(),
-                    ))
-                }
-            };
-            let mut row = indexmap::IndexMap::new();
-            for entry in obj_iter {
-                let (k, v) = match entry {
-                    Ok((k, v)) => (k, v),
-                    Err(_) => {
-                        return Err(VeloxxError::Parsing(
-                            "Error reading key-value pair".to_string(),
-                        ))
-                    }
-                };
-                let value = if let Ok(f) = v.read_float() {
-                    Some(crate::types::Value::F64(f as f64))
-                } else if let Ok(i) = v.read_integer() {
-                    Some(crate::types::Value::I32(i as i32))
-                } else if let Ok(s) = v.read_string() {
-                    Some(crate::types::Value::String(s.to_string()))
-                } else if let Ok(b) = v.read_boolean() {
-                    Some(crate::types::Value::Bool(b))
-                } else if let Ok(dt) = v.read_integer() {
-                    Some(crate::types::Value::DateTime(dt as i64))
-                } else {
-                    None
-                };
-                row.insert(k.to_string(), value);
-            }
+            let row = read_json_record(row_val).map_err(VeloxxError::Parsing)?;
             rows.push(row);
         }
         if rows.is_empty() {
             return Err(VeloxxError::Parsing("JSON array is empty".to_string()));
         }
         let column_names: Vec<String> = rows[0].keys().cloned().collect();
-        let mut columns: indexmap::IndexMap<String, Vec<Option<crate::types::Value>>> =
-            indexmap::IndexMap::new();
-        for name in &column_names {
-            columns.insert(name.clone(), Vec::new());
-        }
-        for row in rows {
-            for name in &column_names {
-                columns
-                    .get_mut(name)
-                    .unwrap()
-                    .push(row.get(name).cloned().unwrap_or(None));
+        Ok(json_records_to_frame(&column_names, rows))
+    }
+
+    /// Reads a newline-delimited JSON (NDJSON / JSON Lines) file into a `DataFrame`.
+    ///
+    /// Every non-blank line must be one JSON object holding a record. The columns are the
+    /// union of the keys of all records, in order of first appearance, and a record that
+    /// lacks a key has a null in that column. Numbers are read at full precision: a column
+    /// of integer literals becomes `I32`, or `I64` if any value needs it, and a column holding
+    /// any other number becomes `F64`. Booleans become `Bool`, and strings or a mix of
+    /// incompatible types become `String`. Nested arrays and objects are read as nulls.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file to read.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok(DataFrame)` with one row per record, `Err(VeloxxError::Io)`
+    /// if the file cannot be read, `Err(VeloxxError::InvalidOperation)` naming the 1-based line
+    /// number if a line is not a JSON object, or `Err(VeloxxError::Parsing)` if the file holds
+    /// no records.
+    pub fn from_ndjson(path: &str) -> Result<Self, VeloxxError> {
+        let file = std::fs::File::open(path)?;
+        let mut column_names: Vec<String> = Vec::new();
+        let mut rows = Vec::new();
+        for (i, line) in BufReader::new(file).lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let row = parse_ndjson_record(&line).map_err(|message| {
+                VeloxxError::InvalidOperation(format!("NDJSON line {}: {}", i + 1, message))
+            })?;
+            for key in row.keys() {
+                if !column_names.contains(key) {
+                    column_names.push(key.clone());
+                }
             }
+            rows.push(row);
         }
-        let mut series_map = indexmap::IndexMap::new();
-        for (name, values) in columns {
-            let series = if let Some(Some(crate::types::Value::F64(_))) =
-                values.iter().find(|v| v.is_some())
-            {
-                Series::new_f64(
-                    &name,
-                    values
-                        .into_iter()
-                        .map(|v| match v {
-                            Some(crate::types::Value::F64(f)) => Some(f),
-                            _ => None,
-                        })
-                        .collect(),
-                )
-            } else if let Some(Some(crate::types::Value::I32(_))) =
-                values.iter().find(|v| v.is_some())
-            {
-                Series::new_i32(
-                    &name,
-                    values
-                        .into_iter()
-                        .map(|v| match v {
-                            Some(crate::types::Value::I32(i)) => Some(i),
-                            _ => None,
-                        })
-                        .collect(),
-                )
-            } else if let Some(Some(crate::types::Value::Bool(_))) =
-                values.iter().find(|v| v.is_some())
-            {
-                Series::new_bool(
-                    &name,
-                    values
-                        .into_iter()
-                        .map(|v| match v {
-                            Some(crate::types::Value::Bool(b)) => Some(b),
-                            _ => None,
-                        })
-                        .collect(),
-                )
-            } else if let Some(Some(crate::types::Value::DateTime(_))) =
-                values.iter().find(|v| v.is_some())
-            {
-                Series::new_datetime(
-                    &name,
-                    values
-                        .into_iter()
-                        .map(|v| match v {
-                            Some(crate::types::Value::DateTime(dt)) => Some(dt),
-                            _ => None,
-                        })
-                        .collect(),
-                )
-            } else {
-                Series::new_string(
-                    &name,
-                    values
-                        .into_iter()
-                        .map(|v| match v {
-                            Some(crate::types::Value::String(s)) => Some(s),
-                            _ => None,
-                        })
-                        .collect(),
-                )
+        if rows.is_empty() {
+            return Err(VeloxxError::Parsing(
+                "NDJSON file has no records".to_string(),
+            ));
+        }
+        let mut columns = IndexMap::new();
+        for name in column_names {
+            let values: Vec<Option<Value>> = rows
+                .iter()
+                .map(|row| row.get(&name).cloned().flatten())
+                .collect();
+            let series = json_values_to_series(&name, &values);
+            columns.insert(name, series);
+        }
+        Ok(DataFrame::new(columns))
+    }
+
+    /// Writes the `DataFrame` to a newline-delimited JSON (NDJSON / JSON Lines) file.
+    ///
+    /// Each row becomes one line holding a JSON object with a key per column, in column order.
+    /// Nulls, and F64 values that JSON cannot represent (NaN and infinities), are written as
+    /// `null`. Whole F64 values keep a trailing `.0` so [`DataFrame::from_ndjson`] reads them
+    /// back as floats. DateTime values are written as their integer timestamps.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file to create.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok(())` on success or `Err(VeloxxError::Io)` if the file
+    /// cannot be written.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::series::Series;
+    /// use indexmap::IndexMap;
+    ///
+    /// let mut columns = IndexMap::new();
+    /// columns.insert("id".to_string(), Series::new_i32("id", vec![Some(1), Some(2)]));
+    /// columns.insert("tag".to_string(), Series::new_string("tag", vec![Some("a".to_string()), None]));
+    /// let df = DataFrame::new(columns);
+    ///
+    /// df.to_ndjson("tags.ndjson").unwrap();
+    /// // Writes: {"id":1,"tag":"a"}\n{"id":2,"tag":null}\n
+    /// # std::fs::remove_file("tags.ndjson").unwrap();
+    /// ```
+    pub fn to_ndjson(&self, path: &str) -> Result<(), VeloxxError> {
+        let file = std::fs::File::create(path)?;
+        let mut writer = BufWriter::new(file);
+        for i in 0..self.row_count() {
+            let mut line = String::from("{");
+            for (j, (name, series)) in self.columns.iter().enumerate() {
+                if j > 0 {
+                    line.push(',');
+                }
+                push_json_string(&mut line, name);
+                line.push(':');
+                match series.get_value(i) {
+                    Some(Value::I32(v)) => line.push_str(&v.to_string()),
+                    Some(Value::I64(v)) => line.push_str(&v.to_string()),
+                    Some(value @ Value::F64(v)) if v.is_finite() => {
+                        line.push_str(&value.to_string())
+                    }
+                    Some(Value::Bool(v)) => line.push_str(&v.to_string()),
+                    Some(Value::String(v)) => push_json_string(&mut line, &v),
+                    Some(Value::DateTime(v)) => line.push_str(&v.to_string()),
+                    Some(Value::F64(_)) | Some(Value::Null) | None => line.push_str("null"),
+                }
+            }
+            line.push('}');
+            writeln!(writer, "{}", line)?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Expands a column of JSON object strings into one column per key.
+    ///
+    /// Each cell of `column` is parsed as a JSON object. Every key seen in any row becomes
+    /// a new column named `"{column}_{key}"`, inserted in place of the source column in
+    /// order of first appearance. Column types are inferred from the parsed values:
+    /// integers become `I32`, numbers `F64`, booleans `Bool`, and anything else or a mix
+    /// of incompatible types becomes `String`. Rows that lack a key, null cells, and cells
+    /// that are not valid JSON objects produce nulls in the new columns.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - The name of the `String` column holding JSON objects.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok(DataFrame)` with the source column replaced by the expanded
+    /// columns, `Err(VeloxxError::ColumnNotFound)` if `column` does not exist,
+    /// `Err(VeloxxError::DataTypeMismatch)` if it is not a `String` column, or
+    /// `Err(VeloxxError::InvalidOperation)` if an expanded column name is already taken.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::series::Series;
+    /// use veloxx::types::Value;
+    /// use indexmap::IndexMap;
+    ///
+    /// let mut columns = IndexMap::new();
+    /// columns.insert("meta".to_string(), Series::new_string("meta", vec![
+    ///     Some(r#"{"id": 1, "tag": "a"}"#.to_string()),
+    ///     Some("not json".to_string()),
+    /// ]));
+    /// let df = DataFrame::new(columns);
+    ///
+    /// let unnested = df.unnest_json("meta").unwrap();
+    /// assert_eq!(unnested.get_column("meta_id").unwrap().get_value(0), Some(Value::I32(1)));
+    /// assert_eq!(unnested.get_column("meta_tag").unwrap().get_value(1), None);
+    /// ```
+    pub fn unnest_json(&self, column: &str) -> Result<Self, VeloxxError> {
+        let source = self
+            .get_column(column)
+            .ok_or_else(|| VeloxxError::ColumnNotFound(column.to_string()))?;
+        if source.data_type() != crate::types::DataType::String {
+            return Err(VeloxxError::DataTypeMismatch(format!(
+                "Column '{}' must be a String column to unnest JSON",
+                column
+            )));
+        }
+
+        let row_count = source.len();
+        let mut fields: IndexMap<String, Vec<Option<crate::types::Value>>> = IndexMap::new();
+        for i in 0..row_count {
+            let Some(text) = source.get_string(i) else {
+                continue;
+            };
+            let Some(entries) = parse_json_object(text) else {
+                continue;
             };
-            series_map.insert(name, series);
+            for (key, value) in entries {
+                let values = fields.entry(key).or_insert_with(|| vec![None; row_count]);
+                values[i] = value;
+            }
+        }
+
+        for key in fields.keys() {
+            let new_name = format!("{}_{}", column, key);
+            if self.columns.contains_key(&new_name) {
+                return Err(VeloxxError::InvalidOperation(format!(
+                    "Unnesting key '{}' of column '{}' would overwrite existing column '{}'",
+                    key, column, new_name
+                )));
+            }
+        }
+
+        let mut new_columns = IndexMap::new();
+        for (name, series) in &self.columns {
+            if name != column {
+                new_columns.insert(name.clone(), series.clone());
+                continue;
+            }
+            for (key, values) in &fields {
+                let new_name = format!("{}_{}", column, key);
+                new_columns.insert(new_name.clone(), json_values_to_series(&new_name, values));
+            }
+        }
+        Ok(DataFrame::new(new_columns))
+    }
+}
+
+/// Reads one JSON object as a record, mapping each scalar with the [`DataFrame::from_json`]
+/// rules. Returns a description of the problem if `value` is not a well-formed object.
+fn read_json_record(value: JSONValue) -> Result<IndexMap<String, Option<Value>>, String> {
+    let obj_iter = value
+        .iter_object()
+        .map_err(|_| "Each row must be a JSON object".to_string())?;
+    let mut row = IndexMap::new();
+    for entry in obj_iter {
+        let (k, v) = entry.map_err(|_| "Error reading key-value pair".to_string())?;
+        let value = if let Ok(f) = v.read_float() {
+            Some(Value::F64(f as f64))
+        } else if let Ok(i) = v.read_integer() {
+            Some(Value::I32(i as i32))
+        } else if let Ok(s) = v.read_string() {
+            Some(Value::String(s.to_string()))
+        } else if let Ok(b) = v.read_boolean() {
+            Some(Value::Bool(b))
+        } else if let Ok(dt) = v.read_integer() {
+            Some(Value::DateTime(dt as i64))
+        } else {
+            None
+        };
+        row.insert(k.to_string(), value);
+    }
+    Ok(row)
+}
+
+/// Parses one NDJSON line as a flat JSON object, returning its entries in document order.
+/// Numbers keep full precision: integer literals become `I32`, or `I64` when they do not fit,
+/// and other numbers `F64`. Nested arrays and objects map to `None`. Returns a description of
+/// the problem if `line` is not a well-formed object.
+fn parse_ndjson_record(line: &str) -> Result<IndexMap<String, Option<Value>>, String> {
+    let mut scanner = JsonScanner { text: line, pos: 0 };
+    scanner.skip_whitespace();
+    if !scanner.eat(b'{') {
+        return Err("Each row must be a JSON object".to_string());
+    }
+    let mut row = IndexMap::new();
+    scanner.skip_whitespace();
+    if !scanner.eat(b'}') {
+        loop {
+            scanner.skip_whitespace();
+            let key = scanner.string()?;
+            scanner.skip_whitespace();
+            scanner.expect(b':')?;
+            scanner.skip_whitespace();
+            let value = scanner.value()?;
+            row.insert(key, value);
+            scanner.skip_whitespace();
+            if scanner.eat(b'}') {
+                break;
+            }
+            scanner.expect(b',')?;
         }
-        Ok(DataFrame::new(series_map))
+    }
+    scanner.skip_whitespace();
+    if scanner.pos < line.len() {
+        return Err(format!(
+            "Unexpected text after the object at byte {}",
+            scanner.pos
+        ));
+    }
+    Ok(row)
+}
+
+/// A cursor over JSON text, used by [`parse_ndjson_record`].
+struct JsonScanner<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl JsonScanner<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.text.as_bytes().get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.pos += 1;
+        }
+    }
+
+    fn eat(&mut self, byte: u8) -> bool {
+        let found = self.peek() == Some(byte);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), String> {
+        if self.eat(byte) {
+            Ok(())
+        } else {
+            Err(format!("Expected '{}' at byte {}", byte as char, self.pos))
+        }
+    }
+
+    fn eat_literal(&mut self, literal: &str) -> bool {
+        let found = self.text.as_bytes()[self.pos..].starts_with(literal.as_bytes());
+        if found {
+            self.pos += literal.len();
+        }
+        found
+    }
+
+    /// Reads one value. Arrays and objects are skipped and read as `None`, like `null`.
+    fn value(&mut self) -> Result<Option<Value>, String> {
+        match self.peek() {
+            Some(b'"') => self.string().map(|s| Some(Value::String(s))),
+            Some(b'[' | b'{') => self.skip_nested().map(|_| None),
+            Some(b'-' | b'0'..=b'9') => self.number().map(Some),
+            _ if self.eat_literal("true") => Ok(Some(Value::Bool(true))),
+            _ if self.eat_literal("false") => Ok(Some(Value::Bool(false))),
+            _ if self.eat_literal("null") => Ok(None),
+            _ => Err(format!("Invalid JSON value at byte {}", self.pos)),
+        }
+    }
+
+    fn number(&mut self) -> Result<Value, String> {
+        let start = self.pos;
+        while matches!(
+            self.peek(),
+            Some(b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9')
+        ) {
+            self.pos += 1;
+        }
+        let literal = &self.text[start..self.pos];
+        if !literal.contains(['.', 'e', 'E']) {
+            if let Ok(i) = literal.parse::<i64>() {
+                return Ok(i32::try_from(i).map(Value::I32).unwrap_or(Value::I64(i)));
+            }
+        }
+        literal
+            .parse::<f64>()
+            .map(Value::F64)
+            .map_err(|_| format!("Invalid JSON number '{}'", literal))
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        self.expect(b'"')?;
+        let mut out = String::new();
+        loop {
+            let start = self.pos;
+            while !matches!(self.peek(), None | Some(b'"' | b'\\')) {
+                self.pos += 1;
+            }
+            out.push_str(&self.text[start..self.pos]);
+            match self.peek() {
+                None => return Err("Unterminated JSON string".to_string()),
+                Some(b'"') => {
+                    self.pos += 1;
+                    return Ok(out);
+                }
+                _ => self.pos += 1,
+            }
+            let escape = self.peek().ok_or("Unterminated JSON string")?;
+            self.pos += 1;
+            match escape {
+                b'"' => out.push('"'),
+                b'\\' => out.push('\\'),
+                b'/' => out.push('/'),
+                b'b' => out.push('\u{8}'),
+                b'f' => out.push('\u{c}'),
+                b'n' => out.push('\n'),
+                b'r' => out.push('\r'),
+                b't' => out.push('\t'),
+                b'u' => out.push(self.unicode_escape()?),
+                _ => {
+                    return Err(format!(
+                        "Invalid escape in JSON string at byte {}",
+                        self.pos
+                    ))
+                }
+            }
+        }
+    }
+
+    /// Reads the hex digits of a `\u` escape, combining a UTF-16 surrogate pair.
+    fn unicode_escape(&mut self) -> Result<char, String> {
+        let high = self.hex4()?;
+        let code = if (0xD800..0xDC00).contains(&high) && self.eat_literal("\\u") {
+            let low = self.hex4()?;
+            if !(0xDC00..0xE000).contains(&low) {
+                return Err("Invalid surrogate pair in JSON string".to_string());
+            }
+            0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+        } else {
+            high
+        };
+        char::from_u32(code).ok_or_else(|| "Invalid \\u escape in JSON string".to_string())
+    }
+
+    fn hex4(&mut self) -> Result<u32, String> {
+        let digits = self
+            .text
+            .get(self.pos..self.pos + 4)
+            .filter(|d| d.bytes().all(|b| b.is_ascii_hexdigit()))
+            .ok_or("Invalid \\u escape in JSON string")?;
+        self.pos += 4;
+        Ok(u32::from_str_radix(digits, 16).unwrap())
+    }
+
+    /// Skips an array or object, including any strings inside it.
+    fn skip_nested(&mut self) -> Result<(), String> {
+        let mut depth = 0usize;
+        loop {
+            match self.peek() {
+                None => return Err("Unterminated JSON array or object".to_string()),
+                Some(b'"') => {
+                    self.string()?;
+                    continue;
+                }
+                Some(b'[' | b'{') => depth += 1,
+                Some(b']' | b'}') => {
+                    depth -= 1;
+                    if depth == 0 {
+                        self.pos += 1;
+                        return Ok(());
+                    }
+                }
+                _ => {}
+            }
+            self.pos += 1;
+        }
+    }
+}
+
+/// Builds the `column_names` columns from JSON records. Each column takes the type of its
+/// first non-null value; values of another type, and keys a record lacks, become null.
+fn json_records_to_frame(
+    column_names: &[String],
+    rows: Vec<IndexMap<String, Option<Value>>>,
+) -> DataFrame {
+    let mut columns: IndexMap<String, Vec<Option<Value>>> = IndexMap::new();
+    for name in column_names {
+        columns.insert(name.clone(), Vec::new());
+    }
+    for row in rows {
+        for name in column_names {
+            columns
+                .get_mut(name)
+                .unwrap()
+                .push(row.get(name).cloned().unwrap_or(None));
+        }
+    }
+    let mut series_map = IndexMap::new();
+    for (name, values) in columns {
+        let series = match values.iter().flatten().next() {
+            Some(Value::F64(_)) => Series::new_f64(
+                &name,
+                values
+                    .into_iter()
+                    .map(|v| match v {
+                        Some(Value::F64(f)) => Some(f),
+                        _ => None,
+                    })
+                    .collect(),
+            ),
+            Some(Value::I32(_)) => Series::new_i32(
+                &name,
+                values
+                    .into_iter()
+                    .map(|v| match v {
+                        Some(Value::I32(i)) => Some(i),
+                        _ => None,
+                    })
+                    .collect(),
+            ),
+            Some(Value::Bool(_)) => Series::new_bool(
+                &name,
+                values
+                    .into_iter()
+                    .map(|v| match v {
+                        Some(Value::Bool(b)) => Some(b),
+                        _ => None,
+                    })
+                    .collect(),
+            ),
+            Some(Value::DateTime(_)) => Series::new_datetime(
+                &name,
+                values
+                    .into_iter()
+                    .map(|v| match v {
+                        Some(Value::DateTime(dt)) => Some(dt),
+                        _ => None,
+                    })
+                    .collect(),
+            ),
+            _ => Series::new_string(
+                &name,
+                values
+                    .into_iter()
+                    .map(|v| match v {
+                        Some(Value::String(s)) => Some(s),
+                        _ => None,
+                    })
+                    .collect(),
+            ),
+        };
+        series_map.insert(name, series);
+    }
+    DataFrame::new(series_map)
+}
+
+/// Appends `s` to `out` as a quoted JSON string, escaping quotes, backslashes and control
+/// characters.
+fn push_json_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// Builds one chunk of [`DataFrame::from_csv_chunked`], inferring `schema` from the first chunk
+/// and parsing later chunks with it. `first_row` is the 1-based data row number of `rows[0]`.
+fn csv_chunk(
+    rows: Vec<Vec<String>>,
+    header: &[String],
+    schema: &mut Option<Vec<DataType>>,
+    first_row: usize,
+) -> Result<DataFrame, VeloxxError> {
+    let Some(types) = schema else {
+        let df = DataFrame::from_vec_of_vec(rows, header.to_vec())?;
+        *schema = Some(df.columns.values().map(|s| s.data_type()).collect());
+        return Ok(df);
+    };
+
+    let mut columns: IndexMap<String, Series> = IndexMap::new();
+    for (col_idx, (name, dtype)) in header.iter().zip(types.iter()).enumerate() {
+        let values = rows
+            .iter()
+            .enumerate()
+            .map(|(i, row)| {
+                let cell = &row[col_idx];
+                if cell.is_empty() {
+                    return Ok(None);
+                }
+                let value = match dtype {
+                    DataType::I32 => cell.parse().ok().map(Value::I32),
+                    DataType::I64 => cell.parse().ok().map(Value::I64),
+                    DataType::F64 => cell.parse().ok().map(Value::F64),
+                    DataType::Bool => cell.parse().ok().map(Value::Bool),
+                    DataType::DateTime => cell.parse().ok().map(Value::DateTime),
+                    DataType::String => Some(Value::String(cell.clone())),
+                };
+                value.map(Some).ok_or_else(|| {
+                    VeloxxError::Parsing(format!(
+                        "CSV row {} column '{}': cannot parse '{}' as {:?}, the type inferred from the first chunk",
+                        first_row + i,
+                        name,
+                        cell,
+                        dtype
+                    ))
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        columns.insert(
+            name.clone(),
+            Series::from_values(name, values, dtype.clone())?,
+        );
+    }
+    Ok(DataFrame::new(columns))
+}
+
+/// Parses `text` as a flat JSON object, returning its entries in document order.
+/// Returns `None` if `text` is not a JSON object. Values that are not scalars map to `None`.
+fn parse_json_object(text: &str) -> Option<Vec<(String, Option<crate::types::Value>)>> {
+    let json = JSONValue::load(text.trim());
+    let obj_iter = json.iter_object().ok()?;
+    let mut entries = Vec::new();
+    for entry in obj_iter {
+        let (k, v) = entry.ok()?;
+        let value = if let Ok(i) = v.read_integer() {
+            i32::try_from(i)
+                .map(crate::types::Value::I32)
+                .unwrap_or(crate::types::Value::F64(i as f64))
+        } else if let Ok(f) = v.read_float() {
+            crate::types::Value::F64(f as f64)
+        } else if let Ok(s) = v.read_string() {
+            crate::types::Value::String(s.to_string())
+        } else if let Ok(b) = v.read_boolean() {
+            crate::types::Value::Bool(b)
+        } else {
+            entries.push((k.to_string(), None));
+            continue;
+        };
+        entries.push((k.to_string(), Some(value)));
+    }
+    Some(entries)
+}
+
+/// Builds a series from parsed JSON values, widening to the narrowest type that holds them all.
+fn json_values_to_series(name: &str, values: &[Option<crate::types::Value>]) -> Series {
+    use crate::types::Value;
+    let present = || values.iter().flatten();
+    if present().all(|v| matches!(v, Value::I32(_))) {
+        Series::new_i32(
+            name,
+            values
+                .iter()
+                .map(|v| v.as_ref().and_then(Value::as_i32))
+                .collect(),
+        )
+    } else if present().all(|v| matches!(v, Value::I32(_) | Value::I64(_))) {
+        Series::new_i64(
+            name,
+            values
+                .iter()
+                .map(|v| v.as_ref().and_then(Value::as_i64))
+                .collect(),
+        )
+    } else if present().all(|v| matches!(v, Value::I32(_) | Value::I64(_) | Value::F64(_))) {
+        Series::new_f64(
+            name,
+            values
+                .iter()
+                .map(|v| match v {
+                    Some(Value::I32(i)) => Some(*i as f64),
+                    Some(Value::I64(i)) => Some(*i as f64),
+                    Some(Value::F64(f)) => Some(*f),
+                    _ => None,
+                })
+                .collect(),
+        )
+    } else if present().all(|v| matches!(v, Value::Bool(_))) {
+        Series::new_bool(
+            name,
+            values
+                .iter()
+                .map(|v| v.as_ref().and_then(Value::as_bool))
+                .collect(),
+        )
+    } else {
+        Series::new_string(
+            name,
+            values
+                .iter()
+                .map(|v| v.as_ref().map(|v| v.to_string()))
+                .collect(),
+        )
     }
 }
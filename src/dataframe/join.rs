@@ -3,6 +3,7 @@ use crate::{dataframe::DataFrame, series::Series, types::Value};
 use indexmap::IndexMap;
 use rayon::iter::IntoParallelIterator;
 use rayon::prelude::*;
+use std::cmp::Ordering;
 
 #[derive(PartialEq)]
 /// Defines the type of join to be performed between two DataFrames.
@@ -15,6 +16,25 @@ pub enum JoinType {
     Right,
     /// Returns all rows when there is a match in either left or right DataFrame.
     Outer,
+    /// Returns the rows of the left DataFrame whose key appears in the right DataFrame, with
+    /// only the left DataFrame's columns. Each left row is emitted at most once.
+    LeftSemi,
+    /// Returns the rows of the left DataFrame whose key does not appear in the right DataFrame,
+    /// with only the left DataFrame's columns.
+    LeftAnti,
+}
+
+/// Selects the algorithm used by [`DataFrame::join_with`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum JoinAlgorithm {
+    /// Builds a hash map over the join column of one side and probes it with the other.
+    /// Works on unsorted input and is what [`DataFrame::join`] uses.
+    #[default]
+    Hash,
+    /// Merges two frames that are already sorted ascending on the join column (nulls first,
+    /// as produced by [`DataFrame::sort`]), without building a hash map. Output rows are
+    /// ordered by key.
+    SortMerge,
 }
 
 impl DataFrame {
@@ -22,19 +42,35 @@ impl DataFrame {
     ///
     /// This method combines two DataFrames based on a common column (`on_column`) and a specified
     /// `JoinType`. It creates a new DataFrame containing columns from both original DataFrames.
+    /// It is [`DataFrame::join_on`] with a single key column.
+    ///
+    /// Inner and left joins emit rows in the order of the left DataFrame, and the matches for a
+    /// given left row follow the order of the right DataFrame, so the output is reproducible
+    /// even though matching runs in parallel.
+    ///
+    /// Apart from `on_column`, the two DataFrames must not share any column names: a shared
+    /// column would have to drop one side's values, so it is rejected instead. Rename the
+    /// columns on one side before joining, or use [`DataFrame::join_with_suffixes`]. `LeftSemi`
+    /// and `LeftAnti` only return the left DataFrame's columns, so they are not affected by
+    /// this restriction.
+    ///
+    /// Null keys never match, so a left row with a null key is dropped by `LeftSemi` and kept
+    /// by `LeftAnti`.
     ///
     /// # Arguments
     ///
     /// * `other` - The other `DataFrame` to join with.
     /// * `on_column` - The name of the column to join on. This column must exist in both DataFrames
-    ///   and have comparable data types.
-    /// * `join_type` - The type of join to perform (`Inner`, `Left`, `Right`, or `Outer`).
+    ///   and have the same data type.
+    /// * `join_type` - The type of join to perform (`Inner`, `Left`, `Right`, `Outer`,
+    ///   `LeftSemi` or `LeftAnti`).
     ///
     /// # Returns
     ///
     /// A `Result` which is `Ok(DataFrame)` containing the joined `DataFrame`,
     /// or `Err(VeloxxError::ColumnNotFound)` if the `on_column` is not found in either DataFrame,
-    /// or `Err(VeloxxError::InvalidOperation)` if there are issues during the join process (e.g., incompatible types).
+    /// `Err(VeloxxError::DataTypeMismatch)` if `on_column` has different types on the two sides,
+    /// or `Err(VeloxxError::InvalidOperation)` if both DataFrames share a non-key column name.
     ///
     /// # Examples
     ///
@@ -157,542 +193,557 @@ impl DataFrame {
     /// // Expected rows: 1, 2, 3, 4
     /// assert_eq!(outer_joined_df.row_count(), 4);
     /// ```
-    #[allow(clippy::type_complexity)]
+    ///
+    /// ## Semi and Anti Joins
+    ///
+    /// Keeps the rows of `left_df` with (semi) or without (anti) a matching `id` in `right_df`.
+    ///
+    /// ```rust
+    /// # use veloxx::dataframe::DataFrame;
+    /// # use veloxx::series::Series;
+    /// # use indexmap::IndexMap;
+    /// # use veloxx::dataframe::join::JoinType;
+    /// # let mut left_cols = IndexMap::new();
+    /// # left_cols.insert("id".to_string(), Series::new_i32("id", vec![Some(1), Some(2), Some(3)]));
+    /// # left_cols.insert("name".to_string(), Series::new_string("name", vec![Some("Alice".to_string()), Some("Bob".to_string()), Some("Charlie".to_string())]));
+    /// # let left_df = DataFrame::new(left_cols);
+    /// # let mut right_cols = IndexMap::new();
+    /// # right_cols.insert("id".to_string(), Series::new_i32("id", vec![Some(2), Some(3), Some(4)]));
+    /// # right_cols.insert("city".to_string(), Series::new_string("city", vec![Some("London".to_string()), Some("Paris".to_string()), Some("Rome".to_string())]));
+    /// # let right_df = DataFrame::new(right_cols);
+    ///
+    /// let semi = left_df.join(&right_df, "id", JoinType::LeftSemi).unwrap();
+    /// assert_eq!(semi.row_count(), 2);
+    /// assert!(semi.get_column("city").is_none());
+    ///
+    /// let anti = left_df.join(&right_df, "id", JoinType::LeftAnti).unwrap();
+    /// assert_eq!(anti.row_count(), 1);
+    /// ```
     pub fn join(
         &self,
         other: &DataFrame,
         on_column: &str,
         join_type: JoinType,
     ) -> Result<Self, VeloxxError> {
-        let mut new_columns: IndexMap<String, Series> = IndexMap::new();
-
-        let self_col_names: Vec<String> =
-            self.column_names().iter().map(|s| (*s).clone()).collect();
-        let other_col_names: Vec<String> =
-            other.column_names().iter().map(|s| (*s).clone()).collect();
+        self.join_on(other, &[on_column], join_type)
+    }
 
-        // Check if join column exists in both DataFrames
-        if !self_col_names.contains(&on_column.to_string()) {
-            return Err(VeloxxError::ColumnNotFound(format!(
-                "Join column '{on_column}' not found in left DataFrame."
-            )));
+    /// Performs a join operation with another `DataFrame` on a composite key.
+    ///
+    /// Rows match when the values of every column in `on` are equal, e.g. the same
+    /// `(date, store_id)` pair. A row with a null in any key column never matches. Apart from
+    /// taking several key columns, this behaves exactly like [`DataFrame::join`], including row
+    /// order and the restriction on shared non-key column names.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The other `DataFrame` to join with.
+    /// * `on` - The key columns, which must exist in both DataFrames with the same data types.
+    /// * `join_type` - The type of join to perform.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok(DataFrame)` containing the joined `DataFrame`,
+    /// `Err(VeloxxError::ColumnNotFound)` if a key column is missing from either DataFrame,
+    /// `Err(VeloxxError::DataTypeMismatch)` if a key column has different types on the two
+    /// sides, or `Err(VeloxxError::InvalidOperation)` if `on` is empty or both DataFrames share
+    /// a non-key column name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::dataframe::join::JoinType;
+    /// use veloxx::series::Series;
+    /// use indexmap::IndexMap;
+    ///
+    /// let mut sales_cols = IndexMap::new();
+    /// sales_cols.insert("date".to_string(), Series::new_string("date", vec![Some("2024-01-01".to_string()), Some("2024-01-01".to_string())]));
+    /// sales_cols.insert("store_id".to_string(), Series::new_i32("store_id", vec![Some(1), Some(2)]));
+    /// sales_cols.insert("sales".to_string(), Series::new_f64("sales", vec![Some(10.0), Some(20.0)]));
+    /// let sales = DataFrame::new(sales_cols);
+    ///
+    /// let mut staff_cols = IndexMap::new();
+    /// staff_cols.insert("date".to_string(), Series::new_string("date", vec![Some("2024-01-01".to_string())]));
+    /// staff_cols.insert("store_id".to_string(), Series::new_i32("store_id", vec![Some(2)]));
+    /// staff_cols.insert("staff".to_string(), Series::new_i32("staff", vec![Some(4)]));
+    /// let staff = DataFrame::new(staff_cols);
+    ///
+    /// let joined = sales.join_on(&staff, &["date", "store_id"], JoinType::Inner).unwrap();
+    /// assert_eq!(joined.row_count(), 1);
+    /// ```
+    pub fn join_on(
+        &self,
+        other: &DataFrame,
+        on: &[&str],
+        join_type: JoinType,
+    ) -> Result<Self, VeloxxError> {
+        if on.is_empty() {
+            return Err(VeloxxError::InvalidOperation(
+                "Join requires at least one key column.".to_string(),
+            ));
         }
-        if !other_col_names.contains(&on_column.to_string()) {
-            return Err(VeloxxError::ColumnNotFound(format!(
-                "Join column '{on_column}' not found in right DataFrame."
-            )));
+        let (left_keys, right_keys) = matching_key_columns(self, other, on)?;
+        if matches!(join_type, JoinType::LeftSemi | JoinType::LeftAnti) {
+            return self.filter_by_key_match(other, on, join_type == JoinType::LeftSemi);
         }
+        check_column_collisions(self, other, on)?;
 
-        // Determine all unique column names and their types
-        let all_column_names: Vec<String> = {
-            let mut temp_names = Vec::new();
-            for col_name in self_col_names.iter() {
-                temp_names.push(col_name.clone());
-            }
-            for col_name in other_col_names.iter() {
-                if !temp_names.contains(col_name) {
-                    temp_names.push(col_name.clone());
+        let pairs: Vec<(Option<usize>, Option<usize>)> = match join_type {
+            JoinType::Inner | JoinType::Left | JoinType::Outer => {
+                let right_index = build_join_index(&right_keys);
+                let keep_unmatched = join_type != JoinType::Inner;
+                // Matches are collected per left row and flattened in order, so the output
+                // follows the left frame even though matching runs in parallel.
+                let mut pairs: Vec<(Option<usize>, Option<usize>)> = (0..self.row_count())
+                    .into_par_iter()
+                    .map(
+                        |i| match join_key(&left_keys, i).and_then(|key| right_index.get(&key)) {
+                            Some(rows) => rows.iter().map(|&r| (Some(i), Some(r))).collect(),
+                            None if keep_unmatched => vec![(Some(i), None)],
+                            None => Vec::new(),
+                        },
+                    )
+                    .collect::<Vec<Vec<_>>>()
+                    .into_iter()
+                    .flatten()
+                    .collect();
+                if join_type == JoinType::Outer {
+                    let matched: std::collections::HashSet<usize> =
+                        pairs.iter().filter_map(|&(_, r)| r).collect();
+                    pairs.extend(
+                        (0..other.row_count())
+                            .filter(|r| !matched.contains(r))
+                            .map(|r| (None, Some(r))),
+                    );
                 }
+                pairs
+            }
+            JoinType::Right => {
+                let left_index = build_join_index(&left_keys);
+                (0..other.row_count())
+                    .into_par_iter()
+                    .map(
+                        |r| match join_key(&right_keys, r).and_then(|key| left_index.get(&key)) {
+                            Some(rows) => rows.iter().map(|&l| (Some(l), Some(r))).collect(),
+                            None => vec![(None, Some(r))],
+                        },
+                    )
+                    .collect::<Vec<Vec<_>>>()
+                    .into_iter()
+                    .flatten()
+                    .collect()
+            }
+            JoinType::LeftSemi | JoinType::LeftAnti => {
+                unreachable!("semi and anti joins return before pairing rows")
             }
-            temp_names
         };
 
-        let mut column_types: IndexMap<String, crate::types::DataType> = IndexMap::new();
+        self.assemble_joined_rows(other, &pairs, join_type == JoinType::Right)
+    }
 
-        for col_name in self_col_names.iter() {
-            column_types.insert(
-                col_name.clone(),
-                self.get_column(col_name).unwrap().data_type(),
-            );
+    /// Performs a join on a composite key, suffixing the non-key columns both DataFrames share.
+    ///
+    /// [`DataFrame::join_on`] rejects non-key columns present on both sides. Here such a column
+    /// `value` is instead emitted as `value{left_suffix}` from the left frame and
+    /// `value{right_suffix}` from the right frame, where `suffixes` is
+    /// `(left_suffix, right_suffix)`. Key columns are never suffixed. `LeftSemi` and `LeftAnti`
+    /// only return the left columns, so they ignore the suffixes.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok(DataFrame)` containing the joined `DataFrame`, the errors of
+    /// [`DataFrame::join_on`], or `Err(VeloxxError::InvalidOperation)` if a suffixed name
+    /// clashes with another output column.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::dataframe::join::JoinType;
+    /// use veloxx::series::Series;
+    /// use indexmap::IndexMap;
+    ///
+    /// let mut left_cols = IndexMap::new();
+    /// left_cols.insert("id".to_string(), Series::new_i32("id", vec![Some(1), Some(2)]));
+    /// left_cols.insert("value".to_string(), Series::new_f64("value", vec![Some(1.0), Some(2.0)]));
+    /// let left = DataFrame::new(left_cols);
+    ///
+    /// let mut right_cols = IndexMap::new();
+    /// right_cols.insert("id".to_string(), Series::new_i32("id", vec![Some(2)]));
+    /// right_cols.insert("value".to_string(), Series::new_f64("value", vec![Some(20.0)]));
+    /// let right = DataFrame::new(right_cols);
+    ///
+    /// let joined = left
+    ///     .join_with_suffixes(&right, &["id"], JoinType::Inner, ("_left", "_right"))
+    ///     .unwrap();
+    /// assert_eq!(joined.column_names(), vec!["id", "value_left", "value_right"]);
+    /// ```
+    pub fn join_with_suffixes(
+        &self,
+        other: &DataFrame,
+        on: &[&str],
+        join_type: JoinType,
+        suffixes: (&str, &str),
+    ) -> Result<Self, VeloxxError> {
+        if matches!(join_type, JoinType::LeftSemi | JoinType::LeftAnti) {
+            return self.join_on(other, on, join_type);
         }
-        for col_name in other_col_names.iter() {
-            if !column_types.contains_key(col_name) {
-                column_types.insert(
-                    col_name.clone(),
-                    other.get_column(col_name).unwrap().data_type(),
-                );
-            }
+        let shared: Vec<&str> = self
+            .columns
+            .keys()
+            .map(|name| name.as_str())
+            .filter(|name| !on.contains(name) && other.columns.contains_key(*name))
+            .collect();
+        if shared.is_empty() {
+            return self.join_on(other, on, join_type);
         }
 
-        // Initialize new Series data vectors
-        let mut series_data: indexmap::IndexMap<String, Vec<Option<Value>>> =
-            indexmap::IndexMap::new();
-        for col_name in all_column_names.iter() {
-            series_data.insert(col_name.clone(), Vec::new());
+        let left = with_suffixed_columns(self, &shared, suffixes.0)?;
+        let right = with_suffixed_columns(other, &shared, suffixes.1)?;
+        let mut seen = std::collections::HashSet::new();
+        let output_names = left.columns.keys().chain(
+            right
+                .columns
+                .keys()
+                .filter(|name| !on.contains(&name.as_str())),
+        );
+        for name in output_names {
+            if !seen.insert(name) {
+                return Err(VeloxxError::InvalidOperation(format!(
+                    "Join suffixes ('{}', '{}') produce the duplicate column name '{name}'.",
+                    suffixes.0, suffixes.1
+                )));
+            }
         }
+        left.join_on(&right, on, join_type)
+    }
 
-        match join_type {
-            JoinType::Inner => {
-                let other_on_series = other.get_column(on_column).unwrap();
-                let other_join_map: indexmap::IndexMap<Value, Vec<usize>> = (0..other.row_count())
-                    .into_par_iter()
-                    .filter_map(|i| other_on_series.get_value(i).map(|val| (val, i)))
-                    .fold(
-                        indexmap::IndexMap::new,
-                        |mut map: indexmap::IndexMap<Value, Vec<usize>>, (val, i)| {
-                            map.entry(val).or_default().push(i);
-                            map
-                        },
-                    )
-                    .reduce(indexmap::IndexMap::new, |mut acc, map| {
-                        for (key, value) in map {
-                            acc.entry(key).or_default().extend(value);
-                        }
-                        acc
-                    });
+    /// Performs a join operation with another `DataFrame` using an explicit join algorithm.
+    ///
+    /// `JoinAlgorithm::Hash` behaves exactly like [`DataFrame::join`]. `JoinAlgorithm::SortMerge`
+    /// requires both frames to be sorted ascending on `on_column` and avoids materializing a
+    /// hash map of either side, which keeps memory flat for large, pre-sorted inputs. As with the
+    /// hash join, null keys never match, and the key column takes its value from the left row
+    /// (the right row for `JoinType::Right`, or when there is no left row).
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok(DataFrame)` containing the joined `DataFrame`,
+    /// `Err(VeloxxError::ColumnNotFound)` if `on_column` is missing from either frame,
+    /// `Err(VeloxxError::DataTypeMismatch)` if `on_column` has different types on the two sides,
+    /// or `Err(VeloxxError::InvalidOperation)` if the frames share a non-key column name or a
+    /// sort-merge join is requested on unsorted input.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::dataframe::join::{JoinAlgorithm, JoinType};
+    /// use veloxx::series::Series;
+    /// use indexmap::IndexMap;
+    ///
+    /// let mut left_cols = IndexMap::new();
+    /// left_cols.insert("id".to_string(), Series::new_i32("id", vec![Some(1), Some(2), Some(3)]));
+    /// let left_df = DataFrame::new(left_cols);
+    ///
+    /// let mut right_cols = IndexMap::new();
+    /// right_cols.insert("id".to_string(), Series::new_i32("id", vec![Some(2), Some(3), Some(4)]));
+    /// right_cols.insert("score".to_string(), Series::new_f64("score", vec![Some(0.5), Some(0.7), Some(0.9)]));
+    /// let right_df = DataFrame::new(right_cols);
+    ///
+    /// let joined = left_df
+    ///     .join_with(&right_df, "id", JoinType::Inner, JoinAlgorithm::SortMerge)
+    ///     .unwrap();
+    /// assert_eq!(joined.row_count(), 2);
+    /// ```
+    pub fn join_with(
+        &self,
+        other: &DataFrame,
+        on_column: &str,
+        join_type: JoinType,
+        algorithm: JoinAlgorithm,
+    ) -> Result<Self, VeloxxError> {
+        match algorithm {
+            JoinAlgorithm::Hash => self.join(other, on_column, join_type),
+            JoinAlgorithm::SortMerge => self.sort_merge_join(other, on_column, join_type),
+        }
+    }
 
-                let self_on_series = self.get_column(on_column).unwrap();
-                let results: Vec<Vec<(String, Option<Value>)>> = (0..self.row_count())
-                    .into_par_iter()
-                    .filter_map(|i| {
-                        if let Some(self_join_val) = self_on_series.get_value(i) {
-                            if let Some(other_indices) = other_join_map.get(&self_join_val) {
-                                let self_col_names_cloned = self_col_names.clone();
-                                let all_column_names_cloned = all_column_names.clone();
-                                Some(
-                                    other_indices
-                                        .par_iter()
-                                        .flat_map(move |&other_idx| {
-                                            let mut row_values = Vec::new();
-                                            for col_name in all_column_names_cloned.iter() {
-                                                let value = if self_col_names_cloned
-                                                    .contains(col_name)
-                                                {
-                                                    self.get_column(col_name).unwrap().get_value(i)
-                                                } else {
-                                                    other
-                                                        .get_column(col_name)
-                                                        .unwrap()
-                                                        .get_value(other_idx)
-                                                };
-                                                row_values.push((col_name.clone(), value));
-                                            }
-                                            vec![row_values]
-                                        })
-                                        .collect::<Vec<_>>(),
-                                )
-                            } else {
-                                None
-                            }
-                        } else {
-                            None
-                        }
-                    })
-                    .flatten()
-                    .collect();
+    fn sort_merge_join(
+        &self,
+        other: &DataFrame,
+        on_column: &str,
+        join_type: JoinType,
+    ) -> Result<Self, VeloxxError> {
+        matching_key_columns(self, other, &[on_column])?;
+        let left_keys = sorted_join_keys(self, on_column, "left")?;
+        let right_keys = sorted_join_keys(other, on_column, "right")?;
+        if matches!(join_type, JoinType::LeftSemi | JoinType::LeftAnti) {
+            return self.filter_by_key_match(other, &[on_column], join_type == JoinType::LeftSemi);
+        }
+        check_column_collisions(self, other, &[on_column])?;
+        let keep_left = matches!(join_type, JoinType::Left | JoinType::Outer);
+        let keep_right = matches!(join_type, JoinType::Right | JoinType::Outer);
 
-                for row_values in results {
-                    for (col_name, value) in row_values {
-                        series_data.get_mut(&col_name).unwrap().push(value);
+        let mut pairs: Vec<(Option<usize>, Option<usize>)> = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < left_keys.len() || j < right_keys.len() {
+            let order = match (left_keys.get(i), right_keys.get(j)) {
+                (Some(Some(l)), Some(Some(r))) => l.cmp(r),
+                // Null keys never match; they sort first, so they are consumed first.
+                (Some(None), _) | (Some(Some(_)), None) => Ordering::Less,
+                (None, _) | (Some(Some(_)), Some(None)) => Ordering::Greater,
+            };
+            match order {
+                Ordering::Less => {
+                    if keep_left {
+                        pairs.push((Some(i), None));
                     }
+                    i += 1;
                 }
-            }
-            JoinType::Left => {
-                let other_on_series = other.get_column(on_column).unwrap();
-                let other_join_map: indexmap::IndexMap<Value, Vec<usize>> = (0..other.row_count())
-                    .into_par_iter()
-                    .filter_map(|i| other_on_series.get_value(i).map(|val| (val, i)))
-                    .fold(
-                        indexmap::IndexMap::new,
-                        |mut map: indexmap::IndexMap<Value, Vec<usize>>, (val, i)| {
-                            map.entry(val).or_default().push(i);
-                            map
-                        },
-                    )
-                    .reduce(indexmap::IndexMap::new, |mut acc, map| {
-                        for (key, value) in map {
-                            acc.entry(key).or_default().extend(value);
-                        }
-                        acc
-                    });
-
-                let self_on_series = self.get_column(on_column).unwrap();
-                let collected_rows: Vec<Vec<(String, Option<Value>)>> = (0..self.row_count())
-                    .into_par_iter()
-                    .flat_map(|i| {
-                        if let Some(self_join_val) = self_on_series.get_value(i) {
-                            if let Some(other_indices) = other_join_map.get(&self_join_val) {
-                                let self_col_names_cloned = self_col_names.clone();
-                                let all_column_names_cloned = all_column_names.clone();
-                                let _other_col_names_cloned = other_col_names.clone();
-                                other_indices
-                                    .par_iter()
-                                    .map(move |&other_idx| {
-                                        let mut row_values = Vec::new();
-                                        for col_name in all_column_names_cloned.iter() {
-                                            let value = if self_col_names_cloned.contains(col_name)
-                                            {
-                                                self.get_column(col_name).unwrap().get_value(i)
-                                            } else {
-                                                other
-                                                    .get_column(col_name)
-                                                    .unwrap()
-                                                    .get_value(other_idx)
-                                            };
-                                            row_values.push((col_name.clone(), value));
-                                        }
-                                        row_values
-                                    })
-                                    .collect::<Vec<_>>()
-                            } else {
-                                let all_column_names_cloned = all_column_names.clone();
-                                let self_col_names_cloned = self_col_names.clone();
-                                let mut row_values = Vec::new();
-                                for col_name in all_column_names_cloned.iter() {
-                                    let value = if self_col_names_cloned.contains(col_name) {
-                                        self.get_column(col_name).unwrap().get_value(i)
-                                    } else {
-                                        None
-                                    };
-                                    row_values.push((col_name.clone(), value));
-                                }
-                                vec![row_values]
-                            }
-                        } else {
-                            let all_column_names_cloned = all_column_names.clone();
-                            let self_col_names_cloned = self_col_names.clone();
-                            let mut row_values = Vec::new();
-                            for col_name in all_column_names_cloned.iter() {
-                                let value = if self_col_names_cloned.contains(col_name) {
-                                    self.get_column(col_name).unwrap().get_value(i)
-                                } else {
-                                    None
-                                };
-                                row_values.push((col_name.clone(), value));
-                            }
-                            vec![row_values]
-                        }
-                    })
-                    .collect();
-
-                for row_values in collected_rows {
-                    for (col_name, value) in row_values {
-                        series_data.get_mut(&col_name).unwrap().push(value);
+                Ordering::Greater => {
+                    if keep_right {
+                        pairs.push((None, Some(j)));
                     }
+                    j += 1;
                 }
-            }
-            JoinType::Right => {
-                let self_on_series = self.get_column(on_column).unwrap();
-                let self_join_map: indexmap::IndexMap<Value, Vec<usize>> = (0..self.row_count())
-                    .into_par_iter()
-                    .filter_map(|i| self_on_series.get_value(i).map(|val| (val, i)))
-                    .fold(
-                        indexmap::IndexMap::new,
-                        |mut map: indexmap::IndexMap<Value, Vec<usize>>, (val, i)| {
-                            map.entry(val).or_default().push(i);
-                            map
-                        },
-                    )
-                    .reduce(indexmap::IndexMap::new, |mut acc, map| {
-                        for (key, value) in map {
-                            acc.entry(key).or_default().extend(value);
-                        }
-                        acc
-                    });
-
-                let other_on_series = other.get_column(on_column).unwrap();
-                let collected_rows: Vec<Vec<(String, Option<Value>)>> = (0..other.row_count())
-                    .into_par_iter()
-                    .flat_map(|i| {
-                        if let Some(other_join_val) = other_on_series.get_value(i) {
-                            if let Some(self_indices) = self_join_map.get(&other_join_val) {
-                                let other_col_names_cloned = other_col_names.clone();
-                                let all_column_names_cloned = all_column_names.clone();
-                                let _self_col_names_cloned = self_col_names.clone();
-                                self_indices
-                                    .par_iter()
-                                    .map(move |&self_idx| {
-                                        let mut row_values = Vec::new();
-                                        for col_name in all_column_names_cloned.iter() {
-                                            let value = if other_col_names_cloned.contains(col_name)
-                                            {
-                                                other.get_column(col_name).unwrap().get_value(i)
-                                            } else {
-                                                self.get_column(col_name)
-                                                    .unwrap()
-                                                    .get_value(self_idx)
-                                            };
-                                            row_values.push((col_name.clone(), value));
-                                        }
-                                        row_values
-                                    })
-                                    .collect::<Vec<_>>()
-                            } else {
-                                let all_column_names_cloned = all_column_names.clone();
-                                let other_col_names_cloned = other_col_names.clone();
-                                let mut row_values = Vec::new();
-                                for col_name in all_column_names_cloned.iter() {
-                                    let value = if other_col_names_cloned.contains(col_name) {
-                                        other.get_column(col_name).unwrap().get_value(i)
-                                    } else {
-                                        None
-                                    };
-                                    row_values.push((col_name.clone(), value));
-                                }
-                                vec![row_values]
-                            }
-                        } else {
-                            let all_column_names_cloned = all_column_names.clone();
-                            let other_col_names_cloned = other_col_names.clone();
-                            let mut row_values = Vec::new();
-                            for col_name in all_column_names_cloned.iter() {
-                                let value = if other_col_names_cloned.contains(col_name) {
-                                    other.get_column(col_name).unwrap().get_value(i)
-                                } else {
-                                    None
-                                };
-                                row_values.push((col_name.clone(), value));
-                            }
-                            vec![row_values]
+                Ordering::Equal => {
+                    let i_end = key_run_end(&left_keys, i);
+                    let j_end = key_run_end(&right_keys, j);
+                    for l in i..i_end {
+                        for r in j..j_end {
+                            pairs.push((Some(l), Some(r)));
                         }
-                    })
-                    .collect();
-
-                for row_values in collected_rows {
-                    for (col_name, value) in row_values {
-                        series_data.get_mut(&col_name).unwrap().push(value);
                     }
+                    i = i_end;
+                    j = j_end;
                 }
             }
-            JoinType::Outer => {
-                // Full Outer Join implementation
-                // Strategy:
-                // 1. Perform Left Outer Join logic
-                // 2. Track which keys from Right DataFrame were matched
-                // 3. Append rows from Right DataFrame that were NOT matched
+        }
 
-                let other_on_series = other.get_column(on_column).unwrap();
-                // Build map of Right DataFrame keys -> indices
-                let other_join_map: indexmap::IndexMap<Value, Vec<usize>> = (0..other.row_count())
-                    .into_par_iter()
-                    .filter_map(|i| other_on_series.get_value(i).map(|val| (val, i)))
-                    .fold(
-                        indexmap::IndexMap::new,
-                        |mut map: indexmap::IndexMap<Value, Vec<usize>>, (val, i)| {
-                            map.entry(val).or_default().push(i);
-                            map
-                        },
-                    )
-                    .reduce(indexmap::IndexMap::new, |mut acc, map| {
-                        for (key, value) in map {
-                            acc.entry(key).or_default().extend(value);
-                        }
-                        acc
-                    });
+        self.assemble_joined_rows(other, &pairs, join_type == JoinType::Right)
+    }
 
-                // Keep track of matched right indices to handle the "Right Anti" part later
-                // Using a thread-safe structure or collecting matched indices
-                // Since we are doing parallel processing, collecting all matched indices might be expensive?
-                // Alternative: Use a DashSet or concurrent bitset?
-                // Or just collect locally and merge.
+    /// Builds the joined frame from `(left_row, right_row)` pairs: the left columns followed by
+    /// the right-only columns. A shared key column takes its value from the left row unless
+    /// there is none or `prefer_right` is set and there is a right row.
+    fn assemble_joined_rows(
+        &self,
+        other: &DataFrame,
+        pairs: &[(Option<usize>, Option<usize>)],
+        prefer_right: bool,
+    ) -> Result<Self, VeloxxError> {
+        let column_names = self.columns.keys().chain(
+            other
+                .columns
+                .keys()
+                .filter(|name| !self.columns.contains_key(*name)),
+        );
+        let mut new_columns: IndexMap<String, Series> = IndexMap::new();
+        for name in column_names {
+            let left = self.get_column(name);
+            let right = other.get_column(name);
+            let values: Vec<Option<Value>> = pairs
+                .iter()
+                .map(|&(l, r)| {
+                    let use_right = match (left, right) {
+                        (Some(_), Some(_)) => l.is_none() || (prefer_right && r.is_some()),
+                        (None, Some(_)) => true,
+                        _ => false,
+                    };
+                    if use_right {
+                        r.and_then(|r| right.and_then(|series| series.get_value(r)))
+                    } else {
+                        l.and_then(|l| left.and_then(|series| series.get_value(l)))
+                    }
+                })
+                .collect();
+            let data_type = left.or(right).map(|series| series.data_type()).unwrap();
+            new_columns.insert(name.clone(), Series::from_values(name, values, data_type)?);
+        }
 
-                // We'll process Left Join part and collect matched right indices
-                let self_on_series = self.get_column(on_column).unwrap();
+        Ok(DataFrame::new(new_columns))
+    }
 
-                // Process Left side (Left Outer Join)
-                let collected_results: Vec<(Vec<Vec<(String, Option<Value>)>>, Vec<usize>)> = (0
-                    ..self.row_count())
-                    .into_par_iter()
-                    .map(|i| {
-                        let mut matched_indices = Vec::new();
-                        let mut rows = Vec::new();
+    /// Keeps the left rows whose non-null key is (`keep_matched`) or is not present in `other`.
+    fn filter_by_key_match(
+        &self,
+        other: &DataFrame,
+        on: &[&str],
+        keep_matched: bool,
+    ) -> Result<Self, VeloxxError> {
+        let left_keys = key_columns(self, on)?;
+        let right_index = build_join_index(&key_columns(other, on)?);
+        let rows: Vec<usize> = (0..self.row_count())
+            .into_par_iter()
+            .filter(|&i| {
+                let matched =
+                    join_key(&left_keys, i).is_some_and(|key| right_index.contains_key(&key));
+                matched == keep_matched
+            })
+            .collect();
 
-                        if let Some(self_join_val) = self_on_series.get_value(i) {
-                            if let Some(other_indices) = other_join_map.get(&self_join_val) {
-                                matched_indices.extend(other_indices.iter().cloned());
-                                for &other_idx in other_indices {
-                                    let mut row_values = Vec::new();
-                                    for col_name in &all_column_names {
-                                        let value = if self_col_names.contains(col_name) {
-                                            self.get_column(col_name).unwrap().get_value(i)
-                                        } else {
-                                            other.get_column(col_name).unwrap().get_value(other_idx)
-                                        };
-                                        row_values.push((col_name.clone(), value));
-                                    }
-                                    rows.push(row_values);
-                                }
-                            } else {
-                                // No match in right, emit Left row with nulls
-                                let mut row_values = Vec::new();
-                                for col_name in &all_column_names {
-                                    let value = if self_col_names.contains(col_name) {
-                                        self.get_column(col_name).unwrap().get_value(i)
-                                    } else {
-                                        None
-                                    };
-                                    row_values.push((col_name.clone(), value));
-                                }
-                                rows.push(row_values);
-                            }
-                        } else {
-                            // Null key in left, emit Left row with nulls (assuming null != null for join usually, standard SQL behavior)
-                            // If we want null=null matching, we'd handle it above. Here we assume strict equality.
-                            let mut row_values = Vec::new();
-                            for col_name in &all_column_names {
-                                let value = if self_col_names.contains(col_name) {
-                                    self.get_column(col_name).unwrap().get_value(i)
-                                } else {
-                                    None
-                                };
-                                row_values.push((col_name.clone(), value));
-                            }
-                            rows.push(row_values);
-                        }
-                        (rows, matched_indices)
-                    })
-                    .collect::<Vec<_>>();
+        let mut new_columns: IndexMap<String, Series> = IndexMap::new();
+        for (name, series) in &self.columns {
+            new_columns.insert(name.clone(), series.filter(&rows)?);
+        }
+        Ok(DataFrame::new(new_columns))
+    }
+}
 
-                // Sequential post-processing
-                let mut collected_rows = Vec::new();
-                let mut matched_right_set = std::collections::HashSet::new();
+/// Rejects non-key columns present in both frames, whose values a join would otherwise merge
+/// into a single output column.
+fn check_column_collisions(
+    left: &DataFrame,
+    right: &DataFrame,
+    on: &[&str],
+) -> Result<(), VeloxxError> {
+    let shared: Vec<&str> = left
+        .columns
+        .keys()
+        .filter(|name| !on.contains(&name.as_str()) && right.columns.contains_key(*name))
+        .map(|name| name.as_str())
+        .collect();
+    if shared.is_empty() {
+        return Ok(());
+    }
+    Err(VeloxxError::InvalidOperation(format!(
+        "Cannot join on '{}': both DataFrames have column(s) {}; rename them on one side or use join_with_suffixes.",
+        on.join(", "),
+        shared.join(", ")
+    )))
+}
 
-                for (rows, indices) in collected_results {
-                    collected_rows.extend(rows);
-                    matched_right_set.extend(indices);
-                }
+/// Copies `df`, renaming each of the `shared` columns to `{name}{suffix}`, or fails if a
+/// renamed column would collide with another column of `df`.
+fn with_suffixed_columns(
+    df: &DataFrame,
+    shared: &[&str],
+    suffix: &str,
+) -> Result<DataFrame, VeloxxError> {
+    let mut columns: IndexMap<String, Series> = IndexMap::with_capacity(df.columns.len());
+    for (name, series) in &df.columns {
+        let mut series = series.clone();
+        let new_name = if shared.contains(&name.as_str()) {
+            let new_name = format!("{name}{suffix}");
+            series.set_name(&new_name);
+            new_name
+        } else {
+            name.clone()
+        };
+        if columns.contains_key(&new_name) {
+            return Err(VeloxxError::InvalidOperation(format!(
+                "Join suffix '{suffix}' produces the duplicate column name '{new_name}'."
+            )));
+        }
+        columns.insert(new_name, series);
+    }
+    Ok(DataFrame::new(columns))
+}
 
-                // Append Right rows that were not matched
-                for i in 0..other.row_count() {
-                    if !matched_right_set.contains(&i) {
-                        let mut row_values = Vec::new();
-                        for col_name in &all_column_names {
-                            let value = if other_col_names.contains(col_name) {
-                                other.get_column(col_name).unwrap().get_value(i)
-                            } else {
-                                None // Not in Right (so it's a Left column), set to Null
-                            };
-                            row_values.push((col_name.clone(), value));
-                        }
-                        // Add to collected_rows? No, we can directly push to series_data or append to collected_rows
-                        // Pushing to series_data is non-trivial because collected_rows is waiting to be pushed.
-                        // Better to push everything to series_data at once or append here.
-                        // collected_rows is consumed below.
-                        // We can't append to collected_rows easily because it's Vec of Vec.
-                        // Actually we can.
-                        // But wait, `collected_rows` is immutable from the unzip. We need to make it mutable or chain.
-                        // Let's just process this loop into series_data directly?
-                        // No, consistent processing is better.
+/// Looks up the key columns `on` in `df`.
+/// Looks up the `on` key columns of both frames, checking that each has the same type on
+/// both sides.
+fn matching_key_columns<'a>(
+    left: &'a DataFrame,
+    right: &'a DataFrame,
+    on: &[&str],
+) -> Result<(Vec<&'a Series>, Vec<&'a Series>), VeloxxError> {
+    let mut left_keys = Vec::with_capacity(on.len());
+    let mut right_keys = Vec::with_capacity(on.len());
+    for &name in on {
+        let left_key = left.get_column(name).ok_or_else(|| {
+            VeloxxError::ColumnNotFound(format!(
+                "Join column '{name}' not found in left DataFrame."
+            ))
+        })?;
+        let right_key = right.get_column(name).ok_or_else(|| {
+            VeloxxError::ColumnNotFound(format!(
+                "Join column '{name}' not found in right DataFrame."
+            ))
+        })?;
+        if left_key.data_type() != right_key.data_type() {
+            return Err(VeloxxError::DataTypeMismatch(format!(
+                "Join column '{name}' is {:?} in the left DataFrame but {:?} in the right DataFrame.",
+                left_key.data_type(),
+                right_key.data_type()
+            )));
+        }
+        left_keys.push(left_key);
+        right_keys.push(right_key);
+    }
+    Ok((left_keys, right_keys))
+}
 
-                        // Let's add these to a separate vector and process both.
-                    }
-                }
+fn key_columns<'a>(df: &'a DataFrame, on: &[&str]) -> Result<Vec<&'a Series>, VeloxxError> {
+    on.iter()
+        .map(|name| {
+            df.get_column(name)
+                .ok_or_else(|| VeloxxError::ColumnNotFound(name.to_string()))
+        })
+        .collect()
+}
 
-                // Actually, let's gather right-only rows separately
-                let right_only_rows: Vec<Vec<(String, Option<Value>)>> = (0..other.row_count())
-                    .into_par_iter()
-                    .filter(|i| !matched_right_set.contains(i))
-                    .map(|i| {
-                        let mut row_values = Vec::new();
-                        for col_name in &all_column_names {
-                            let value = if other_col_names.contains(col_name) {
-                                other.get_column(col_name).unwrap().get_value(i)
-                            } else {
-                                None
-                            };
-                            row_values.push((col_name.clone(), value));
-                        }
-                        row_values
-                    })
-                    .collect();
+/// Reads the composite key of `row`, or `None` if any key column is null there.
+fn join_key(key_columns: &[&Series], row: usize) -> Option<Vec<Value>> {
+    key_columns
+        .iter()
+        .map(|series| series.get_value(row))
+        .collect()
+}
 
-                // Populate series_data
-                for row_values in collected_rows {
-                    for (col_name, value) in row_values {
-                        series_data.get_mut(&col_name).unwrap().push(value);
-                    }
-                }
-                for row_values in right_only_rows {
-                    for (col_name, value) in row_values {
-                        series_data.get_mut(&col_name).unwrap().push(value);
-                    }
-                }
+/// Maps each non-null composite key to the rows holding it, in ascending row order.
+fn build_join_index(key_columns: &[&Series]) -> IndexMap<Vec<Value>, Vec<usize>> {
+    let row_count = key_columns.first().map_or(0, |series| series.len());
+    let mut index: IndexMap<Vec<Value>, Vec<usize>> = (0..row_count)
+        .into_par_iter()
+        .filter_map(|i| join_key(key_columns, i).map(|key| (key, i)))
+        .fold(
+            IndexMap::new,
+            |mut map: IndexMap<Vec<Value>, Vec<usize>>, (key, i)| {
+                map.entry(key).or_default().push(i);
+                map
+            },
+        )
+        .reduce(IndexMap::new, |mut acc, map| {
+            for (key, value) in map {
+                acc.entry(key).or_default().extend(value);
             }
-        }
+            acc
+        });
+    // Partial maps may be merged in any order; keep matches in row order for stable output.
+    for rows in index.values_mut() {
+        rows.sort_unstable();
+    }
+    index
+}
 
-        // Create new Series objects
-        for (col_name, data_vec) in series_data {
-            let col_data_type = column_types.get(&col_name).unwrap();
-            let new_series = match col_data_type {
-                crate::types::DataType::I32 => Series::new_i32(
-                    &col_name,
-                    data_vec
-                        .into_iter()
-                        .map(|x| {
-                            x.and_then(|v| {
-                                if let Value::I32(val) = v {
-                                    Some(val)
-                                } else {
-                                    None
-                                }
-                            })
-                        })
-                        .collect(),
-                ),
-                crate::types::DataType::F64 => Series::new_f64(
-                    &col_name,
-                    data_vec
-                        .into_iter()
-                        .map(|x| {
-                            x.and_then(|v| {
-                                if let Value::F64(val) = v {
-                                    Some(val)
-                                } else {
-                                    None
-                                }
-                            })
-                        })
-                        .collect(),
-                ),
-                crate::types::DataType::Bool => Series::new_bool(
-                    &col_name,
-                    data_vec
-                        .into_iter()
-                        .map(|x| {
-                            x.and_then(|v| {
-                                if let Value::Bool(val) = v {
-                                    Some(val)
-                                } else {
-                                    None
-                                }
-                            })
-                        })
-                        .collect(),
-                ),
-                crate::types::DataType::String => Series::new_string(
-                    &col_name,
-                    data_vec
-                        .into_iter()
-                        .map(|x| {
-                            x.and_then(|v| {
-                                if let Value::String(val) = v {
-                                    Some(val)
-                                } else {
-                                    None
-                                }
-                            })
-                        })
-                        .collect(),
-                ),
-                crate::types::DataType::DateTime => Series::new_datetime(
-                    &col_name,
-                    data_vec
-                        .into_iter()
-                        .map(|x| {
-                            x.and_then(|v| {
-                                if let Value::DateTime(val) = v {
-                                    Some(val)
-                                } else {
-                                    None
-                                }
-                            })
-                        })
-                        .collect(),
-                ),
-            };
-            new_columns.insert(col_name, new_series);
-        }
+/// Reads the join keys of `df`, checking that they are sorted ascending with nulls first.
+fn sorted_join_keys(
+    df: &DataFrame,
+    on_column: &str,
+    side: &str,
+) -> Result<Vec<Option<Value>>, VeloxxError> {
+    let series = df.get_column(on_column).ok_or_else(|| {
+        VeloxxError::ColumnNotFound(format!(
+            "Join column '{on_column}' not found in {side} DataFrame."
+        ))
+    })?;
+    df.assert_sorted(on_column, true).map_err(|e| match e {
+        VeloxxError::InvalidOperation(reason) => VeloxxError::InvalidOperation(format!(
+            "Sort-merge join requires the {side} DataFrame to be sorted ascending on '{on_column}': {reason}"
+        )),
+        other => other,
+    })?;
+    Ok((0..series.len()).map(|i| series.get_value(i)).collect())
+}
 
-        Ok(DataFrame::new(new_columns))
+/// Returns the end (exclusive) of the run of keys equal to `keys[start]`.
+fn key_run_end(keys: &[Option<Value>], start: usize) -> usize {
+    let mut end = start + 1;
+    while end < keys.len() && keys[end] == keys[start] {
+        end += 1;
     }
+    end
 }
@@ -31,7 +31,7 @@ use crate::{
     conditions::Condition,
     dataframe::DataFrame,
     expressions::Expr,
-    series::Series,
+    series::{Interpolation, Series},
     types::{DataType, Value},
 };
 use indexmap::IndexMap;
@@ -85,10 +85,53 @@ impl DataFrame {
         Ok(DataFrame::new(selected_columns))
     }
 
+    /// Selects the columns whose names satisfy `predicate`, keeping their original order.
+    ///
+    /// If no column matches, the result is an empty `DataFrame` with no columns rather than
+    /// an error, so callers can check `column_count()` when a match is required.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::series::Series;
+    /// use indexmap::IndexMap;
+    ///
+    /// let mut columns = IndexMap::new();
+    /// columns.insert("id".to_string(), Series::new_i32("id", vec![Some(1)]));
+    /// columns.insert("feature_a".to_string(), Series::new_f64("feature_a", vec![Some(0.5)]));
+    /// columns.insert("feature_b".to_string(), Series::new_f64("feature_b", vec![Some(1.5)]));
+    /// let df = DataFrame::new(columns);
+    ///
+    /// let features = df.select_matching(|name| name.starts_with("feature_")).unwrap();
+    /// assert_eq!(features.column_names(), vec!["feature_a", "feature_b"]);
+    /// ```
+    pub fn select_matching(&self, predicate: impl Fn(&str) -> bool) -> Result<Self, VeloxxError> {
+        let names: Vec<String> = self
+            .columns
+            .keys()
+            .filter(|name| predicate(name))
+            .cloned()
+            .collect();
+        self.select_columns(names)
+    }
+
+    /// Selects the columns whose names match the regular expression `pattern`.
+    ///
+    /// Behaves like [`DataFrame::select_matching`], returning an empty `DataFrame` when
+    /// nothing matches, and `Err(VeloxxError::InvalidOperation)` if `pattern` is not a
+    /// valid regular expression.
+    #[cfg(feature = "data_quality")]
+    pub fn select_regex(&self, pattern: &str) -> Result<Self, VeloxxError> {
+        let regex = regex::Regex::new(pattern)
+            .map_err(|e| VeloxxError::InvalidOperation(format!("Invalid regex pattern: {}", e)))?;
+        self.select_matching(|name| regex.is_match(name))
+    }
+
     /// Drops specified columns from the `DataFrame`.
     ///
-    /// This method creates a new `DataFrame` with the specified columns removed.
-    /// The original DataFrame remains unchanged.
+    /// This method creates a new `DataFrame` with the specified columns removed; the
+    /// remaining columns keep their original order. The original DataFrame remains unchanged.
     ///
     /// # Arguments
     ///
@@ -110,16 +153,15 @@ impl DataFrame {
     /// columns.insert("A".to_string(), Series::new_i32("A", vec![Some(1), Some(2)]));
     /// columns.insert("B".to_string(), Series::new_f64("B", vec![Some(1.1), Some(2.2)]));
     /// columns.insert("C".to_string(), Series::new_string("C", vec![Some("x".to_string()), Some("y".to_string())]));
-    /// let df = DataFrame::new(columns).unwrap();
+    /// let df = DataFrame::new(columns);
     ///
     /// let dropped_df = df.drop_columns(vec!["B".to_string()]).unwrap();
-    /// assert_eq!(dropped_df.column_count(), 2);
-    /// assert!(!dropped_df.column_names().contains(&&"B".to_string()));
+    /// assert_eq!(dropped_df.column_names(), vec!["A", "C"]);
     /// ```
     pub fn drop_columns(&self, names: Vec<String>) -> Result<Self, VeloxxError> {
         let mut new_columns: IndexMap<String, Series> = self.columns.clone();
         for name in names {
-            if new_columns.swap_remove(&name).is_none() {
+            if new_columns.shift_remove(&name).is_none() {
                 return Err(VeloxxError::ColumnNotFound(name));
             }
         }
@@ -129,7 +171,8 @@ impl DataFrame {
     /// Renames a column in the `DataFrame`.
     ///
     /// This method creates a new `DataFrame` with the specified column renamed.
-    /// The original DataFrame remains unchanged.
+    /// The renamed column keeps its original position, and the original DataFrame
+    /// remains unchanged.
     ///
     /// # Arguments
     ///
@@ -152,34 +195,48 @@ impl DataFrame {
     /// let mut columns = IndexMap::new();
     /// columns.insert("A".to_string(), Series::new_i32("A", vec![Some(1), Some(2)]));
     /// columns.insert("B".to_string(), Series::new_f64("B", vec![Some(1.1), Some(2.2)]));
-    /// let df = DataFrame::new(columns).unwrap();
+    /// let df = DataFrame::new(columns);
     ///
     /// let renamed_df = df.rename_column("A", "Alpha").unwrap();
-    /// assert!(renamed_df.column_names().contains(&&"Alpha".to_string()));
-    /// assert!(!renamed_df.column_names().contains(&&"A".to_string()));
+    /// assert_eq!(renamed_df.column_names(), vec!["Alpha", "B"]);
     /// ```
     pub fn rename_column(&self, old_name: &str, new_name: &str) -> Result<Self, VeloxxError> {
-        let mut new_columns: IndexMap<String, Series> = self.columns.clone();
-        if let Some(mut series) = new_columns.swap_remove(old_name) {
-            if new_columns.contains_key(new_name) {
-                return Err(VeloxxError::InvalidOperation(format!(
-                    "Column with new name '{new_name}' already exists."
-                )));
-            }
-            series.set_name(new_name);
-            new_columns.insert(new_name.to_string(), series);
-            Ok(DataFrame::new(new_columns))
-        } else {
-            Err(VeloxxError::ColumnNotFound(old_name.to_string()))
+        let Some(position) = self.columns.get_index_of(old_name) else {
+            return Err(VeloxxError::ColumnNotFound(old_name.to_string()));
+        };
+        if new_name != old_name && self.columns.contains_key(new_name) {
+            return Err(VeloxxError::InvalidOperation(format!(
+                "Column with new name '{new_name}' already exists."
+            )));
         }
+        let new_columns: IndexMap<String, Series> = self
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(i, (name, series))| {
+                if i == position {
+                    let mut series = series.clone();
+                    series.set_name(new_name);
+                    (new_name.to_string(), series)
+                } else {
+                    (name.clone(), series.clone())
+                }
+            })
+            .collect();
+        Ok(DataFrame::new(new_columns))
     }
 
     /// Sorts the `DataFrame` by one or more columns.
     ///
     /// This method creates a new `DataFrame` with rows sorted according to the values
     /// in the specified `by_columns`. Sorting is performed lexicographically for strings,
-    /// numerically for numbers, and chronologically for DateTime values. Null values
-    /// are always sorted first.
+    /// numerically for numbers, and chronologically for DateTime values. By default nulls
+    /// come first when ascending and last when descending; use
+    /// [`DataFrame::sort_with_nulls`] to place them explicitly.
+    ///
+    /// The sort is stable. Sorting by a single `I32` column takes a fast path that radix-sorts
+    /// row indices against the raw column values instead of comparing materialized rows. Use
+    /// [`DataFrame::sort_by`] to choose the direction of each column separately.
     ///
     /// # Arguments
     ///
@@ -215,10 +272,108 @@ impl DataFrame {
     /// assert_eq!(sorted_df_name_desc.get_column("name").unwrap().get_value(0), Some(Value::String("Charlie".to_string())));
     /// ```
     pub fn sort(&self, by_columns: Vec<String>, ascending: bool) -> Result<Self, VeloxxError> {
+        let by: Vec<(String, bool)> = by_columns
+            .into_iter()
+            .map(|column| (column, ascending))
+            .collect();
+        self.sort_by(&by)
+    }
+
+    /// Sorts the `DataFrame` by one or more columns, each in its own direction.
+    ///
+    /// Each `(column, ascending)` pair is a sort key in priority order, so
+    /// `[("date", true), ("score", false)]` sorts by date ascending and breaks ties by score
+    /// descending. As in [`DataFrame::sort`], the sort is stable and null values sort first in
+    /// an ascending column (last in a descending one).
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok(DataFrame)` containing a new sorted `DataFrame`,
+    /// or `Err(VeloxxError::ColumnNotFound)` if any of the columns do not exist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::series::Series;
+    /// use indexmap::IndexMap;
+    /// use veloxx::types::Value;
+    ///
+    /// let mut columns = IndexMap::new();
+    /// columns.insert("date".to_string(), Series::new_i32("date", vec![Some(2), Some(1), Some(1)]));
+    /// columns.insert("score".to_string(), Series::new_f64("score", vec![Some(5.0), Some(3.0), Some(9.0)]));
+    /// let df = DataFrame::new(columns);
+    ///
+    /// let sorted = df
+    ///     .sort_by(&[("date".to_string(), true), ("score".to_string(), false)])
+    ///     .unwrap();
+    /// assert_eq!(sorted.get_column("score").unwrap().get_value(0), Some(Value::F64(9.0)));
+    /// assert_eq!(sorted.get_column("score").unwrap().get_value(1), Some(Value::F64(3.0)));
+    /// ```
+    pub fn sort_by(&self, by: &[(String, bool)]) -> Result<Self, VeloxxError> {
+        let keys: Vec<(&str, bool, bool)> = by
+            .iter()
+            .map(|(column, ascending)| (column.as_str(), *ascending, !*ascending))
+            .collect();
+        self.sort_keys(&keys)
+    }
+
+    /// Sorts the `DataFrame` by one or more columns, choosing where null values go.
+    ///
+    /// This is [`DataFrame::sort`] with explicit null placement: with `nulls_last` nulls end
+    /// up after every value, otherwise before, in either direction. [`DataFrame::sort`] keeps
+    /// nulls first when ascending and last when descending, which is
+    /// `sort_with_nulls(by_columns, ascending, !ascending)`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok(DataFrame)` containing a new sorted `DataFrame`,
+    /// or `Err(VeloxxError::ColumnNotFound)` if any of the `by_columns` do not exist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::series::Series;
+    /// use indexmap::IndexMap;
+    /// use veloxx::types::Value;
+    ///
+    /// let mut columns = IndexMap::new();
+    /// columns.insert("score".to_string(), Series::new_i32("score", vec![None, Some(2), Some(1)]));
+    /// let df = DataFrame::new(columns);
+    ///
+    /// let sorted = df.sort_with_nulls(vec!["score".to_string()], true, true).unwrap();
+    /// assert_eq!(sorted.get_column("score").unwrap().get_value(0), Some(Value::I32(1)));
+    /// assert_eq!(sorted.get_column("score").unwrap().get_value(2), None);
+    /// ```
+    pub fn sort_with_nulls(
+        &self,
+        by_columns: Vec<String>,
+        ascending: bool,
+        nulls_last: bool,
+    ) -> Result<Self, VeloxxError> {
+        let keys: Vec<(&str, bool, bool)> = by_columns
+            .iter()
+            .map(|column| (column.as_str(), ascending, nulls_last))
+            .collect();
+        self.sort_keys(&keys)
+    }
+
+    /// Sorts by `(column, ascending, nulls_last)` keys in priority order.
+    fn sort_keys(&self, by: &[(&str, bool, bool)]) -> Result<Self, VeloxxError> {
         if self.row_count() == 0 {
             return Ok(self.clone());
         }
 
+        if let [(column, ascending, nulls_last)] = by {
+            if let Some(Series::I32(_, values, validity)) = self.columns.get(*column) {
+                if self.columns.values().all(|s| s.len() == values.len()) {
+                    let order = radix_sort_i32_indices(values, validity, *ascending, *nulls_last);
+                    return self.filter_by_indices(&order);
+                }
+            }
+        }
+
         let mut rows: Vec<Vec<Option<Value>>> = Vec::with_capacity(self.row_count());
         for i in 0..self.row_count() {
             let mut row: Vec<Option<Value>> = Vec::with_capacity(self.column_count());
@@ -229,12 +384,13 @@ impl DataFrame {
             rows.push(row);
         }
 
-        let column_indices: Result<Vec<usize>, VeloxxError> = by_columns
+        let column_indices: Result<Vec<(usize, bool, bool)>, VeloxxError> = by
             .iter()
-            .map(|col_name| {
+            .map(|&(col_name, ascending, nulls_last)| {
                 self.column_names()
                     .iter()
                     .position(|name| name == col_name)
+                    .map(|col_idx| (col_idx, ascending, nulls_last))
                     .ok_or(VeloxxError::ColumnNotFound(format!(
                         "Column '{col_name}' not found for sorting."
                     )))
@@ -244,22 +400,29 @@ impl DataFrame {
         let column_indices = column_indices?;
 
         rows.sort_by(|a, b| {
-            for &col_idx in column_indices.iter() {
+            for &(col_idx, ascending, nulls_last) in column_indices.iter() {
                 let val_a = &a[col_idx];
                 let val_b = &b[col_idx];
 
                 let cmp = match (val_a, val_b) {
-                    (Some(Value::I32(v_a)), Some(Value::I32(v_b))) => v_a.cmp(v_b),
-                    (Some(Value::F64(v_a)), Some(Value::F64(v_b))) => {
-                        v_a.partial_cmp(v_b).unwrap_or(std::cmp::Ordering::Equal)
-                    }
-                    (Some(Value::Bool(v_a)), Some(Value::Bool(v_b))) => v_a.cmp(v_b),
-                    (Some(Value::String(v_a)), Some(Value::String(v_b))) => v_a.cmp(v_b),
-                    (Some(Value::DateTime(v_a)), Some(Value::DateTime(v_b))) => v_a.cmp(v_b),
+                    // `Value`'s total order, so NaN sorts after every other number
+                    (Some(v_a), Some(v_b)) => v_a.cmp(v_b),
                     (None, None) => std::cmp::Ordering::Equal,
-                    (None, Some(_)) => std::cmp::Ordering::Less, // Nulls come first
-                    (Some(_), None) => std::cmp::Ordering::Greater, // Non-nulls come after nulls
-                    _ => panic!("Mismatched types during comparison for sorting."),
+                    // Null placement does not depend on the sort direction.
+                    (None, Some(_)) => {
+                        return if nulls_last {
+                            std::cmp::Ordering::Greater
+                        } else {
+                            std::cmp::Ordering::Less
+                        };
+                    }
+                    (Some(_), None) => {
+                        return if nulls_last {
+                            std::cmp::Ordering::Less
+                        } else {
+                            std::cmp::Ordering::Greater
+                        };
+                    }
                 };
 
                 if cmp != std::cmp::Ordering::Equal {
@@ -302,6 +465,21 @@ impl DataFrame {
                         })
                         .collect(),
                 ),
+                crate::types::DataType::I64 => Series::new_i64(
+                    &col_name,
+                    data_vec
+                        .into_iter()
+                        .map(|x| {
+                            x.and_then(|v| {
+                                if let Value::I64(val) = v {
+                                    Some(val)
+                                } else {
+                                    None
+                                }
+                            })
+                        })
+                        .collect(),
+                ),
                 crate::types::DataType::F64 => Series::new_f64(
                     &col_name,
                     data_vec
@@ -369,6 +547,132 @@ impl DataFrame {
         Ok(DataFrame::new(new_series_map))
     }
 
+    /// Checks that `column` is sorted, failing at the first row that is out of order.
+    ///
+    /// The expected order is the one [`DataFrame::sort`] produces: nulls first when
+    /// `ascending`, last otherwise, with equal neighbours allowed. A NaN cannot be ordered and
+    /// always fails the check. Use this as an early guard before operations that assume a
+    /// sorted key, instead of getting silently wrong results.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the column is sorted, `Err(VeloxxError::ColumnNotFound)` if it does not
+    /// exist, or `Err(VeloxxError::InvalidOperation)` naming the first out-of-order row.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::series::Series;
+    /// use indexmap::IndexMap;
+    ///
+    /// let mut columns = IndexMap::new();
+    /// columns.insert("ts".to_string(), Series::new_i32("ts", vec![Some(1), Some(3), Some(2)]));
+    /// let df = DataFrame::new(columns);
+    ///
+    /// let err = df.assert_sorted("ts", true).unwrap_err();
+    /// assert!(err.to_string().contains("row 2"));
+    /// assert!(df.head(2).unwrap().assert_sorted("ts", true).is_ok());
+    /// ```
+    pub fn assert_sorted(&self, column: &str, ascending: bool) -> Result<(), VeloxxError> {
+        let series = self
+            .get_column(column)
+            .ok_or_else(|| VeloxxError::ColumnNotFound(column.to_string()))?;
+        let mut previous = series.get_value(0);
+        for row in 1..series.len() {
+            let current = series.get_value(row);
+            let is_nan = |value: &Option<Value>| matches!(value, Some(Value::F64(v)) if v.is_nan());
+            let ordering = match (&previous, &current) {
+                // `Value`'s total order puts NaN last, but a NaN key is never considered sorted.
+                _ if is_nan(&previous) || is_nan(&current) => None,
+                (Some(a), Some(b)) => a.cmp_numeric(b).or_else(|| a.partial_cmp(b)),
+                _ => previous.partial_cmp(&current),
+            };
+            let in_order = match ordering {
+                Some(std::cmp::Ordering::Equal) => true,
+                Some(std::cmp::Ordering::Less) => ascending,
+                Some(std::cmp::Ordering::Greater) => !ascending,
+                None => false,
+            };
+            if !in_order {
+                return Err(VeloxxError::InvalidOperation(format!(
+                    "Column '{column}' is not sorted {}: row {row} is out of order",
+                    if ascending { "ascending" } else { "descending" }
+                )));
+            }
+            previous = current;
+        }
+        Ok(())
+    }
+
+    /// Sorts the `DataFrame` by one or more computed expressions.
+    ///
+    /// Each expression is evaluated for every row to build the sort keys, so rows can be
+    /// ordered by derived values without adding and dropping a helper column. The sort is
+    /// stable, and as in [`DataFrame::sort`] null keys come first (last when descending).
+    /// Null keys come from expressions that yield `Value::Null`, such as a bare
+    /// [`Expr::Column`] over a null cell; arithmetic on a null operand is an error, so wrap
+    /// such operands in [`Expr::IfNull`] or [`Expr::Coalesce`] to give them a value.
+    ///
+    /// # Arguments
+    ///
+    /// * `exprs` - `(expression, ascending)` pairs in priority order.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok(DataFrame)` containing a new sorted `DataFrame`,
+    /// or `Err(VeloxxError)` if any expression cannot be evaluated for a row.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::series::Series;
+    /// use veloxx::expressions::Expr;
+    /// use veloxx::types::Value;
+    /// use indexmap::IndexMap;
+    ///
+    /// let mut columns = IndexMap::new();
+    /// columns.insert("open".to_string(), Series::new_i32("open", vec![Some(10), Some(20), Some(30)]));
+    /// columns.insert("close".to_string(), Series::new_i32("close", vec![Some(12), Some(11), Some(29)]));
+    /// let df = DataFrame::new(columns);
+    ///
+    /// // Largest move first
+    /// let delta = Expr::Subtract(
+    ///     Box::new(Expr::Column("open".to_string())),
+    ///     Box::new(Expr::Column("close".to_string())),
+    /// );
+    /// let sorted = df.sort_by_expr(vec![(delta, false)]).unwrap();
+    /// assert_eq!(sorted.get_column("open").unwrap().get_value(0), Some(Value::I32(20)));
+    /// ```
+    pub fn sort_by_expr(&self, exprs: Vec<(Expr, bool)>) -> Result<Self, VeloxxError> {
+        let row_count = self.row_count();
+        if row_count == 0 {
+            return Ok(self.clone());
+        }
+
+        let mut keys: Vec<Vec<Value>> = Vec::with_capacity(row_count);
+        for row in 0..row_count {
+            let row_keys = exprs
+                .iter()
+                .map(|(expr, _)| expr.evaluate(self, row))
+                .collect::<Result<Vec<_>, _>>()?;
+            keys.push(row_keys);
+        }
+
+        let mut order: Vec<usize> = (0..row_count).collect();
+        order.sort_by(|&a, &b| {
+            for (key_idx, (_, ascending)) in exprs.iter().enumerate() {
+                let cmp = keys[a][key_idx].cmp(&keys[b][key_idx]);
+                if cmp != std::cmp::Ordering::Equal {
+                    return if *ascending { cmp } else { cmp.reverse() };
+                }
+            }
+            std::cmp::Ordering::Equal
+        });
+        self.filter_by_indices(&order)
+    }
+
     /// Adds a new column to the `DataFrame` based on an expression.
     ///
     /// This method evaluates the provided `Expr` for each row in the DataFrame
@@ -384,7 +688,8 @@ impl DataFrame {
     /// # Returns
     ///
     /// A `Result` which is `Ok(DataFrame)` containing a new `DataFrame` with the added column,
-    /// or `Err(VeloxxError::InvalidOperation)` if a column with `new_col_name` already exists,
+    /// `Err(VeloxxError::InvalidOperation)` if a column with `new_col_name` already exists,
+    /// `Err(VeloxxError::DataTypeMismatch)` if the non-null results do not all share one type,
     /// or `Err(VeloxxError)` if the expression cannot be evaluated for any row.
     ///
     /// # Examples
@@ -426,18 +731,102 @@ impl DataFrame {
             )));
         }
 
+        let new_series = self.evaluate_column(new_col_name, expr)?;
+        new_columns.insert(new_col_name.to_string(), new_series);
+        Ok(DataFrame::new(new_columns))
+    }
+
+    /// Adds several columns computed from expressions in one step.
+    ///
+    /// Every expression is evaluated against this `DataFrame` as it is, so an expression
+    /// cannot refer to another column added in the same call. The new columns are appended
+    /// in the order given, with the same type rules as [`DataFrame::with_column`], and the
+    /// existing columns are copied once rather than once per new column.
+    ///
+    /// # Arguments
+    ///
+    /// * `exprs` - The `(name, expression)` pairs defining the new columns.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok(DataFrame)` containing a new `DataFrame` with the added columns,
+    /// `Err(VeloxxError::InvalidOperation)` if a name already exists or is used twice in
+    /// `exprs`, or `Err(VeloxxError)` if an expression cannot be evaluated for any row.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::series::Series;
+    /// use veloxx::expressions::Expr;
+    /// use veloxx::types::Value;
+    /// use indexmap::IndexMap;
+    ///
+    /// let mut columns = IndexMap::new();
+    /// columns.insert("price".to_string(), Series::new_f64("price", vec![Some(2.0), Some(5.0)]));
+    /// columns.insert("qty".to_string(), Series::new_f64("qty", vec![Some(3.0), Some(1.0)]));
+    /// let df = DataFrame::new(columns);
+    ///
+    /// let col = |name: &str| Box::new(Expr::Column(name.to_string()));
+    /// let df = df
+    ///     .with_columns(&[
+    ///         ("total", Expr::Multiply(col("price"), col("qty"))),
+    ///         ("bulk", Expr::GreaterThan(col("qty"), Box::new(Expr::Literal(Value::F64(2.0))))),
+    ///     ])
+    ///     .unwrap();
+    /// assert_eq!(df.column_names(), vec!["price", "qty", "total", "bulk"]);
+    /// assert_eq!(df.get_column("total").unwrap().get_value(0), Some(Value::F64(6.0)));
+    /// ```
+    pub fn with_columns(&self, exprs: &[(&str, Expr)]) -> Result<Self, VeloxxError> {
+        let mut added: indexmap::IndexMap<String, Series> = indexmap::IndexMap::new();
+        for (new_col_name, expr) in exprs {
+            if self.columns.contains_key(*new_col_name) {
+                return Err(VeloxxError::InvalidOperation(format!(
+                    "Column '{new_col_name}' already exists."
+                )));
+            }
+            if added.contains_key(*new_col_name) {
+                return Err(VeloxxError::InvalidOperation(format!(
+                    "Column '{new_col_name}' is added more than once."
+                )));
+            }
+            added.insert(
+                new_col_name.to_string(),
+                self.evaluate_column(new_col_name, expr)?,
+            );
+        }
+
+        let mut new_columns = self.columns.clone();
+        new_columns.extend(added);
+        Ok(DataFrame::new(new_columns))
+    }
+
+    /// Evaluates `expr` for every row into a series named `new_col_name`.
+    ///
+    /// The series takes the type of the first non-null result, falling back to the
+    /// expression's static type and then to `String` when every result is null.
+    fn evaluate_column(&self, new_col_name: &str, expr: &Expr) -> Result<Series, VeloxxError> {
         let mut evaluated_values: Vec<Value> = Vec::with_capacity(self.row_count());
         let mut inferred_type: Option<crate::types::DataType> = None;
 
         for i in 0..self.row_count() {
             let evaluated_value = expr.evaluate(self, i)?;
-            if inferred_type.is_none() && evaluated_value != Value::Null {
-                inferred_type = Some(evaluated_value.data_type());
+            if evaluated_value != Value::Null {
+                let value_type = evaluated_value.data_type();
+                match &inferred_type {
+                    None => inferred_type = Some(value_type),
+                    Some(expected) if *expected != value_type => {
+                        return Err(VeloxxError::DataTypeMismatch(format!(
+                            "Expression for column '{new_col_name}' produced {value_type:?} at row {i}, expected {expected:?}"
+                        )));
+                    }
+                    Some(_) => {}
+                }
             }
             evaluated_values.push(evaluated_value);
         }
 
-        let new_series = match inferred_type {
+        let new_series = match inferred_type.or_else(|| expr.static_type()) {
             Some(DataType::I32) => Series::new_i32(
                 new_col_name,
                 evaluated_values
@@ -445,6 +834,13 @@ impl DataFrame {
                     .map(|v| if let Value::I32(x) = v { Some(x) } else { None })
                     .collect(),
             ),
+            Some(DataType::I64) => Series::new_i64(
+                new_col_name,
+                evaluated_values
+                    .into_iter()
+                    .map(|v| if let Value::I64(x) = v { Some(x) } else { None })
+                    .collect(),
+            ),
             Some(DataType::F64) => Series::new_f64(
                 new_col_name,
                 evaluated_values
@@ -493,9 +889,57 @@ impl DataFrame {
             ),
             None => Series::new_string(new_col_name, vec![None; self.row_count()]), // All nulls, default to String
         };
+        Ok(new_series)
+    }
 
-        new_columns.insert(new_col_name.to_string(), new_series);
-        Ok(DataFrame::new(new_columns))
+    /// Adds a new column computed from an expression at a given position.
+    ///
+    /// This behaves like [`DataFrame::with_column`], except that the new column is placed
+    /// at ordinal position `index` and the columns from that position onwards shift right.
+    /// An `index` past the last column places the new column at the end.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The position the new column should occupy.
+    /// * `new_col_name` - The name of the new column to be added.
+    /// * `expr` - The `Expr` defining how to compute the values for the new column.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok(DataFrame)` containing a new `DataFrame` with the inserted column,
+    /// or `Err(VeloxxError::InvalidOperation)` if a column with `new_col_name` already exists,
+    /// or `Err(VeloxxError)` if the expression cannot be evaluated for any row.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::series::Series;
+    /// use veloxx::expressions::Expr;
+    /// use indexmap::IndexMap;
+    ///
+    /// let mut columns = IndexMap::new();
+    /// columns.insert("a".to_string(), Series::new_i32("a", vec![Some(1), Some(2)]));
+    /// columns.insert("b".to_string(), Series::new_i32("b", vec![Some(3), Some(4)]));
+    /// let df = DataFrame::new(columns);
+    ///
+    /// let key = Expr::Add(
+    ///     Box::new(Expr::Column("a".to_string())),
+    ///     Box::new(Expr::Column("b".to_string())),
+    /// );
+    /// let df = df.insert_column_at(0, "key", &key).unwrap();
+    /// assert_eq!(df.column_names(), vec!["key".to_string(), "a".to_string(), "b".to_string()]);
+    /// ```
+    pub fn insert_column_at(
+        &self,
+        index: usize,
+        new_col_name: &str,
+        expr: &Expr,
+    ) -> Result<Self, VeloxxError> {
+        let mut df = self.with_column(new_col_name, expr)?;
+        let last = df.columns.len() - 1;
+        df.columns.move_index(last, index.min(last));
+        Ok(df)
     }
 
     /// Filters the `DataFrame` based on a given condition.
@@ -568,8 +1012,12 @@ impl DataFrame {
             None => return Ok(None),
         };
 
-        // Create bit mask using vectorized operations
-        let mask = VectorizedFilter::fast_filter_single_column(series, comparison_value, op)?;
+        // Create bit mask using vectorized operations; type pairs the fast path does not
+        // cover (e.g. an I32 literal against an F64 column) use the fallback
+        let mask = match VectorizedFilter::fast_filter_single_column(series, comparison_value, op) {
+            Err(VeloxxError::Unsupported(_)) => return Ok(None),
+            mask => mask?,
+        };
 
         // Apply mask to all columns
         let mut filtered_columns = indexmap::IndexMap::new();
@@ -582,11 +1030,25 @@ impl DataFrame {
         Ok(Some(DataFrame::new(filtered_columns)))
     }
 
+    /// Filter DataFrame using a boolean mask Series
+    pub fn filter_by_mask(&self, mask: &Series) -> Result<DataFrame, VeloxxError> {
+        let mut new_columns = indexmap::IndexMap::new();
+
+        for (name, series) in &self.columns {
+            let filtered_series = series.filter_by_mask(mask)?;
+            new_columns.insert(name.clone(), filtered_series);
+        }
+
+        Ok(DataFrame::new(new_columns))
+    }
+
     /// Filters the `DataFrame` based on a list of row indices.
     ///
     /// This is a lower-level filtering method that directly takes a slice of row indices.
     /// It creates a new `DataFrame` containing only the rows at the specified indices.
-    /// The order of rows in the new DataFrame will match the order of `row_indices`.
+    /// The order of rows in the new DataFrame will match the order of `row_indices`, and
+    /// columns keep their original order; an empty `row_indices` yields an empty frame that
+    /// still has every column.
     ///
     /// # Arguments
     ///
@@ -615,30 +1077,218 @@ impl DataFrame {
     /// assert_eq!(filtered_df.get_column("data").unwrap().get_value(0), Some(Value::I32(10)));
     /// assert_eq!(filtered_df.get_column("data").unwrap().get_value(1), Some(Value::I32(30)));
     /// ```
-    /// Filter DataFrame using a boolean mask Series
-    pub fn filter_by_mask(&self, mask: &Series) -> Result<DataFrame, VeloxxError> {
-        let mut new_columns = indexmap::IndexMap::new();
-
-        for (name, series) in &self.columns {
-            let filtered_series = series.filter_by_mask(mask)?;
-            new_columns.insert(name.clone(), filtered_series);
+    pub fn filter_by_indices(&self, row_indices: &[usize]) -> Result<Self, VeloxxError> {
+        let mut new_columns: indexmap::IndexMap<String, Series> = indexmap::IndexMap::new();
+        for (col_name, series) in self.columns.iter() {
+            let new_series = (*series).filter(row_indices)?;
+            new_columns.insert(col_name.clone(), new_series);
         }
 
         Ok(DataFrame::new(new_columns))
     }
 
-    pub fn filter_by_indices(&self, row_indices: &[usize]) -> Result<Self, VeloxxError> {
-        if row_indices.is_empty() {
-            return Ok(DataFrame::new(indexmap::IndexMap::new()));
+    /// Returns the first `n` rows of the `DataFrame`.
+    ///
+    /// `n` is clamped to `row_count()`, and `head(0)` yields a frame with every column but no
+    /// rows.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::series::Series;
+    /// use indexmap::IndexMap;
+    /// use veloxx::types::Value;
+    ///
+    /// let mut columns = IndexMap::new();
+    /// columns.insert("data".to_string(), Series::new_i32("data", vec![Some(10), Some(20), Some(30)]));
+    /// let df = DataFrame::new(columns);
+    ///
+    /// let first = df.head(2).unwrap();
+    /// assert_eq!(first.row_count(), 2);
+    /// assert_eq!(first.get_column("data").unwrap().get_value(1), Some(Value::I32(20)));
+    /// assert_eq!(df.head(10).unwrap().row_count(), 3);
+    /// ```
+    pub fn head(&self, n: usize) -> Result<Self, VeloxxError> {
+        let n = n.min(self.row_count());
+        self.filter_by_indices(&(0..n).collect::<Vec<_>>())
+    }
+
+    /// Returns the last `n` rows of the `DataFrame`, in their original order.
+    ///
+    /// `n` is clamped to `row_count()`, and `tail(0)` yields a frame with every column but no
+    /// rows.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::series::Series;
+    /// use indexmap::IndexMap;
+    /// use veloxx::types::Value;
+    ///
+    /// let mut columns = IndexMap::new();
+    /// columns.insert("data".to_string(), Series::new_i32("data", vec![Some(10), Some(20), Some(30)]));
+    /// let df = DataFrame::new(columns);
+    ///
+    /// let last = df.tail(2).unwrap();
+    /// assert_eq!(last.get_column("data").unwrap().get_value(0), Some(Value::I32(20)));
+    /// assert_eq!(last.get_column("data").unwrap().get_value(1), Some(Value::I32(30)));
+    /// ```
+    pub fn tail(&self, n: usize) -> Result<Self, VeloxxError> {
+        let row_count = self.row_count();
+        let start = row_count - n.min(row_count);
+        self.filter_by_indices(&(start..row_count).collect::<Vec<_>>())
+    }
+
+    /// Returns `n` rows chosen at random without replacement.
+    ///
+    /// `n` is clamped to `row_count()` and no row is chosen twice. Rows come out in the random
+    /// order they were drawn. With `Some(seed)` the selection is reproducible: the same seed
+    /// on the same frame always picks the same rows in the same order. With `None` the
+    /// generator is seeded from the operating system.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::series::Series;
+    /// use indexmap::IndexMap;
+    ///
+    /// let mut columns = IndexMap::new();
+    /// columns.insert("data".to_string(), Series::new_i32("data", (0..100).map(Some).collect()));
+    /// let df = DataFrame::new(columns);
+    ///
+    /// let sample = df.sample(10, Some(42)).unwrap();
+    /// assert_eq!(sample.row_count(), 10);
+    /// assert_eq!(df.sample(1_000, Some(42)).unwrap().row_count(), 100);
+    /// ```
+    pub fn sample(&self, n: usize, seed: Option<u64>) -> Result<Self, VeloxxError> {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let row_count = self.row_count();
+        let mut rng = match seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+        let rows = rand::seq::index::sample(&mut rng, row_count, n.min(row_count)).into_vec();
+        self.filter_by_indices(&rows)
+    }
+
+    /// Returns a random fraction of the rows, chosen without replacement.
+    ///
+    /// Samples `frac * row_count()` rows, rounded to the nearest whole row, as
+    /// [`DataFrame::sample`] does.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok(DataFrame)` with the sampled rows, or
+    /// `Err(VeloxxError::InvalidOperation)` if `frac` is not within `[0, 1]`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::series::Series;
+    /// use indexmap::IndexMap;
+    ///
+    /// let mut columns = IndexMap::new();
+    /// columns.insert("data".to_string(), Series::new_i32("data", (0..100).map(Some).collect()));
+    /// let df = DataFrame::new(columns);
+    ///
+    /// assert_eq!(df.sample_frac(0.25, Some(7)).unwrap().row_count(), 25);
+    /// assert!(df.sample_frac(1.5, Some(7)).is_err());
+    /// ```
+    pub fn sample_frac(&self, frac: f64, seed: Option<u64>) -> Result<Self, VeloxxError> {
+        if !(0.0..=1.0).contains(&frac) {
+            return Err(VeloxxError::InvalidOperation(format!(
+                "Sample fraction must be between 0 and 1, got {frac}"
+            )));
         }
+        let n = (frac * self.row_count() as f64).round() as usize;
+        self.sample(n, seed)
+    }
 
-        let mut new_columns: indexmap::IndexMap<String, Series> = indexmap::IndexMap::new();
-        for (col_name, series) in self.columns.iter() {
-            let new_series = (*series).filter(row_indices)?;
-            new_columns.insert(col_name.clone(), new_series);
+    /// Returns `n` rows chosen without replacement, each with probability proportional to its
+    /// value in `weight_col`.
+    ///
+    /// The weight column must be numeric. Null weights count as zero, and rows with zero
+    /// weight are never chosen, so fewer than `n` rows are returned when fewer than `n` rows
+    /// have a positive weight. The same `seed` always selects the same rows.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok(DataFrame)` with the sampled rows,
+    /// `Err(VeloxxError::ColumnNotFound)` if `weight_col` does not exist,
+    /// `Err(VeloxxError::DataTypeMismatch)` if it is not numeric, or
+    /// `Err(VeloxxError::InvalidOperation)` if a weight is negative, infinite or NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::series::Series;
+    /// use indexmap::IndexMap;
+    /// use veloxx::types::Value;
+    ///
+    /// let mut columns = IndexMap::new();
+    /// columns.insert("id".to_string(), Series::new_i32("id", vec![Some(1), Some(2), Some(3)]));
+    /// columns.insert("weight".to_string(), Series::new_f64("weight", vec![Some(0.0), Some(5.0), None]));
+    /// let df = DataFrame::new(columns);
+    ///
+    /// let sample = df.weighted_sample(2, "weight", 42).unwrap();
+    /// assert_eq!(sample.row_count(), 1);
+    /// assert_eq!(sample.get_column("id").unwrap().get_value(0), Some(Value::I32(2)));
+    /// ```
+    pub fn weighted_sample(
+        &self,
+        n: usize,
+        weight_col: &str,
+        seed: u64,
+    ) -> Result<Self, VeloxxError> {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let weights = self
+            .get_column(weight_col)
+            .ok_or_else(|| VeloxxError::ColumnNotFound(weight_col.to_string()))?;
+        if !weights.is_numeric() {
+            return Err(VeloxxError::DataTypeMismatch(format!(
+                "Weight column '{}' must be numeric, not {:?}",
+                weight_col,
+                weights.data_type()
+            )));
         }
 
-        Ok(DataFrame::new(new_columns))
+        let mut candidates: Vec<(usize, f64)> = Vec::new();
+        for row in 0..self.row_count() {
+            let weight = match weights.get_value(row) {
+                Some(Value::I32(w)) => w as f64,
+                Some(Value::I64(w)) => w as f64,
+                Some(Value::F64(w)) => w,
+                _ => 0.0,
+            };
+            if !weight.is_finite() || weight < 0.0 {
+                return Err(VeloxxError::InvalidOperation(format!(
+                    "Weight {weight} at row {row} of '{weight_col}' must be finite and non-negative"
+                )));
+            }
+            if weight > 0.0 {
+                candidates.push((row, weight));
+            }
+        }
+
+        let mut rng = StdRng::seed_from_u64(seed);
+        let picked = rand::seq::index::sample_weighted(
+            &mut rng,
+            candidates.len(),
+            |i| candidates[i].1,
+            n.min(candidates.len()),
+        )
+        .map_err(|e| VeloxxError::InvalidOperation(format!("Weighted sampling failed: {e}")))?;
+        let rows: Vec<usize> = picked.into_iter().map(|i| candidates[i].0).collect();
+        self.filter_by_indices(&rows)
     }
 
     /// Appends another `DataFrame` to the end of this `DataFrame`.
@@ -647,8 +1297,11 @@ impl DataFrame {
     /// For a successful append, both DataFrames must have:
     /// - The same number of columns.
     /// - Identical column names (case-sensitive).
-    /// - Matching data types for each corresponding column.
-    ///   The order of columns in both DataFrames is also important.
+    /// - Matching data types for each column of the same name.
+    ///
+    /// Columns are matched by name, exactly as [`DataFrame::append_aligned`] does, so
+    /// `other`'s columns may appear in any order; the result keeps this `DataFrame`'s
+    /// column order.
     ///
     /// # Arguments
     ///
@@ -657,7 +1310,7 @@ impl DataFrame {
     /// # Returns
     ///
     /// A `Result` which is `Ok(DataFrame)` containing a new `DataFrame` with rows from both DataFrames,
-    /// or `Err(VeloxxError::InvalidOperation)` if column counts, names, or order mismatch,
+    /// or `Err(VeloxxError::InvalidOperation)` if column counts or names mismatch,
     /// or `Err(VeloxxError::DataTypeMismatch)` if corresponding columns have different data types.
     ///
     /// # Examples
@@ -671,12 +1324,12 @@ impl DataFrame {
     /// let mut df1_cols = IndexMap::new();
     /// df1_cols.insert("id".to_string(), Series::new_i32("id", vec![Some(1), Some(2)]));
     /// df1_cols.insert("value".to_string(), Series::new_f64("value", vec![Some(10.0), Some(20.0)]));
-    /// let df1 = DataFrame::new(df1_cols).unwrap();
+    /// let df1 = DataFrame::new(df1_cols);
     ///
     /// let mut df2_cols = IndexMap::new();
     /// df2_cols.insert("id".to_string(), Series::new_i32("id", vec![Some(3), Some(4)]));
     /// df2_cols.insert("value".to_string(), Series::new_f64("value", vec![Some(30.0), Some(40.0)]));
-    /// let df2 = DataFrame::new(df2_cols).unwrap();
+    /// let df2 = DataFrame::new(df2_cols);
     ///
     /// let appended_df = df1.append(&df2).unwrap();
     /// assert_eq!(appended_df.row_count(), 4);
@@ -684,44 +1337,73 @@ impl DataFrame {
     /// assert_eq!(appended_df.get_column("value").unwrap().get_value(3), Some(Value::F64(40.0)));
     /// ```
     pub fn append(&self, other: &DataFrame) -> Result<Self, VeloxxError> {
+        self.append_aligned(other)
+    }
+
+    /// Appends rows from another `DataFrame`, matching columns by name rather than position.
+    ///
+    /// Both DataFrames must contain the same set of column names with matching data types, but
+    /// `other`'s columns may appear in any order; they are reordered to follow this
+    /// `DataFrame` before concatenating.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The `DataFrame` to append.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok(DataFrame)` with this `DataFrame`'s column order,
+    /// `Err(VeloxxError::InvalidOperation)` if the column sets differ, or
+    /// `Err(VeloxxError::DataTypeMismatch)` if a column has different data types in the two frames.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::series::Series;
+    /// use indexmap::IndexMap;
+    /// use veloxx::types::Value;
+    ///
+    /// let mut df1_cols = IndexMap::new();
+    /// df1_cols.insert("id".to_string(), Series::new_i32("id", vec![Some(1)]));
+    /// df1_cols.insert("value".to_string(), Series::new_f64("value", vec![Some(10.0)]));
+    /// let df1 = DataFrame::new(df1_cols);
+    ///
+    /// let mut df2_cols = IndexMap::new();
+    /// df2_cols.insert("value".to_string(), Series::new_f64("value", vec![Some(20.0)]));
+    /// df2_cols.insert("id".to_string(), Series::new_i32("id", vec![Some(2)]));
+    /// let df2 = DataFrame::new(df2_cols);
+    ///
+    /// let stacked = df1.append_aligned(&df2).unwrap();
+    /// assert_eq!(stacked.column_names(), vec!["id", "value"]);
+    /// assert_eq!(stacked.get_column("id").unwrap().get_value(1), Some(Value::I32(2)));
+    /// ```
+    pub fn append_aligned(&self, other: &DataFrame) -> Result<Self, VeloxxError> {
         if self.column_count() != other.column_count() {
             return Err(VeloxxError::InvalidOperation(
                 "Cannot append DataFrames with different number of columns.".to_string(),
             ));
         }
 
-        // Build a mapping of other column names to ensure we match by name, not order
-        let self_column_names: Vec<String> = self.column_names();
-        let other_column_names: Vec<String> = other.column_names();
-
-        // Validate that both DataFrames contain the same set of columns and types
-        use std::collections::HashSet;
-        let self_set: HashSet<String> = self_column_names.iter().cloned().collect();
-        let other_set: HashSet<String> = other_column_names.iter().cloned().collect();
-        if self_set != other_set {
-            return Err(VeloxxError::InvalidOperation(
-                "Cannot append DataFrames with different column names.".to_string(),
-            ));
-        }
-
-        for name in &self_column_names {
-            let t1 = self.get_column(name).unwrap().data_type();
-            let t2 = other.get_column(name).unwrap().data_type();
-            if t1 != t2 {
+        let mut pairs = Vec::with_capacity(self.column_count());
+        for (name, self_series) in &self.columns {
+            let other_series = other.get_column(name).ok_or_else(|| {
+                VeloxxError::InvalidOperation(
+                    "Cannot append DataFrames with different column names.".to_string(),
+                )
+            })?;
+            if self_series.data_type() != other_series.data_type() {
                 return Err(VeloxxError::DataTypeMismatch(format!(
                     "Cannot append DataFrames with mismatched data types for column '{}'.",
                     name
                 )));
             }
+            pairs.push((name, self_series, other_series));
         }
 
-        // Create appended columns by matching names regardless of order
         let mut new_columns: indexmap::IndexMap<String, Series> = indexmap::IndexMap::new();
-        for col_name in self_column_names.into_iter() {
-            let self_series = self.get_column(&col_name).unwrap();
-            let other_series = other.get_column(&col_name).unwrap();
-            let appended_series = self_series.append(other_series)?;
-            new_columns.insert(col_name, appended_series);
+        for (name, self_series, other_series) in pairs {
+            new_columns.insert(name.clone(), self_series.append(other_series)?);
         }
 
         Ok(DataFrame::new(new_columns))
@@ -858,16 +1540,13 @@ impl DataFrame {
 
     /// Generates descriptive statistics for the `DataFrame`.
     ///
-    /// This method calculates various statistical measures for each column in the DataFrame.
-    /// For numeric columns (`I32`, `F64`, `DateTime`), it computes count, mean, standard deviation,
-    /// minimum, maximum, and median. For non-numeric columns (`Bool`, `String`), only the count
-    /// of non-null values is provided.
+    /// Equivalent to [`DataFrame::describe_with`] over every column with the 25th, 50th and
+    /// 75th percentiles.
     ///
     /// # Returns
     ///
-    /// A `Result` which is `Ok(DataFrame)` containing a new `DataFrame` where each row represents
-    /// a statistical measure (e.g., "count", "mean"), and each column represents an original
-    /// column from the input DataFrame. Returns `Err(VeloxxError)` if any statistical calculation fails.
+    /// A `Result` which is `Ok(DataFrame)` with one row per original column, or
+    /// `Err(VeloxxError)` if any statistical calculation fails.
     ///
     /// # Examples
     ///
@@ -879,98 +1558,157 @@ impl DataFrame {
     /// let mut columns = IndexMap::new();
     /// columns.insert("age".to_string(), Series::new_i32("age", vec![Some(20), Some(30), Some(25), None, Some(35)]));
     /// columns.insert("city".to_string(), Series::new_string("city", vec![Some("NY".to_string()), Some("LA".to_string()), Some("NY".to_string()), Some("SF".to_string()), None]));
-    /// let df = DataFrame::new(columns).unwrap();
+    /// let df = DataFrame::new(columns);
     ///
     /// let description_df = df.describe().unwrap();
     /// println!("Descriptive Statistics:\n{}", description_df);
-    /// // Expected output (column order might vary):
-    /// // column         count          mean           std            min            max            median         
-    /// // --------------- --------------- --------------- --------------- --------------- --------------- ---------------
-    /// // age            4              27.50          6.45           Value::I32(20) Value::I32(35) Value::F64(27.50)
-    /// // city           4              null           null           null           null           null           
+    /// // column  count  mean   std   min    25%    50%    75%    max
+    /// // age     4      27.50  6.45  20.00  23.75  27.50  31.25  35.00
+    /// // city    4      null   null  null   null   null   null   null
     /// ```
     pub fn describe(&self) -> Result<DataFrame, VeloxxError> {
-        let mut descriptions: indexmap::IndexMap<String, Series> = indexmap::IndexMap::new();
-        let mut counts: Vec<Option<i32>> = Vec::new();
-        let mut means: Vec<Option<f64>> = Vec::new();
-        let mut std_devs: Vec<Option<f64>> = Vec::new();
-        let mut mins: Vec<Option<Value>> = Vec::new();
-        let mut maxs: Vec<Option<Value>> = Vec::new();
-        let mut medians: Vec<Option<Value>> = Vec::new();
+        self.describe_with(None, &[0.25, 0.5, 0.75])
+    }
 
-        let mut column_names_vec: Vec<String> = Vec::new();
+    /// Generates descriptive statistics for a subset of columns with custom percentiles.
+    ///
+    /// The result has a `"column"` label column followed by `F64` statistic columns:
+    /// `count` (`I32`, non-null values), `mean`, `std`, `min`, one column per percentile
+    /// named after it (e.g. `"25%"`), and `max`. Statistics are computed for `I32`, `I64` and
+    /// `F64` columns, and for `DateTime` columns over their integer timestamps; other columns
+    /// get nulls. Percentiles use [`Series::quantile`] with [`Interpolation::Linear`].
+    ///
+    /// # Arguments
+    ///
+    /// * `columns` - The columns to describe, or `None` for all columns.
+    /// * `percentiles` - The percentiles to report, each between 0.0 and 1.0.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok(DataFrame)` with one row per described column,
+    /// `Err(VeloxxError::ColumnNotFound)` if a requested column does not exist, or
+    /// `Err(VeloxxError::InvalidOperation)` if a percentile is outside `0.0..=1.0` or two
+    /// percentiles would share a column name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::series::Series;
+    /// use indexmap::IndexMap;
+    ///
+    /// let mut columns = IndexMap::new();
+    /// columns.insert("a".to_string(), Series::new_i32("a", vec![Some(1), Some(2), Some(3), Some(4), Some(5)]));
+    /// columns.insert("b".to_string(), Series::new_f64("b", vec![Some(0.5); 5]));
+    /// let df = DataFrame::new(columns);
+    ///
+    /// let summary = df.describe_with(Some(&["a"]), &[0.125, 0.75]).unwrap();
+    /// assert_eq!(summary.row_count(), 1);
+    /// assert_eq!(summary.get_column("12.5%").unwrap().get_f64(0), Some(1.5));
+    /// assert_eq!(summary.get_column("75%").unwrap().get_f64(0), Some(4.0));
+    /// ```
+    pub fn describe_with(
+        &self,
+        columns: Option<&[&str]>,
+        percentiles: &[f64],
+    ) -> Result<DataFrame, VeloxxError> {
+        if let Some(p) = percentiles.iter().find(|p| !(0.0..=1.0).contains(*p)) {
+            return Err(VeloxxError::InvalidOperation(format!(
+                "Percentile {p} must be between 0.0 and 1.0"
+            )));
+        }
+        let mut percentile_labels: Vec<String> = Vec::with_capacity(percentiles.len());
+        for p in percentiles {
+            let label = format!("{}%", (p * 1000.0).round() / 10.0);
+            if percentile_labels.contains(&label) {
+                return Err(VeloxxError::InvalidOperation(format!(
+                    "Percentile {p} repeats the '{label}' column of an earlier percentile"
+                )));
+            }
+            percentile_labels.push(label);
+        }
+        let selected: Vec<(&str, &Series)> = match columns {
+            Some(names) => names
+                .iter()
+                .map(|name| {
+                    self.columns
+                        .get(*name)
+                        .map(|series| (*name, series))
+                        .ok_or_else(|| VeloxxError::ColumnNotFound(name.to_string()))
+                })
+                .collect::<Result<_, _>>()?,
+            None => self
+                .columns
+                .iter()
+                .map(|(name, series)| (name.as_str(), series))
+                .collect(),
+        };
 
-        for (col_name, series) in self.columns.iter() {
-            column_names_vec.push(col_name.clone());
-            counts.push(Some(series.len() as i32));
-
-            match series.data_type() {
-                crate::types::DataType::I32
-                | crate::types::DataType::F64
-                | crate::types::DataType::DateTime => {
-                    means.push(series.mean().ok().and_then(|v| {
-                        if let Value::F64(val) = v {
-                            Some(val)
-                        } else {
-                            None
-                        }
-                    }));
-                    std_devs.push(series.std_dev().ok().and_then(|v| {
-                        if let Value::F64(val) = v {
-                            Some(val)
-                        } else {
-                            None
-                        }
-                    }));
-                    mins.push(series.min().ok());
-                    maxs.push(series.max().ok());
-                    medians.push(series.median().ok());
+        let as_f64 = |value: Value| match value {
+            Value::I32(v) => Some(v as f64),
+            Value::I64(v) => Some(v as f64),
+            Value::F64(v) => Some(v),
+            _ => None,
+        };
+
+        let mut labels = Vec::with_capacity(selected.len());
+        let mut counts = Vec::with_capacity(selected.len());
+        let mut means = Vec::with_capacity(selected.len());
+        let mut std_devs = Vec::with_capacity(selected.len());
+        let mut mins = Vec::with_capacity(selected.len());
+        let mut maxs = Vec::with_capacity(selected.len());
+        let mut quantiles: Vec<Vec<Option<f64>>> = vec![Vec::new(); percentiles.len()];
+
+        for (name, series) in selected {
+            labels.push(Some(name.to_string()));
+            counts.push(Some(series.count() as i32));
+
+            let timestamps;
+            let series = match series {
+                Series::DateTime(name, values, bitmap) => {
+                    timestamps = Series::new_i64(
+                        name,
+                        values
+                            .iter()
+                            .zip(bitmap)
+                            .map(|(&v, &valid)| valid.then_some(v))
+                            .collect(),
+                    );
+                    &timestamps
                 }
-                _ => {
-                    means.push(None);
-                    std_devs.push(None);
-                    mins.push(None);
-                    maxs.push(None);
-                    medians.push(None);
+                other => other,
+            };
+            let numeric = series.is_numeric();
+            let stat = |result: Result<Value, VeloxxError>| {
+                if numeric {
+                    result.ok().and_then(as_f64)
+                } else {
+                    None
                 }
+            };
+            means.push(stat(series.mean()));
+            std_devs.push(stat(series.std_dev()));
+            mins.push(stat(series.min()));
+            maxs.push(stat(series.max()));
+            for (column, &p) in quantiles.iter_mut().zip(percentiles) {
+                column.push(stat(
+                    series
+                        .quantile(p, Interpolation::Linear)
+                        .map(|v| v.unwrap_or(Value::Null)),
+                ));
             }
         }
 
-        descriptions.insert(
-            "column".to_string(),
-            Series::new_string("column", column_names_vec.into_iter().map(Some).collect()),
-        );
+        let mut descriptions = IndexMap::new();
+        descriptions.insert("column".to_string(), Series::new_string("column", labels));
         descriptions.insert("count".to_string(), Series::new_i32("count", counts));
         descriptions.insert("mean".to_string(), Series::new_f64("mean", means));
         descriptions.insert("std".to_string(), Series::new_f64("std", std_devs));
-        descriptions.insert(
-            "min".to_string(),
-            Series::new_string(
-                "min",
-                mins.into_iter()
-                    .map(|x| x.map(|v| format!("{v:?}")))
-                    .collect(),
-            ),
-        );
-        descriptions.insert(
-            "max".to_string(),
-            Series::new_string(
-                "max",
-                maxs.into_iter()
-                    .map(|x| x.map(|v| format!("{v:?}")))
-                    .collect(),
-            ),
-        );
-        descriptions.insert(
-            "median".to_string(),
-            Series::new_string(
-                "median",
-                medians
-                    .into_iter()
-                    .map(|x| x.map(|v| format!("{v:?}")))
-                    .collect(),
-            ),
-        );
+        descriptions.insert("min".to_string(), Series::new_f64("min", mins));
+        for (values, label) in quantiles.into_iter().zip(percentile_labels) {
+            descriptions.insert(label.clone(), Series::new_f64(&label, values));
+        }
+        descriptions.insert("max".to_string(), Series::new_f64("max", maxs));
 
         Ok(DataFrame::new(descriptions))
     }
@@ -1135,6 +1873,108 @@ impl DataFrame {
         Ok(sum_products / (n - 1) as f64)
     }
 
+    /// Computes the pairwise Pearson correlation matrix of numeric columns.
+    ///
+    /// The result has a String `column` label column followed by one F64 column per selected
+    /// column, so `result["b"][i]` is the correlation between row label `i` and `b`. Nulls are
+    /// handled by pairwise deletion; pairs with fewer than two complete rows or zero variance
+    /// yield null.
+    ///
+    /// # Arguments
+    ///
+    /// * `columns` - The columns to include, in output order. `None` selects every `I32` and
+    ///   `F64` column, which can be expensive on wide frames.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok(DataFrame)` containing the matrix,
+    /// `Err(VeloxxError::ColumnNotFound)` if a requested column does not exist,
+    /// `Err(VeloxxError::DataTypeMismatch)` if a requested column is not numeric, or
+    /// `Err(VeloxxError::InvalidOperation)` if a selected column is itself named `column`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::series::Series;
+    /// use indexmap::IndexMap;
+    ///
+    /// let mut columns = IndexMap::new();
+    /// columns.insert("x".to_string(), Series::new_i32("x", vec![Some(1), Some(2), Some(3)]));
+    /// columns.insert("y".to_string(), Series::new_f64("y", vec![Some(2.0), Some(4.0), Some(6.0)]));
+    /// columns.insert("z".to_string(), Series::new_f64("z", vec![Some(3.0), Some(1.0), Some(2.0)]));
+    /// let df = DataFrame::new(columns);
+    ///
+    /// let corr = df.correlation_matrix(Some(&["x", "y"])).unwrap();
+    /// assert_eq!(corr.column_names(), vec!["column", "x", "y"]);
+    /// ```
+    pub fn correlation_matrix(&self, columns: Option<&[&str]>) -> Result<Self, VeloxxError> {
+        self.pairwise_matrix(columns, Series::correlation)
+    }
+
+    /// Computes the pairwise sample covariance matrix of numeric columns.
+    ///
+    /// Laid out and restricted exactly like [`DataFrame::correlation_matrix`]; pairs with fewer
+    /// than two complete rows yield null.
+    pub fn covariance_matrix(&self, columns: Option<&[&str]>) -> Result<Self, VeloxxError> {
+        self.pairwise_matrix(columns, Series::covariance)
+    }
+
+    fn pairwise_matrix(
+        &self,
+        columns: Option<&[&str]>,
+        stat: fn(&Series, &Series) -> Result<Option<f64>, VeloxxError>,
+    ) -> Result<Self, VeloxxError> {
+        let selected: Vec<(&str, &Series)> = match columns {
+            Some(names) => names
+                .iter()
+                .map(|&name| {
+                    let series = self
+                        .get_column(name)
+                        .ok_or_else(|| VeloxxError::ColumnNotFound(name.to_string()))?;
+                    match series.data_type() {
+                        DataType::I32 | DataType::I64 | DataType::F64 => Ok((name, series)),
+                        other => Err(VeloxxError::DataTypeMismatch(format!(
+                            "Column '{}' has type {:?}; only I32, I64 and F64 columns are supported",
+                            name, other
+                        ))),
+                    }
+                })
+                .collect::<Result<_, _>>()?,
+            None => self
+                .columns
+                .iter()
+                .filter(|(_, series)| series.is_numeric())
+                .map(|(name, series)| (name.as_str(), series))
+                .collect(),
+        };
+        if selected.iter().any(|(name, _)| *name == "column") {
+            return Err(VeloxxError::InvalidOperation(
+                "A column named 'column' clashes with the matrix's label column".to_string(),
+            ));
+        }
+
+        let mut matrix = IndexMap::new();
+        matrix.insert(
+            "column".to_string(),
+            Series::new_string(
+                "column",
+                selected
+                    .iter()
+                    .map(|(name, _)| Some(name.to_string()))
+                    .collect(),
+            ),
+        );
+        for (name, col) in &selected {
+            let values = selected
+                .iter()
+                .map(|(_, row)| stat(row, col))
+                .collect::<Result<Vec<_>, _>>()?;
+            matrix.insert(name.to_string(), Series::new_f64(name, values));
+        }
+        Ok(DataFrame::new(matrix))
+    }
+
     /// Converts the `DataFrame` into a `Vec<Vec<Option<Value>>>`.
     ///
     /// This method transforms the tabular data of the `DataFrame` into a nested vector
@@ -1182,6 +2022,438 @@ impl DataFrame {
         }
         result
     }
+
+    /// Converts the `DataFrame` into one map per row, keyed by column name in column order.
+    ///
+    /// Null cells are represented as `Value::Null`, so every map has an entry for every column.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::series::Series;
+    /// use indexmap::IndexMap;
+    /// use veloxx::types::Value;
+    ///
+    /// let mut columns = IndexMap::new();
+    /// columns.insert("A".to_string(), Series::new_i32("A", vec![Some(1), Some(2)]));
+    /// columns.insert("B".to_string(), Series::new_string("B", vec![Some("x".to_string()), None]));
+    /// let df = DataFrame::new(columns);
+    ///
+    /// let records = df.to_records();
+    /// assert_eq!(records.len(), 2);
+    /// assert_eq!(records[0]["A"], Value::I32(1));
+    /// assert_eq!(records[1]["B"], Value::Null);
+    /// ```
+    pub fn to_records(&self) -> Vec<IndexMap<String, Value>> {
+        (0..self.row_count())
+            .map(|i| {
+                self.columns
+                    .iter()
+                    .map(|(name, series)| {
+                        (name.clone(), series.get_value(i).unwrap_or(Value::Null))
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Encodes a string column as integer category codes.
+    ///
+    /// Each distinct value of `column` is assigned a code in order of first appearance,
+    /// starting at 0. The column is replaced, in place, by an `I32` column of codes and the
+    /// categories are returned alongside the new `DataFrame`, so that
+    /// `categories[code]` decodes a code back to its original value. Null values stay null.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - The name of the `String` column to encode.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok((DataFrame, Vec<String>))` containing the encoded `DataFrame`
+    /// and the ordered category list, `Err(VeloxxError::ColumnNotFound)` if the column does not
+    /// exist, or `Err(VeloxxError::DataTypeMismatch)` if it is not a `String` column.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::series::Series;
+    /// use veloxx::types::Value;
+    /// use indexmap::IndexMap;
+    ///
+    /// let mut columns = IndexMap::new();
+    /// columns.insert("color".to_string(), Series::new_string("color", vec![Some("red".to_string()), Some("blue".to_string()), Some("red".to_string()), None]));
+    /// let df = DataFrame::new(columns);
+    ///
+    /// let (encoded, categories) = df.factorize("color").unwrap();
+    /// assert_eq!(categories, vec!["red".to_string(), "blue".to_string()]);
+    /// let codes = encoded.get_column("color").unwrap();
+    /// assert_eq!(codes.get_value(2), Some(Value::I32(0)));
+    /// assert_eq!(codes.get_value(3), None);
+    /// ```
+    pub fn factorize(&self, column: &str) -> Result<(Self, Vec<String>), VeloxxError> {
+        let series = self
+            .get_column(column)
+            .ok_or_else(|| VeloxxError::ColumnNotFound(column.to_string()))?;
+        let (values, validity) = match series {
+            Series::String(_, values, validity) => (values, validity),
+            _ => {
+                return Err(VeloxxError::DataTypeMismatch(format!(
+                    "Cannot factorize column '{}': expected a String column",
+                    column
+                )))
+            }
+        };
+
+        let mut categories: IndexMap<&str, i32> = IndexMap::new();
+        let codes: Vec<Option<i32>> = values
+            .iter()
+            .zip(validity.iter())
+            .map(|(value, &is_valid)| {
+                if !is_valid {
+                    return None;
+                }
+                let next_code = categories.len() as i32;
+                Some(*categories.entry(value.as_str()).or_insert(next_code))
+            })
+            .collect();
+
+        let mut new_columns = self.columns.clone();
+        new_columns.insert(column.to_string(), Series::new_i32(column, codes));
+        let categories = categories.into_keys().map(|s| s.to_string()).collect();
+        Ok((DataFrame::new(new_columns), categories))
+    }
+
+    /// Computes a stable hash of each row over the chosen columns.
+    ///
+    /// The hash is 64-bit FNV-1a over a type-tagged encoding of each value, so it is identical
+    /// across runs, processes and machines. Two rows hash equally when they hold the same values
+    /// (with nulls in the same places) in the hashed columns. Columns are hashed in the order
+    /// given, or in the `DataFrame`'s column order when `columns` is `None`.
+    ///
+    /// # Arguments
+    ///
+    /// * `columns` - The columns to hash, or `None` to hash every column.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok(Series)` containing a `String` series named `"row_hash"` with
+    /// one 16-digit hexadecimal hash per row, or `Err(VeloxxError::ColumnNotFound)` if any of
+    /// the `columns` do not exist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::series::Series;
+    /// use indexmap::IndexMap;
+    ///
+    /// let mut columns = IndexMap::new();
+    /// columns.insert("a".to_string(), Series::new_i32("a", vec![Some(1), Some(2), Some(1)]));
+    /// let df = DataFrame::new(columns);
+    ///
+    /// let hashes = df.row_hashes(None).unwrap();
+    /// assert_eq!(hashes.get_value(0), hashes.get_value(2));
+    /// assert_ne!(hashes.get_value(0), hashes.get_value(1));
+    /// ```
+    pub fn row_hashes(&self, columns: Option<&[&str]>) -> Result<Series, VeloxxError> {
+        let hashed: Vec<&Series> = match columns {
+            Some(names) => names
+                .iter()
+                .map(|name| {
+                    self.get_column(name)
+                        .ok_or_else(|| VeloxxError::ColumnNotFound(name.to_string()))
+                })
+                .collect::<Result<_, _>>()?,
+            None => self.columns.values().collect(),
+        };
+
+        let hashes: Vec<Option<String>> = (0..self.row_count())
+            .map(|row| {
+                let mut hash = FNV_OFFSET_BASIS;
+                for series in &hashed {
+                    hash = fnv1a_value(hash, series.get_value(row).as_ref());
+                }
+                Some(format!("{:016x}", hash))
+            })
+            .collect();
+        Ok(Series::new_string("row_hash", hashes))
+    }
+
+    /// Sorts the rows of each group while keeping the rows of a group contiguous.
+    ///
+    /// Rows are grouped by the unique combinations of values in the `by` columns.
+    /// Groups are emitted in the order in which they first appear in the `DataFrame`,
+    /// and the rows of each group are sorted by `sort_cols`. The sort is stable, so rows
+    /// that compare equal keep their original relative order. Null values sort first.
+    ///
+    /// # Arguments
+    ///
+    /// * `by` - The names of the columns that identify a group.
+    /// * `sort_cols` - `(column, ascending)` pairs giving the sort keys within each group,
+    ///   in priority order. Each column carries its own sort direction.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok(DataFrame)` containing the reordered rows,
+    /// or `Err(VeloxxError::ColumnNotFound)` if any of the `by` or `sort_cols` columns do not exist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::series::Series;
+    /// use veloxx::types::Value;
+    /// use indexmap::IndexMap;
+    ///
+    /// let mut columns = IndexMap::new();
+    /// columns.insert("store".to_string(), Series::new_string("store", vec![Some("b".to_string()), Some("a".to_string()), Some("b".to_string())]));
+    /// columns.insert("sales".to_string(), Series::new_i32("sales", vec![Some(5), Some(1), Some(3)]));
+    /// let df = DataFrame::new(columns);
+    ///
+    /// let sorted = df.sort_within_groups(&["store"], vec![("sales".to_string(), true)]).unwrap();
+    /// // Group "b" appears first, and its rows are sorted by sales.
+    /// assert_eq!(sorted.get_column("sales").unwrap().get_value(0), Some(Value::I32(3)));
+    /// assert_eq!(sorted.get_column("sales").unwrap().get_value(1), Some(Value::I32(5)));
+    /// assert_eq!(sorted.get_column("sales").unwrap().get_value(2), Some(Value::I32(1)));
+    /// ```
+    pub fn sort_within_groups(
+        &self,
+        by: &[&str],
+        sort_cols: Vec<(String, bool)>,
+    ) -> Result<Self, VeloxxError> {
+        let groups = self.sorted_group_indices(by, &sort_cols)?;
+        if self.row_count() == 0 {
+            return Ok(self.clone());
+        }
+        let order: Vec<usize> = groups.into_iter().flatten().collect();
+        self.filter_by_indices(&order)
+    }
+
+    /// Applies `f` to the rows of each group and concatenates the results (split-apply-combine).
+    ///
+    /// Groups are formed from the `by` columns and visited in order of first appearance; the
+    /// output stacks each group's transformed frame in that order. Results with no columns
+    /// (e.g. a group filtered down to nothing) are skipped. All other results must have the
+    /// same columns and types; the output uses the column order of the first result.
+    ///
+    /// # Arguments
+    ///
+    /// * `by` - The columns to group by.
+    /// * `f` - The transformation applied to each group's sub-frame.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok(DataFrame)` containing the combined results,
+    /// `Err(VeloxxError::ColumnNotFound)` if a grouping column is missing,
+    /// `Err(VeloxxError::InvalidOperation)` or `Err(VeloxxError::DataTypeMismatch)` if the
+    /// per-group results have different schemas, or any error returned by `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::series::Series;
+    /// use indexmap::IndexMap;
+    ///
+    /// let mut columns = IndexMap::new();
+    /// columns.insert("store".to_string(), Series::new_string("store", vec![Some("a".to_string()), Some("b".to_string()), Some("a".to_string())]));
+    /// columns.insert("sales".to_string(), Series::new_i32("sales", vec![Some(1), Some(10), Some(2)]));
+    /// let df = DataFrame::new(columns);
+    ///
+    /// // Keep only the best-selling row of each store
+    /// let top = df
+    ///     .group_apply(&["store"], |group| {
+    ///         group.sort(vec!["sales".to_string()], false)?.filter_by_indices(&[0])
+    ///     })
+    ///     .unwrap();
+    /// assert_eq!(top.row_count(), 2);
+    /// ```
+    pub fn group_apply<F>(&self, by: &[&str], f: F) -> Result<Self, VeloxxError>
+    where
+        F: Fn(&DataFrame) -> Result<DataFrame, VeloxxError>,
+    {
+        let mut results = Vec::new();
+        for indices in self.group_row_indices(by)? {
+            let transformed = f(&self.filter_by_indices(&indices)?)?;
+            if transformed.column_count() > 0 {
+                results.push(transformed);
+            }
+        }
+
+        let Some(first) = results.first() else {
+            return Ok(DataFrame::new(IndexMap::new()));
+        };
+        let mut columns = IndexMap::new();
+        for name in first.columns.keys() {
+            let parts = results
+                .iter()
+                .map(|result| {
+                    if result.column_count() != first.column_count() {
+                        return Err(VeloxxError::InvalidOperation(
+                            "group_apply results must all have the same columns".to_string(),
+                        ));
+                    }
+                    result.get_column(name).cloned().ok_or_else(|| {
+                        VeloxxError::InvalidOperation(format!(
+                            "group_apply result is missing column '{}'",
+                            name
+                        ))
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            columns.insert(name.clone(), Series::concat(parts)?);
+        }
+        Ok(DataFrame::new(columns))
+    }
+
+    /// Partitions the row indices by the values of the `by` columns.
+    ///
+    /// Groups are returned in order of first appearance and each group lists its row
+    /// indices in ascending order. Nulls form their own group.
+    pub(crate) fn group_row_indices(&self, by: &[&str]) -> Result<Vec<Vec<usize>>, VeloxxError> {
+        let key_series: Vec<&Series> = by
+            .iter()
+            .map(|name| {
+                self.get_column(name)
+                    .ok_or_else(|| VeloxxError::ColumnNotFound(name.to_string()))
+            })
+            .collect::<Result<_, _>>()?;
+
+        let mut groups: IndexMap<Vec<Value>, Vec<usize>> = IndexMap::new();
+        for row in 0..self.row_count() {
+            let key: Vec<Value> = key_series
+                .iter()
+                .map(|series| series.get_value(row).unwrap_or(Value::Null))
+                .collect();
+            groups.entry(key).or_default().push(row);
+        }
+        Ok(groups.into_values().collect())
+    }
+
+    /// Partitions the row indices by the `by` columns and sorts each partition by
+    /// `order_by`, a list of `(column, ascending)` pairs. Nulls sort first.
+    pub(crate) fn sorted_group_indices(
+        &self,
+        by: &[&str],
+        order_by: &[(String, bool)],
+    ) -> Result<Vec<Vec<usize>>, VeloxxError> {
+        let sort_series: Vec<(&Series, bool)> = order_by
+            .iter()
+            .map(|(name, ascending)| {
+                self.get_column(name)
+                    .map(|series| (series, *ascending))
+                    .ok_or_else(|| VeloxxError::ColumnNotFound(name.clone()))
+            })
+            .collect::<Result<_, _>>()?;
+
+        let mut groups = self.group_row_indices(by)?;
+        for indices in groups.iter_mut() {
+            indices.sort_by(|&a, &b| {
+                for (series, ascending) in &sort_series {
+                    let cmp = series.get_value(a).cmp(&series.get_value(b));
+                    if cmp != std::cmp::Ordering::Equal {
+                        return if *ascending { cmp } else { cmp.reverse() };
+                    }
+                }
+                std::cmp::Ordering::Equal
+            });
+        }
+        Ok(groups)
+    }
+}
+
+/// Stable LSD radix sort of the row indices of an `I32` column, one byte per pass.
+///
+/// Matches the ordering of [`DataFrame::sort_with_nulls`]: nulls before or after every value
+/// according to `nulls_last`, with ties kept in row order either way.
+fn radix_sort_i32_indices(
+    values: &[i32],
+    validity: &[bool],
+    ascending: bool,
+    nulls_last: bool,
+) -> Vec<usize> {
+    // Flipping the sign bit maps i32 order onto u32 order; inverting it sorts descending.
+    let keys: Vec<u32> = values
+        .iter()
+        .map(|&v| {
+            let key = (v as u32) ^ 0x8000_0000;
+            if ascending {
+                key
+            } else {
+                !key
+            }
+        })
+        .collect();
+    let (mut order, nulls): (Vec<usize>, Vec<usize>) =
+        (0..values.len()).partition(|&i| validity[i]);
+
+    let mut scratch = vec![0; order.len()];
+    for shift in [0, 8, 16, 24] {
+        let digit = |row: usize| ((keys[row] >> shift) & 0xFF) as usize;
+        let mut offsets = [0usize; 256];
+        for &row in &order {
+            offsets[digit(row)] += 1;
+        }
+        // Every key shares this byte, so the pass would not move anything.
+        if offsets.contains(&order.len()) {
+            continue;
+        }
+        let mut next = 0;
+        for slot in offsets.iter_mut() {
+            let count = *slot;
+            *slot = next;
+            next += count;
+        }
+        for &row in &order {
+            let d = digit(row);
+            scratch[offsets[d]] = row;
+            offsets[d] += 1;
+        }
+        std::mem::swap(&mut order, &mut scratch);
+    }
+
+    if nulls_last {
+        [order, nulls].concat()
+    } else {
+        [nulls, order].concat()
+    }
+}
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+fn fnv1a(mut hash: u64, bytes: &[u8]) -> u64 {
+    for &byte in bytes {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+    hash
+}
+
+/// Hashes a single value with FNV-1a, which is stable across runs and platforms.
+pub(crate) fn stable_hash(value: &Value) -> u64 {
+    fnv1a_value(FNV_OFFSET_BASIS, Some(value))
+}
+
+/// Feeds a type tag followed by the value's bytes into the hash, so that values of
+/// different types (and nulls) never collide by construction.
+fn fnv1a_value(hash: u64, value: Option<&Value>) -> u64 {
+    match value {
+        None | Some(Value::Null) => fnv1a(hash, &[0]),
+        Some(Value::I32(v)) => fnv1a(fnv1a(hash, &[1]), &v.to_le_bytes()),
+        Some(Value::F64(v)) => fnv1a(fnv1a(hash, &[2]), &v.to_bits().to_le_bytes()),
+        Some(Value::Bool(v)) => fnv1a(hash, &[3, *v as u8]),
+        Some(Value::String(v)) => {
+            let hash = fnv1a(hash, &[4]);
+            let hash = fnv1a(hash, &(v.len() as u64).to_le_bytes());
+            fnv1a(hash, v.as_bytes())
+        }
+        Some(Value::DateTime(v)) => fnv1a(fnv1a(hash, &[5]), &v.to_le_bytes()),
+        Some(Value::I64(v)) => fnv1a(fnv1a(hash, &[6]), &v.to_le_bytes()),
+    }
 }
 
 /// Helper function for min/max calculation with bitmap checking
@@ -1,8 +1,11 @@
 use indexmap::IndexMap;
 
 use crate::series::Series;
+use crate::types::Value;
+use crate::VeloxxError;
 
 pub mod cleaning;
+pub mod diff;
 pub mod display;
 pub mod group_by;
 pub mod io;
@@ -24,6 +27,38 @@ impl DataFrame {
         DataFrame { columns }
     }
 
+    /// Builds a `DataFrame` from `series`, one column per series in the given order, each
+    /// named after its series.
+    ///
+    /// # Errors
+    ///
+    /// Returns `VeloxxError::InvalidOperation` if two series share a name or the series
+    /// differ in length.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::series::Series;
+    ///
+    /// let df = DataFrame::from_series(vec![
+    ///     Series::new_string("city", vec![Some("Oslo".to_string()), Some("Lima".to_string())]),
+    ///     Series::new_i32("population", vec![Some(709), Some(10_092)]),
+    /// ])
+    /// .unwrap();
+    /// assert_eq!(df.column_names(), vec!["city", "population"]);
+    /// ```
+    pub fn from_series(series: Vec<Series>) -> Result<Self, VeloxxError> {
+        let mut df = DataFrame::new(IndexMap::with_capacity(series.len()));
+        for s in series {
+            df.insert_column(s)?;
+        }
+        Ok(df)
+    }
+
+    /// Stores `series` under its own name without any checks, silently overwriting a column
+    /// of the same name. See [`DataFrame::insert_column`] and [`DataFrame::replace_column`]
+    /// for checked alternatives.
     pub fn add_column(&mut self, series: Series) {
         self.columns.insert(series.name().to_string(), series);
     }
@@ -45,6 +80,191 @@ impl DataFrame {
     pub fn get_column(&self, name: &str) -> Option<&Series> {
         self.columns.get(name)
     }
+
+    /// Returns mutable access to the column `name`, for editing it in place without
+    /// rebuilding the frame.
+    ///
+    /// The caller must keep the frame consistent: the series should keep the same length as
+    /// the other columns and keep its name, since the frame still files it under `name`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::series::Series;
+    /// use veloxx::types::Value;
+    /// use indexmap::IndexMap;
+    ///
+    /// let mut columns = IndexMap::new();
+    /// columns.insert("score".to_string(), Series::new_i32("score", vec![Some(1), None]));
+    /// let mut df = DataFrame::new(columns);
+    ///
+    /// let score = df.get_column_mut("score").unwrap();
+    /// *score = score.fill_nulls(&Value::I32(0)).unwrap();
+    /// assert_eq!(df.get_value(1, "score").unwrap(), Some(Value::I32(0)));
+    /// ```
+    pub fn get_column_mut(&mut self, name: &str) -> Option<&mut Series> {
+        self.columns.get_mut(name)
+    }
+
+    /// Stores `series` under its own name, replacing the column of that name in place or
+    /// appending it as a new last column.
+    ///
+    /// As with [`DataFrame::add_column`], the caller must keep the series the same length as
+    /// the other columns.
+    pub fn set_column(&mut self, series: Series) {
+        // `IndexMap::insert` keeps the position of an existing key.
+        self.columns.insert(series.name().to_string(), series);
+    }
+
+    /// Appends `series` as a new last column, checking it fits the frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns `VeloxxError::InvalidOperation` if a column with the series' name already
+    /// exists or the series' length differs from the existing columns.
+    pub fn insert_column(&mut self, series: Series) -> Result<(), VeloxxError> {
+        if self.columns.contains_key(series.name()) {
+            return Err(VeloxxError::InvalidOperation(format!(
+                "Column '{}' already exists.",
+                series.name()
+            )));
+        }
+        self.check_column_length(&series)?;
+        self.columns.insert(series.name().to_string(), series);
+        Ok(())
+    }
+
+    /// Replaces the column with the series' name, keeping its position in the frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns `VeloxxError::ColumnNotFound` if no column has the series' name, or
+    /// `VeloxxError::InvalidOperation` if the series' length differs from the other columns.
+    pub fn replace_column(&mut self, series: Series) -> Result<(), VeloxxError> {
+        if !self.columns.contains_key(series.name()) {
+            return Err(VeloxxError::ColumnNotFound(series.name().to_string()));
+        }
+        self.check_column_length(&series)?;
+        self.columns.insert(series.name().to_string(), series);
+        Ok(())
+    }
+
+    /// Checks that `series` has the length of every column other than its namesake.
+    fn check_column_length(&self, series: &Series) -> Result<(), VeloxxError> {
+        let mismatch = self
+            .columns
+            .iter()
+            .find(|(name, other)| name.as_str() != series.name() && other.len() != series.len());
+        match mismatch {
+            Some((name, other)) => Err(VeloxxError::InvalidOperation(format!(
+                "Column '{}' has {} rows but column '{}' has {}",
+                series.name(),
+                series.len(),
+                name,
+                other.len()
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns the value of a single cell, or `Ok(None)` if the cell is null.
+    ///
+    /// # Errors
+    ///
+    /// Returns `VeloxxError::ColumnNotFound` if `col` does not exist, or
+    /// `VeloxxError::InvalidOperation` if `row` is not below `row_count()`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::series::Series;
+    /// use veloxx::types::Value;
+    /// use indexmap::IndexMap;
+    ///
+    /// let mut columns = IndexMap::new();
+    /// columns.insert("age".to_string(), Series::new_i32("age", vec![Some(31), None]));
+    /// let df = DataFrame::new(columns);
+    ///
+    /// assert_eq!(df.get_value(0, "age").unwrap(), Some(Value::I32(31)));
+    /// assert_eq!(df.get_value(1, "age").unwrap(), None);
+    /// assert!(df.get_value(2, "age").is_err());
+    /// ```
+    pub fn get_value(&self, row: usize, col: &str) -> Result<Option<Value>, VeloxxError> {
+        let series = self
+            .get_column(col)
+            .ok_or_else(|| VeloxxError::ColumnNotFound(col.to_string()))?;
+        if row >= self.row_count() {
+            return Err(VeloxxError::InvalidOperation(format!(
+                "Row {} is out of range for a DataFrame with {} rows",
+                row,
+                self.row_count()
+            )));
+        }
+        Ok(series.get_value(row))
+    }
+
+    /// Releases unused capacity held by every column, e.g. after many filters and appends.
+    /// Returns the total number of bytes reclaimed.
+    pub fn shrink_to_fit(&mut self) -> usize {
+        let reclaimed = self.columns.values_mut().map(|s| s.shrink_to_fit()).sum();
+        self.columns.shrink_to_fit();
+        reclaimed
+    }
+
+    /// Runs `op` on a dedicated thread pool of `num_threads` threads.
+    ///
+    /// Parallel operations such as joins and group-bys normally run on rayon's global pool,
+    /// which spans every core. Any such operation called inside `op` is bounded to this pool
+    /// instead, so one large operation cannot saturate a machine shared with other work. The
+    /// pool is torn down once `op` returns.
+    ///
+    /// # Arguments
+    ///
+    /// * `num_threads` - The number of worker threads, at least 1.
+    /// * `op` - The work to run inside the pool.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok` with the value returned by `op`,
+    /// `Err(VeloxxError::InvalidOperation)` if `num_threads` is 0, or
+    /// `Err(VeloxxError::Other)` if the pool cannot be created.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::dataframe::join::JoinType;
+    /// use veloxx::series::Series;
+    /// use indexmap::IndexMap;
+    ///
+    /// let mut columns = IndexMap::new();
+    /// columns.insert("id".to_string(), Series::new_i32("id", vec![Some(1), Some(2)]));
+    /// let left = DataFrame::new(columns.clone());
+    /// let right = DataFrame::new(columns);
+    ///
+    /// let joined = DataFrame::with_thread_pool(2, || left.join(&right, "id", JoinType::Inner))
+    ///     .unwrap()
+    ///     .unwrap();
+    /// assert_eq!(joined.row_count(), 2);
+    /// ```
+    pub fn with_thread_pool<T, F>(num_threads: usize, op: F) -> Result<T, VeloxxError>
+    where
+        F: FnOnce() -> T + Send,
+        T: Send,
+    {
+        if num_threads == 0 {
+            return Err(VeloxxError::InvalidOperation(
+                "Thread pool size must be at least 1".to_string(),
+            ));
+        }
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(num_threads)
+            .build()
+            .map_err(|e| VeloxxError::Other(format!("Failed to create thread pool: {}", e)))?;
+        Ok(pool.install(op))
+    }
 }
 
 impl Default for DataFrame {
@@ -15,189 +15,512 @@ use std::collections::HashMap;
 pub trait Pivot {
     /// Reshape the DataFrame from long to wide format.
     ///
+    /// Rows are grouped by the `index` columns and every unique value of the `columns` column
+    /// becomes a new column (sorted by header value). Each cell aggregates a value column over the
+    /// rows of that index × column combination with `agg`; combinations with no rows are
+    /// null. This is [`Pivot::pivot_table`] restricted to the core aggregations, with an
+    /// upfront check of every named column.
+    ///
+    /// With a single value column the new columns are named after the header values. With
+    /// several, every value column is spread in turn and its columns are named
+    /// `<value>_<header>`, ordered by value column and then by header, so `revenue` and
+    /// `units` by region give `revenue_East, revenue_West, units_East, units_West`.
+    ///
     /// # Arguments
     ///
-    /// * `values` - The column name containing the values to be aggregated.
     /// * `index` - The column names to group by (these will be the row identifiers).
     /// * `columns` - The column name whose unique values will become the new column headers.
-    /// * `agg_fn` - The aggregation function to apply (e.g., "sum", "mean", "min", "max", "count").
+    /// * `values` - The column names containing the values to be aggregated.
+    /// * `agg` - The aggregation function to apply to each value column: "sum", "mean",
+    ///   "count", "min", "max" or "first" (the first non-null value, of any type).
     ///
     /// # Returns
     ///
-    /// A new `DataFrame` with the reshaped data.
+    /// A `Result` which is `Ok(DataFrame)` with the index columns followed by the spread
+    /// columns, `Err(VeloxxError::ColumnNotFound)` if any named column does not exist,
+    /// `Err(VeloxxError::Unsupported)` if `agg` is not a known aggregation, or
+    /// `Err(VeloxxError::InvalidOperation)` if `values` is empty.
+    ///
+    /// # Examples
+    ///
+    /// With a unique index and `"first"`, `pivot` undoes [`DataFrame::melt`]: every melted row
+    /// fills exactly one cell, so the wide frame comes back with its values and nulls intact.
+    /// Only the column order differs (the spread columns are sorted by header), and value
+    /// columns that `melt` widened to a common type keep the widened type.
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::{DataFrame, Pivot};
+    /// use veloxx::series::Series;
+    /// use indexmap::IndexMap;
+    ///
+    /// let mut columns = IndexMap::new();
+    /// columns.insert("id".to_string(), Series::new_i32("id", vec![Some(1), Some(2)]));
+    /// columns.insert("q2".to_string(), Series::new_f64("q2", vec![Some(4.0), None]));
+    /// columns.insert("q1".to_string(), Series::new_f64("q1", vec![Some(1.5), Some(2.5)]));
+    /// let wide = DataFrame::new(columns);
+    ///
+    /// let long = wide
+    ///     .melt(&["id".to_string()], &["q2".to_string(), "q1".to_string()])
+    ///     .unwrap();
+    /// let back = long
+    ///     .pivot(&["id".to_string()], "variable", &["value"], "first")
+    ///     .unwrap();
+    ///
+    /// assert_eq!(back.column_names(), vec!["id", "q1", "q2"]);
+    /// for name in ["id", "q1", "q2"] {
+    ///     assert_eq!(back.get_column(name), wide.get_column(name));
+    /// }
+    /// ```
     fn pivot(
         &self,
-        values: &str,
+        index: &[String],
+        columns: &str,
+        values: &[&str],
+        agg: &str,
+    ) -> Result<DataFrame, VeloxxError>;
+
+    /// Build a spreadsheet-style pivot table.
+    ///
+    /// Rows are the unique combinations of the `index` columns (in order of first appearance)
+    /// and there is one column per unique value of the `columns` column, sorted by value (so
+    /// numeric headers follow numeric order) and named after its formatted value.
+    /// Each cell holds the aggregation of the `values` column over the rows that fall into
+    /// that index × column combination. Combinations with no rows are null.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The column names identifying the rows of the table.
+    /// * `columns` - The column whose unique values become the new column headers.
+    /// * `values` - The column to aggregate into each cell.
+    /// * `aggfunc` - One of "sum", "mean", "median", "min", "max", "count" or "first".
+    ///
+    /// # Returns
+    ///
+    /// A new `DataFrame` with the index columns followed by one column per header, or
+    /// `Err(VeloxxError::InvalidOperation)` if a header's name matches an index column.
+    fn pivot_table(
+        &self,
         index: Vec<String>,
         columns: &str,
-        agg_fn: &str,
+        values: &str,
+        aggfunc: &str,
     ) -> Result<DataFrame, VeloxxError>;
 }
 
 impl Pivot for DataFrame {
     fn pivot(
         &self,
-        values: &str,
-        index: Vec<String>,
+        index: &[String],
         columns: &str,
-        agg_fn: &str,
+        values: &[&str],
+        agg: &str,
     ) -> Result<DataFrame, VeloxxError> {
-        // 1. Group by the index columns
-        // We need to group by `index` AND the `columns` column first to get unique cells.
-        // Actually, standard pivot logic:
-        // Group by [index + columns] -> Aggregate [values] -> Reshape.
-
-        let mut group_cols = index.clone();
-        group_cols.push(columns.to_string());
-
-        let grouped = self.group_by(group_cols.clone())?;
-
-        // 2. Aggregate
-        // The resulting DF will have: [index cols..., columns_col, values_aggregated]
-        let agg_res = grouped.agg(vec![(values, agg_fn)])?;
-
-        // The name of the aggregated value column
-        let val_col_name = format!("{}_{}", values, agg_fn);
+        let names = index.iter().map(|s| s.as_str()).chain([columns]);
+        for name in names.chain(values.iter().copied()) {
+            if self.get_column(name).is_none() {
+                return Err(VeloxxError::ColumnNotFound(name.to_string()));
+            }
+        }
+        if !matches!(agg, "sum" | "mean" | "count" | "min" | "max" | "first") {
+            return Err(VeloxxError::Unsupported(format!(
+                "Unsupported pivot aggregation: {}",
+                agg
+            )));
+        }
+        match values {
+            [] => Err(VeloxxError::InvalidOperation(
+                "Pivot requires at least one value column".to_string(),
+            )),
+            [value] => self.pivot_table(index.to_vec(), columns, value, agg),
+            _ => {
+                // Every value column groups the same rows by the same keys, so the tables line
+                // up row for row and only their header columns need combining.
+                let mut final_columns = IndexMap::new();
+                for value in values {
+                    let table = self.pivot_table(index.to_vec(), columns, value, agg)?;
+                    for (name, mut series) in table.columns {
+                        if index.contains(&name) {
+                            final_columns.entry(name).or_insert(series);
+                        } else {
+                            let name = format!("{value}_{name}");
+                            series.set_name(&name);
+                            final_columns.insert(name, series);
+                        }
+                    }
+                }
+                Ok(DataFrame::new(final_columns))
+            }
+        }
+    }
 
-        // 3. Identify unique values in the `columns` column to form new headers
-        let pivot_col_series = agg_res
+    fn pivot_table(
+        &self,
+        index: Vec<String>,
+        columns: &str,
+        values: &str,
+        aggfunc: &str,
+    ) -> Result<DataFrame, VeloxxError> {
+        let value_series = self
+            .get_column(values)
+            .ok_or(VeloxxError::ColumnNotFound(values.to_string()))?;
+        let header_series = self
             .get_column(columns)
             .ok_or(VeloxxError::ColumnNotFound(columns.to_string()))?;
-
-        // Get unique values from the pivot column to determine new column headers
-        // We need to collect them to a Vec<String> to ensure stable order.
-        let mut unique_headers: Vec<String> = Vec::new();
-        let len = pivot_col_series.len();
-
-        for i in 0..len {
-            let val_str = match pivot_col_series.get_value(i) {
-                Some(Value::String(s)) => s,
-                Some(v) => v.to_string(),
-                None => "null".to_string(),
-            };
-            if !unique_headers.contains(&val_str) {
-                unique_headers.push(val_str);
+        let cell_dtype = match aggfunc {
+            "count" => DataType::I32,
+            "mean" | "median" => DataType::F64,
+            "sum" | "min" | "max" | "first" => value_series.data_type(),
+            _ => {
+                return Err(VeloxxError::InvalidOperation(format!(
+                    "Unsupported aggregation function: {}",
+                    aggfunc
+                )))
             }
-        }
-        unique_headers.sort(); // Sort headers for deterministic output
-
-        // 4. Construct the new DataFrame
-        // We need to map each (index_tuple) -> { header -> value }
-
-        // Build a map of (IndexKey) -> { Header -> Value }
-        // IndexKey is a tuple of values from `index` columns.
-
-        // Let's use a HashMap<Vec<String>, HashMap<String, Value>>
-        // where outer key is the row identifier, inner key is the column header.
-
-        let mut row_map: HashMap<Vec<String>, HashMap<String, Value>> = HashMap::new();
-        let mut row_order: Vec<Vec<String>> = Vec::new(); // To preserve row order based on first appearance
-
-        let agg_len = agg_res.row_count();
-        let agg_val_series = agg_res
-            .get_column(&val_col_name)
-            .ok_or(VeloxxError::ColumnNotFound(val_col_name.clone()))?;
-
-        for i in 0..agg_len {
-            // Extract index key
-            let mut key = Vec::with_capacity(index.len());
-            for idx_col in &index {
-                let s = agg_res.get_column(idx_col).unwrap();
-                let v = s.get_value(i).unwrap_or(Value::Null).to_string();
-                key.push(v);
+        };
+
+        let mut group_cols: Vec<&str> = index.iter().map(|s| s.as_str()).collect();
+        group_cols.push(columns);
+        let cells = self.group_row_indices(&group_cols)?;
+
+        let mut row_order: IndexMap<Vec<Value>, HashMap<Value, Value>> = IndexMap::new();
+        let mut headers: Vec<Value> = Vec::new();
+        for rows in &cells {
+            let first = rows[0];
+            let key: Vec<Value> = index
+                .iter()
+                .map(|col| {
+                    self.get_column(col)
+                        .and_then(|s| s.get_value(first))
+                        .unwrap_or(Value::Null)
+                })
+                .collect();
+            let header = header_series.get_value(first).unwrap_or(Value::Null);
+            if !headers.contains(&header) {
+                headers.push(header.clone());
             }
 
-            // Extract header
-            let header_val = match pivot_col_series.get_value(i) {
-                Some(Value::String(s)) => s,
-                Some(v) => v.to_string(),
-                None => "null".to_string(),
+            let cell_values = value_series.filter(rows)?;
+            let cell = if aggfunc == "count" {
+                Some(Value::I32(cell_values.count() as i32))
+            } else if cell_values.count() == 0 {
+                None
+            } else {
+                Some(match aggfunc {
+                    "sum" => cell_values.sum()?,
+                    "mean" => cell_values.mean()?,
+                    "median" => cell_values.median()?,
+                    "min" => cell_values.min()?,
+                    "first" => (0..cell_values.len())
+                        .find_map(|i| cell_values.get_value(i))
+                        .unwrap_or(Value::Null),
+                    _ => cell_values.max()?,
+                })
             };
 
-            // Extract value
-            let cell_val = agg_val_series.get_value(i).unwrap_or(Value::Null);
-
-            if !row_map.contains_key(&key) {
-                row_map.insert(key.clone(), HashMap::new());
-                row_order.push(key.clone());
-            }
-
-            if let Some(inner_map) = row_map.get_mut(&key) {
-                inner_map.insert(header_val, cell_val);
+            let row = row_order.entry(key).or_default();
+            if let Some(cell) = cell {
+                row.insert(header, cell);
             }
         }
+        // Order by value so numeric headers come out as 1, 2, 10 rather than 1, 10, 2.
+        headers.sort();
+        let header_names: Vec<String> = headers
+            .iter()
+            .map(|header| match header {
+                Value::String(s) => s.clone(),
+                Value::Null => "null".to_string(),
+                v => v.to_string(),
+            })
+            .collect();
 
-        // 5. Build final Series
         let mut final_columns = IndexMap::new();
-
-        // RESTARTING LOOP with Value keys for type safety
-        let mut row_map_typed: HashMap<Vec<Value>, HashMap<String, Value>> = HashMap::new();
-        let mut row_order_typed: Vec<Vec<Value>> = Vec::new();
-
-        for i in 0..agg_len {
-            let mut key = Vec::with_capacity(index.len());
-            for idx_col in &index {
-                let s = agg_res.get_column(idx_col).unwrap();
-                let v = s.get_value(i).unwrap_or(Value::Null);
-                key.push(v);
+        for (i, col_name) in index.iter().enumerate() {
+            let data: Vec<Option<Value>> = row_order
+                .keys()
+                .map(|key| match &key[i] {
+                    Value::Null => None,
+                    v => Some(v.clone()),
+                })
+                .collect();
+            let dtype = self.get_column(col_name).unwrap().data_type();
+            final_columns.insert(
+                col_name.clone(),
+                Series::from_values(col_name, data, dtype)?,
+            );
+        }
+        for (header, name) in headers.iter().zip(&header_names) {
+            if final_columns.contains_key(name) {
+                return Err(VeloxxError::InvalidOperation(format!(
+                    "Pivot header '{name}' clashes with another output column"
+                )));
             }
+            let data: Vec<Option<Value>> = row_order
+                .values()
+                .map(|cells| cells.get(header).cloned())
+                .collect();
+            final_columns.insert(
+                name.clone(),
+                Series::from_values(name, data, cell_dtype.clone())?,
+            );
+        }
 
-            let header_val = match pivot_col_series.get_value(i) {
-                Some(Value::String(s)) => s,
-                Some(v) => v.to_string(),
-                None => "null".to_string(),
-            };
+        Ok(DataFrame::new(final_columns))
+    }
+}
 
-            let cell_val = agg_val_series.get_value(i).unwrap_or(Value::Null);
+impl DataFrame {
+    /// Unpivot the `value_vars` columns from wide to long format, preserving their type.
+    ///
+    /// The `id_vars` columns are repeated once per value column, a `variable` String column
+    /// records which value column each row came from, and a `value` column holds the stacked
+    /// values. Rows are ordered by value column, then by original row. When every value column
+    /// shares a type the `value` column keeps that exact type (`I32` stays `I32`, `DateTime`
+    /// stays `DateTime`); a mix of numeric types is widened (`I32` with `I64` to `I64`, any
+    /// integer with `F64` to `F64`), and any other mix falls back to `String`.
+    ///
+    /// # Arguments
+    ///
+    /// * `id_vars` - The columns to keep as identifiers.
+    /// * `value_vars` - The columns to unpivot into rows.
+    ///
+    /// # Returns
+    ///
+    /// A new `DataFrame` with `row_count * value_vars.len()` rows, or
+    /// `Err(VeloxxError::ColumnNotFound)` if any of the columns do not exist.
+    pub fn unpivot_preserve_types(
+        &self,
+        id_vars: &[String],
+        value_vars: &[String],
+    ) -> Result<DataFrame, VeloxxError> {
+        self.unpivot(id_vars, value_vars, "variable", "value")
+    }
 
-            if !row_map_typed.contains_key(&key) {
-                row_map_typed.insert(key.clone(), HashMap::new());
-                row_order_typed.push(key.clone());
-            }
+    /// Melt the `value_vars` columns from wide to long format.
+    ///
+    /// The `id_vars` columns are repeated once per value column, a `variable` String column
+    /// records which value column each row came from, and a `value` column holds the stacked
+    /// values, ordered by value column, then by original row. Unlike
+    /// [`DataFrame::unpivot_preserve_types`], the value columns must have compatible types:
+    /// the same type, or numeric types that can be widened (`I32` with `I64` to `I64`, any
+    /// integer with `F64` to `F64`). Anything else is an error rather than a silent
+    /// conversion to `String`.
+    ///
+    /// # Arguments
+    ///
+    /// * `id_vars` - The columns to keep as identifiers.
+    /// * `value_vars` - The columns to melt into rows.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok(DataFrame)` with `row_count * value_vars.len()` rows,
+    /// `Err(VeloxxError::ColumnNotFound)` if any of the columns do not exist, or
+    /// `Err(VeloxxError::DataTypeMismatch)` if the value columns have incompatible types.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::series::Series;
+    /// use veloxx::types::Value;
+    /// use indexmap::IndexMap;
+    ///
+    /// let mut columns = IndexMap::new();
+    /// columns.insert("store".to_string(), Series::new_string("store", vec![Some("a".to_string())]));
+    /// columns.insert("q1".to_string(), Series::new_i32("q1", vec![Some(10)]));
+    /// columns.insert("q2".to_string(), Series::new_f64("q2", vec![Some(12.5)]));
+    /// let df = DataFrame::new(columns);
+    ///
+    /// let long = df.melt(&["store".to_string()], &["q1".to_string(), "q2".to_string()]).unwrap();
+    /// assert_eq!(long.column_names(), vec!["store", "variable", "value"]);
+    /// assert_eq!(long.get_column("value").unwrap().get_value(0), Some(Value::F64(10.0)));
+    /// ```
+    pub fn melt(
+        &self,
+        id_vars: &[String],
+        value_vars: &[String],
+    ) -> Result<DataFrame, VeloxxError> {
+        self.melt_with_names(id_vars, value_vars, "variable", "value")
+    }
 
-            if let Some(inner_map) = row_map_typed.get_mut(&key) {
-                inner_map.insert(header_val, cell_val);
-            }
+    /// Melt the `value_vars` columns from wide to long format under custom column names.
+    ///
+    /// Behaves like [`DataFrame::melt`], including its check that the value columns have
+    /// compatible types, but the column recording the source column is named `var_name` and
+    /// the column holding the stacked values is named `value_name`, so the result can match a
+    /// downstream schema without a rename step.
+    ///
+    /// # Arguments
+    ///
+    /// * `id_vars` - The columns to keep as identifiers.
+    /// * `value_vars` - The columns to melt into rows.
+    /// * `var_name` - The name of the output column holding the source column names.
+    /// * `value_name` - The name of the output column holding the values.
+    ///
+    /// # Returns
+    ///
+    /// A new `DataFrame` with `row_count * value_vars.len()` rows,
+    /// `Err(VeloxxError::ColumnNotFound)` if any of the columns do not exist,
+    /// `Err(VeloxxError::DataTypeMismatch)` if the value columns have incompatible types, or
+    /// `Err(VeloxxError::InvalidOperation)` if `var_name` and `value_name` are equal or
+    /// collide with one of the `id_vars`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::series::Series;
+    /// use indexmap::IndexMap;
+    ///
+    /// let mut columns = IndexMap::new();
+    /// columns.insert("sensor".to_string(), Series::new_string("sensor", vec![Some("s1".to_string())]));
+    /// columns.insert("temp".to_string(), Series::new_f64("temp", vec![Some(21.5)]));
+    /// columns.insert("humidity".to_string(), Series::new_f64("humidity", vec![Some(0.4)]));
+    /// let df = DataFrame::new(columns);
+    ///
+    /// let long = df
+    ///     .melt_with_names(
+    ///         &["sensor".to_string()],
+    ///         &["temp".to_string(), "humidity".to_string()],
+    ///         "metric",
+    ///         "reading",
+    ///     )
+    ///     .unwrap();
+    /// assert_eq!(long.column_names(), vec!["sensor", "metric", "reading"]);
+    /// assert_eq!(long.row_count(), 2);
+    /// ```
+    pub fn melt_with_names(
+        &self,
+        id_vars: &[String],
+        value_vars: &[String],
+        var_name: &str,
+        value_name: &str,
+    ) -> Result<DataFrame, VeloxxError> {
+        if var_name == value_name {
+            return Err(VeloxxError::InvalidOperation(format!(
+                "Melt variable and value columns must have different names, both are '{var_name}'"
+            )));
         }
-
-        // Now build columns
-        // Index columns
-        for (i, col_name) in index.iter().enumerate() {
-            let mut data = Vec::with_capacity(row_order_typed.len());
-            for row_key in &row_order_typed {
-                data.push(Some(row_key[i].clone()));
+        if let Some(id) = id_vars
+            .iter()
+            .find(|id| id.as_str() == var_name || id.as_str() == value_name)
+        {
+            return Err(VeloxxError::InvalidOperation(format!(
+                "Melt output column '{id}' collides with an id column"
+            )));
+        }
+        let mut value_series = Vec::with_capacity(value_vars.len());
+        for name in id_vars.iter().chain(value_vars) {
+            let series = self
+                .get_column(name)
+                .ok_or_else(|| VeloxxError::ColumnNotFound(name.clone()))?;
+            if value_vars.contains(name) {
+                value_series.push(series);
             }
-
-            // Create series from Vec<Option<Value>>
-            // We need a helper `Series::from_any_values` or match on first value type.
-            // Or check original series type again.
-            let orig_series = agg_res.get_column(col_name).unwrap();
-            let new_s = Series::from_values(col_name, data, orig_series.data_type())?;
-            final_columns.insert(col_name.clone(), new_s);
         }
+        if compatible_value_type(&value_series).is_none() {
+            let types: Vec<String> = value_series
+                .iter()
+                .map(|s| format!("{} ({:?})", s.name(), s.data_type()))
+                .collect();
+            return Err(VeloxxError::DataTypeMismatch(format!(
+                "Cannot melt columns with incompatible types: {}",
+                types.join(", ")
+            )));
+        }
+        self.unpivot(id_vars, value_vars, var_name, value_name)
+    }
 
-        // Pivot columns (headers)
-        // What is the type of the values? It's the type of `values` column.
-        let val_dtype = agg_val_series.data_type();
+    fn unpivot(
+        &self,
+        id_vars: &[String],
+        value_vars: &[String],
+        var_name: &str,
+        value_name: &str,
+    ) -> Result<DataFrame, VeloxxError> {
+        let lookup = |names: &[String]| -> Result<Vec<&Series>, VeloxxError> {
+            names
+                .iter()
+                .map(|name| {
+                    self.get_column(name)
+                        .ok_or_else(|| VeloxxError::ColumnNotFound(name.clone()))
+                })
+                .collect()
+        };
+        let id_series = lookup(id_vars)?;
+        let value_series = lookup(value_vars)?;
+        let value_dtype = common_value_type(&value_series);
+        let row_count = self.row_count();
 
-        for header in &unique_headers {
-            let mut data = Vec::with_capacity(row_order_typed.len());
-            for row_key in &row_order_typed {
-                let inner_map = row_map_typed.get(row_key).unwrap();
-                let val = inner_map.get(header).cloned();
-                data.push(val);
-            }
-            let new_s = Series::from_values(header, data, val_dtype.clone())?;
-            final_columns.insert(header.clone(), new_s);
+        let mut final_columns = IndexMap::new();
+        for (name, series) in id_vars.iter().zip(&id_series) {
+            let data: Vec<Option<Value>> = (0..value_series.len())
+                .flat_map(|_| (0..row_count).map(move |i| series.get_value(i)))
+                .collect();
+            final_columns.insert(
+                name.clone(),
+                Series::from_values(name, data, series.data_type())?,
+            );
         }
 
+        let variables: Vec<Option<String>> = value_vars
+            .iter()
+            .flat_map(|name| std::iter::repeat_n(Some(name.clone()), row_count))
+            .collect();
+        final_columns.insert(
+            var_name.to_string(),
+            Series::new_string(var_name, variables),
+        );
+
+        let target = &value_dtype;
+        let values: Vec<Option<Value>> = value_series
+            .iter()
+            .flat_map(|series| {
+                (0..row_count).map(move |i| {
+                    series.get_value(i).map(|v| match (target, v) {
+                        (DataType::F64, Value::I32(n)) => Value::F64(n as f64),
+                        (DataType::F64, Value::I64(n)) => Value::F64(n as f64),
+                        (DataType::I64, Value::I32(n)) => Value::I64(n as i64),
+                        (DataType::String, Value::String(s)) => Value::String(s),
+                        (DataType::String, v) => Value::String(v.to_string()),
+                        (_, v) => v,
+                    })
+                })
+            })
+            .collect();
+        final_columns.insert(
+            value_name.to_string(),
+            Series::from_values(value_name, values, value_dtype)?,
+        );
+
         Ok(DataFrame::new(final_columns))
     }
 }
 
+/// The narrowest type that holds every value column: their shared type when they all agree,
+/// the widened type for a mix of numeric types, and `String` for anything else.
+fn common_value_type(series: &[&Series]) -> DataType {
+    compatible_value_type(series).unwrap_or(DataType::String)
+}
+
+/// The shared type of the value columns, widening numeric mixes (`I32` with `I64` to `I64`,
+/// any integer with `F64` to `F64`), or `None` when the types cannot share a column.
+pub(super) fn compatible_value_type(series: &[&Series]) -> Option<DataType> {
+    let mut types = series.iter().map(|s| s.data_type());
+    let Some(first) = types.next() else {
+        return Some(DataType::String);
+    };
+    types.try_fold(first, |acc, dtype| match (acc, dtype) {
+        (a, b) if a == b => Some(a),
+        (DataType::I32, DataType::I64) | (DataType::I64, DataType::I32) => Some(DataType::I64),
+        (DataType::I32 | DataType::I64, DataType::F64)
+        | (DataType::F64, DataType::I32 | DataType::I64) => Some(DataType::F64),
+        _ => None,
+    })
+}
+
 // Helper extension for Series to create from generic Values
 impl Series {
-    fn from_values(
+    pub(crate) fn from_values(
         name: &str,
         values: Vec<Option<Value>>,
         dtype: DataType,
@@ -210,6 +533,13 @@ impl Series {
                     .collect();
                 Ok(Series::new_i32(name, extracted))
             }
+            DataType::I64 => {
+                let extracted: Vec<Option<i64>> = values
+                    .into_iter()
+                    .map(|v| v.and_then(|val| val.as_i64()))
+                    .collect();
+                Ok(Series::new_i64(name, extracted))
+            }
             DataType::F64 => {
                 let extracted: Vec<Option<f64>> = values
                     .into_iter()
@@ -1,7 +1,6 @@
 use crate::dataframe::DataFrame;
 use crate::VeloxxError;
 
-#[cfg(test)]
 use crate::series::Series;
 #[cfg(test)]
 use crate::types::Value;
@@ -13,6 +12,9 @@ impl DataFrame {
     ///
     /// This method creates new columns with rolling mean calculations for the specified columns.
     /// The new columns are named with the pattern "{original_name}_rolling_mean_{window_size}".
+    /// The first `window_size - 1` rows are null; after that a window produces a value as long
+    /// as it holds at least one non-null value. Use
+    /// [`Series::rolling_mean`](crate::series::Series::rolling_mean) directly to control `min_periods`.
     ///
     /// # Arguments
     ///
@@ -48,7 +50,8 @@ impl DataFrame {
                 .get_column(&column_name)
                 .ok_or_else(|| VeloxxError::ColumnNotFound(column_name.clone()))?;
 
-            let rolling_series = series.rolling_mean(window_size)?;
+            let rolling_series =
+                null_leading_rows(series.rolling_mean(window_size, 1)?, window_size - 1);
             new_columns.insert(rolling_series.name().to_string(), rolling_series);
         }
 
@@ -59,6 +62,9 @@ impl DataFrame {
     ///
     /// This method creates new columns with rolling sum calculations for the specified columns.
     /// The new columns are named with the pattern "{original_name}_rolling_sum_{window_size}".
+    /// The first `window_size - 1` rows are null; after that a window produces a value as long
+    /// as it holds at least one non-null value. Use
+    /// [`Series::rolling_sum`](crate::series::Series::rolling_sum) directly to control `min_periods`.
     ///
     /// # Arguments
     ///
@@ -80,7 +86,8 @@ impl DataFrame {
                 .get_column(&column_name)
                 .ok_or_else(|| VeloxxError::ColumnNotFound(column_name.clone()))?;
 
-            let rolling_series = series.rolling_sum(window_size)?;
+            let rolling_series =
+                null_leading_rows(series.rolling_sum(window_size, 1)?, window_size - 1);
             new_columns.insert(rolling_series.name().to_string(), rolling_series);
         }
 
@@ -91,6 +98,9 @@ impl DataFrame {
     ///
     /// This method creates new columns with rolling minimum calculations for the specified columns.
     /// The new columns are named with the pattern "{original_name}_rolling_min_{window_size}".
+    /// The first `window_size - 1` rows are null; after that a window produces a value as long
+    /// as it holds at least one non-null value. Use
+    /// [`Series::rolling_min`](crate::series::Series::rolling_min) directly to control `min_periods`.
     ///
     /// # Arguments
     ///
@@ -112,7 +122,8 @@ impl DataFrame {
                 .get_column(&column_name)
                 .ok_or_else(|| VeloxxError::ColumnNotFound(column_name.clone()))?;
 
-            let rolling_series = series.rolling_min(window_size)?;
+            let rolling_series =
+                null_leading_rows(series.rolling_min(window_size, 1)?, window_size - 1);
             new_columns.insert(rolling_series.name().to_string(), rolling_series);
         }
 
@@ -123,6 +134,9 @@ impl DataFrame {
     ///
     /// This method creates new columns with rolling maximum calculations for the specified columns.
     /// The new columns are named with the pattern "{original_name}_rolling_max_{window_size}".
+    /// The first `window_size - 1` rows are null; after that a window produces a value as long
+    /// as it holds at least one non-null value. Use
+    /// [`Series::rolling_max`](crate::series::Series::rolling_max) directly to control `min_periods`.
     ///
     /// # Arguments
     ///
@@ -144,7 +158,8 @@ impl DataFrame {
                 .get_column(&column_name)
                 .ok_or_else(|| VeloxxError::ColumnNotFound(column_name.clone()))?;
 
-            let rolling_series = series.rolling_max(window_size)?;
+            let rolling_series =
+                null_leading_rows(series.rolling_max(window_size, 1)?, window_size - 1);
             new_columns.insert(rolling_series.name().to_string(), rolling_series);
         }
 
@@ -155,6 +170,9 @@ impl DataFrame {
     ///
     /// This method creates new columns with rolling standard deviation calculations for the specified columns.
     /// The new columns are named with the pattern "{original_name}_rolling_std_{window_size}".
+    /// The first `window_size - 1` rows are null; after that a window produces a value as long
+    /// as it holds at least two non-null values. Use
+    /// [`Series::rolling_std`](crate::series::Series::rolling_std) directly to control `min_periods`.
     ///
     /// # Arguments
     ///
@@ -176,7 +194,8 @@ impl DataFrame {
                 .get_column(&column_name)
                 .ok_or_else(|| VeloxxError::ColumnNotFound(column_name.clone()))?;
 
-            let rolling_series = series.rolling_std(window_size)?;
+            let rolling_series =
+                null_leading_rows(series.rolling_std(window_size, 1)?, window_size - 1);
             new_columns.insert(rolling_series.name().to_string(), rolling_series);
         }
 
@@ -252,6 +271,21 @@ impl DataFrame {
     }
 }
 
+/// Nulls the first `count` rows of `series`, which have no full window behind them.
+fn null_leading_rows(mut series: Series, count: usize) -> Series {
+    let validity = match &mut series {
+        Series::I32(_, _, validity)
+        | Series::I64(_, _, validity)
+        | Series::F64(_, _, validity)
+        | Series::Bool(_, _, validity)
+        | Series::String(_, _, validity)
+        | Series::DateTime(_, _, validity) => validity,
+    };
+    let count = count.min(validity.len());
+    validity[..count].fill(false);
+    series
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -423,4 +457,44 @@ mod tests {
         let result = df.rolling_mean(vec!["price".to_string()], 5);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_dataframe_rolling_skips_nulls_after_first_window() {
+        let mut columns = IndexMap::new();
+        columns.insert(
+            "price".to_string(),
+            Series::new_f64("price", vec![Some(10.0), None, Some(20.0), None, None]),
+        );
+        let df = DataFrame::new(columns);
+
+        let result = df.rolling_mean(vec!["price".to_string()], 2).unwrap();
+        let mean = result.get_column("price_rolling_mean_2").unwrap();
+        assert_eq!(mean.get_value(0), None);
+        assert_eq!(mean.get_value(1), Some(Value::F64(10.0)));
+        assert_eq!(mean.get_value(2), Some(Value::F64(20.0)));
+        assert_eq!(mean.get_value(3), Some(Value::F64(20.0)));
+        assert_eq!(mean.get_value(4), None);
+
+        let result = df.rolling_sum(vec!["price".to_string()], 2).unwrap();
+        let sum = result.get_column("price_rolling_sum_2").unwrap();
+        assert_eq!(sum.get_value(0), None);
+        assert_eq!(sum.get_value(1), Some(Value::F64(10.0)));
+
+        let result = df.rolling_max(vec!["price".to_string()], 2).unwrap();
+        let max = result.get_column("price_rolling_max_2").unwrap();
+        assert_eq!(max.get_value(0), None);
+        assert_eq!(max.get_value(1), Some(Value::F64(10.0)));
+        assert_eq!(max.get_value(3), Some(Value::F64(20.0)));
+
+        let result = df.rolling_min(vec!["price".to_string()], 2).unwrap();
+        let min = result.get_column("price_rolling_min_2").unwrap();
+        assert_eq!(min.get_value(0), None);
+        assert_eq!(min.get_value(2), Some(Value::F64(20.0)));
+
+        let result = df.rolling_std(vec!["price".to_string()], 3).unwrap();
+        let std = result.get_column("price_rolling_std_3").unwrap();
+        assert_eq!(std.get_value(1), None);
+        assert!(std.get_value(2).is_some());
+        assert_eq!(std.get_value(3), None);
+    }
 }
@@ -57,7 +57,7 @@ use rayon::prelude::*;
 use std::sync::Arc;
 
 #[cfg(feature = "distributed")]
-use arrow::array::{Array, BooleanArray, Float64Array, Int32Array, StringArray};
+use arrow::array::{Array, BooleanArray, Float64Array, Int32Array, Int64Array, StringArray};
 #[cfg(feature = "distributed")]
 use arrow::datatypes::{DataType as ArrowDataType, Field, Schema};
 #[cfg(feature = "distributed")]
@@ -167,6 +167,11 @@ impl DistributedDataFrame {
                 let sliced_bitmap: Vec<bool> = bitmap[start_row..end_row].to_vec();
                 Ok(Series::I32(name.clone(), sliced_values, sliced_bitmap))
             }
+            Series::I64(name, values, bitmap) => {
+                let sliced_values: Vec<i64> = values[start_row..end_row].to_vec();
+                let sliced_bitmap: Vec<bool> = bitmap[start_row..end_row].to_vec();
+                Ok(Series::I64(name.clone(), sliced_values, sliced_bitmap))
+            }
             Series::F64(name, values, bitmap) => {
                 let sliced_values: Vec<f64> = values[start_row..end_row].to_vec();
                 let sliced_bitmap: Vec<bool> = bitmap[start_row..end_row].to_vec();
@@ -602,6 +607,13 @@ impl ArrowInterop {
                     let arrow_array = Int32Array::from(values.clone());
                     arrays.push(Arc::new(arrow_array));
                 }
+                Series::I64(name, values, _bitmap) => {
+                    let field = Field::new(name, ArrowDataType::Int64, true);
+                    fields.push(field);
+
+                    let arrow_array = Int64Array::from(values.clone());
+                    arrays.push(Arc::new(arrow_array));
+                }
                 Series::F64(name, values, _bitmap) => {
                     let field = Field::new(name, ArrowDataType::Float64, true);
                     fields.push(field);
@@ -1,10 +1,11 @@
 #[cfg(target_arch = "wasm32")]
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug)]
 pub enum VeloxxError {
     ColumnNotFound(String),
     InvalidOperation(String),
     DataTypeMismatch(String),
     FileIO(String),
+    Io(std::io::Error),
     Parsing(String),
     Unsupported(String),
     MemoryError(String),
@@ -20,6 +21,7 @@ impl std::fmt::Display for VeloxxError {
             VeloxxError::InvalidOperation(msg) => write!(f, "Invalid operation: {}", msg),
             VeloxxError::DataTypeMismatch(msg) => write!(f, "Data type mismatch: {}", msg),
             VeloxxError::FileIO(msg) => write!(f, "File I/O error: {}", msg),
+            VeloxxError::Io(err) => write!(f, "I/O error: {}", err),
             VeloxxError::Parsing(msg) => write!(f, "Parsing error: {}", msg),
             VeloxxError::Unsupported(msg) => write!(f, "Unsupported operation: {}", msg),
             VeloxxError::MemoryError(msg) => write!(f, "Memory error: {}", msg),
@@ -30,7 +32,35 @@ impl std::fmt::Display for VeloxxError {
 }
 
 #[cfg(target_arch = "wasm32")]
-impl std::error::Error for VeloxxError {}
+impl std::error::Error for VeloxxError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            VeloxxError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl Clone for VeloxxError {
+    fn clone(&self) -> Self {
+        match self {
+            VeloxxError::ColumnNotFound(msg) => VeloxxError::ColumnNotFound(msg.clone()),
+            VeloxxError::InvalidOperation(msg) => VeloxxError::InvalidOperation(msg.clone()),
+            VeloxxError::DataTypeMismatch(msg) => VeloxxError::DataTypeMismatch(msg.clone()),
+            VeloxxError::FileIO(msg) => VeloxxError::FileIO(msg.clone()),
+            // `std::io::Error` is not `Clone`; keep its kind and message.
+            VeloxxError::Io(err) => {
+                VeloxxError::Io(std::io::Error::new(err.kind(), err.to_string()))
+            }
+            VeloxxError::Parsing(msg) => VeloxxError::Parsing(msg.clone()),
+            VeloxxError::Unsupported(msg) => VeloxxError::Unsupported(msg.clone()),
+            VeloxxError::MemoryError(msg) => VeloxxError::MemoryError(msg.clone()),
+            VeloxxError::ExecutionError(msg) => VeloxxError::ExecutionError(msg.clone()),
+            VeloxxError::Other(msg) => VeloxxError::Other(msg.clone()),
+        }
+    }
+}
 // This file handles error types for the Veloxx library.
 // Ensure that any error handling that uses non-WASM-compatible dependencies
 // is feature gated and excluded from WASM builds.
@@ -43,6 +73,10 @@ use thiserror::Error;
 /// for common issues like column not found, invalid operations, data type mismatches,
 /// and I/O errors.
 ///
+/// Failures of the underlying reader or writer are reported as [`VeloxxError::Io`], which
+/// keeps the original `std::io::Error` (and its [`kind`](std::io::Error::kind)) available
+/// through `source()`. Malformed input is reported as [`VeloxxError::Parsing`].
+///
 /// # Examples
 ///
 /// ```rust
@@ -59,7 +93,7 @@ use thiserror::Error;
 /// // Output: Error: Invalid operation: Cannot divide by zero
 /// ```
 #[cfg(not(target_arch = "wasm32"))]
-#[derive(Error, Debug, PartialEq)]
+#[derive(Error, Debug)]
 pub enum VeloxxError {
     #[error("Column not found: {0}")]
     ColumnNotFound(String),
@@ -69,6 +103,8 @@ pub enum VeloxxError {
     DataTypeMismatch(String),
     #[error("File I/O error: {0}")]
     FileIO(String),
+    #[error("I/O error: {0}")]
+    Io(#[source] std::io::Error),
     #[error("Parsing error: {0}")]
     Parsing(String),
     #[error("Unsupported feature: {0}")]
@@ -81,10 +117,25 @@ pub enum VeloxxError {
     Other(String),
 }
 
-#[cfg(not(target_arch = "wasm32"))]
+/// Errors are equal when they are the same variant with the same message; I/O errors also
+/// need the same [`std::io::ErrorKind`].
+impl PartialEq for VeloxxError {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (VeloxxError::Io(a), VeloxxError::Io(b)) => {
+                a.kind() == b.kind() && a.to_string() == b.to_string()
+            }
+            _ => {
+                std::mem::discriminant(self) == std::mem::discriminant(other)
+                    && self.to_string() == other.to_string()
+            }
+        }
+    }
+}
+
 impl From<std::io::Error> for VeloxxError {
     fn from(err: std::io::Error) -> Self {
-        VeloxxError::FileIO(err.to_string())
+        VeloxxError::Io(err)
     }
 }
 
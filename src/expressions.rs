@@ -1,4 +1,6 @@
-use crate::types::Value;
+use crate::conditions::Condition;
+use crate::series::Series;
+use crate::types::{DataType, Value};
 use crate::VeloxxError;
 
 /// Represents an expression that can be evaluated against a DataFrame row.
@@ -84,6 +86,82 @@ use crate::VeloxxError;
 ///     Box::new(Expr::Not(Box::new(Expr::Column("is_suspended".to_string())))),
 /// );
 /// ```
+///
+/// ## Null Handling
+///
+/// Replace nulls in "discount" with 0:
+///
+/// ```rust
+/// use veloxx::expressions::Expr;
+/// use veloxx::types::Value;
+///
+/// let expr = Expr::IfNull(
+///     Box::new(Expr::Column("discount".to_string())),
+///     Box::new(Expr::Literal(Value::I32(0))),
+/// );
+/// ```
+///
+/// Take "preferred" where present, then "fallback", then 0:
+///
+/// ```rust
+/// use veloxx::expressions::Expr;
+/// use veloxx::types::Value;
+///
+/// let expr = Expr::Coalesce(vec![
+///     Expr::Column("preferred".to_string()),
+///     Expr::Column("fallback".to_string()),
+///     Expr::Literal(Value::I32(0)),
+/// ]);
+/// ```
+///
+/// ## String Expressions
+///
+/// Build "FIRST LAST" from the "first" and "last" columns:
+///
+/// ```rust
+/// use veloxx::expressions::Expr;
+/// use veloxx::types::Value;
+///
+/// let expr = Expr::Upper(Box::new(Expr::Concat(vec![
+///     Expr::Column("first".to_string()),
+///     Expr::Literal(Value::String(" ".to_string())),
+///     Expr::Column("last".to_string()),
+/// ])));
+/// ```
+///
+/// ## Conditional Expressions
+///
+/// Bucket the "age" column, with null ages falling through to "unknown":
+///
+/// ```rust
+/// use veloxx::conditions::Condition;
+/// use veloxx::expressions::Expr;
+/// use veloxx::types::Value;
+///
+/// let label = |s: &str| Expr::Literal(Value::String(s.to_string()));
+/// let expr = Expr::Case {
+///     branches: vec![
+///         (Condition::Lt("age".to_string(), Value::I32(18)), label("minor")),
+///         (Condition::Lt("age".to_string(), Value::I32(65)), label("adult")),
+///         (Condition::Gt("age".to_string(), Value::I32(64)), label("senior")),
+///     ],
+///     otherwise: Box::new(label("unknown")),
+/// };
+/// ```
+///
+/// ## Type Conversion
+///
+/// Divide the integer "total" column by "count" as floating-point numbers:
+///
+/// ```rust
+/// use veloxx::expressions::Expr;
+/// use veloxx::types::DataType;
+///
+/// let expr = Expr::Divide(
+///     Box::new(Expr::Cast(Box::new(Expr::Column("total".to_string())), DataType::F64)),
+///     Box::new(Expr::Cast(Box::new(Expr::Column("count".to_string())), DataType::F64)),
+/// );
+/// ```
 #[derive(Debug, Clone)]
 pub enum Expr {
     /// Refers to a column by its name.
@@ -173,11 +251,84 @@ pub enum Expr {
     /// # Arguments
     /// - `Box<Expr>`: The expression to negate.
     Not(Box<Expr>),
+    /// Tests whether an expression evaluates to null, producing a boolean.
+    ///
+    /// # Arguments
+    /// - `Box<Expr>`: The expression to test.
+    IsNull(Box<Expr>),
+    /// Tests whether an expression evaluates to a non-null value, producing a boolean.
+    ///
+    /// # Arguments
+    /// - `Box<Expr>`: The expression to test.
+    IsNotNull(Box<Expr>),
+    /// Evaluates to the first expression, or to the second when the first is null.
+    ///
+    /// # Arguments
+    /// - `Box<Expr>`: The expression to evaluate.
+    /// - `Box<Expr>`: The fallback used when the first expression is null.
+    IfNull(Box<Expr>, Box<Expr>),
+    /// Evaluates the expressions left to right and produces the first non-null result, or null
+    /// when every expression is null. Expressions after the first non-null one are not
+    /// evaluated.
+    ///
+    /// # Arguments
+    /// - `Vec<Expr>`: The expressions to try, in order of preference.
+    Coalesce(Vec<Expr>),
+    /// Joins the string representations of the evaluated expressions, producing a string.
+    /// The result is null if any expression is null.
+    ///
+    /// # Arguments
+    /// - `Vec<Expr>`: The expressions to join, in order.
+    Concat(Vec<Expr>),
+    /// Converts a string expression to uppercase.
+    ///
+    /// # Arguments
+    /// - `Box<Expr>`: The string expression to convert.
+    Upper(Box<Expr>),
+    /// Converts a string expression to lowercase.
+    ///
+    /// # Arguments
+    /// - `Box<Expr>`: The string expression to convert.
+    Lower(Box<Expr>),
+    /// Counts the characters of a string expression, producing an I32.
+    ///
+    /// # Arguments
+    /// - `Box<Expr>`: The string expression to measure.
+    StrLength(Box<Expr>),
+    /// Converts an expression's value to another type with the rules of
+    /// [`Series::cast`](crate::series::Series::cast).
+    ///
+    /// Values that cannot be represented in the target type, such as unparseable strings or
+    /// out-of-range floats, become null, as they do in `Series::cast`. A conversion that
+    /// `Series::cast` does not support at all (e.g. `DateTime` to `Bool`) is an error. Nulls stay
+    /// null.
+    ///
+    /// # Arguments
+    /// - `Box<Expr>`: The expression to convert.
+    /// - `DataType`: The type to convert to.
+    Cast(Box<Expr>, DataType),
+    /// SQL-style `CASE WHEN`: evaluates the branch conditions in order and produces the
+    /// expression of the first one that holds, or `otherwise` when none does.
+    ///
+    /// Only the chosen expression is evaluated. A condition on a null cell does not hold, so
+    /// rows with nulls fall through to later branches. Every branch and `otherwise` should
+    /// produce the same type; [`DataFrame::with_column`](crate::dataframe::DataFrame::with_column)
+    /// rejects a mix.
+    Case {
+        /// The `(condition, expression)` pairs, tried in order.
+        branches: Vec<(Condition, Expr)>,
+        /// The expression used when no condition holds.
+        otherwise: Box<Expr>,
+    },
 }
 
 impl Expr {
     /// Evaluates the expression for a specific row in the DataFrame.
     ///
+    /// Null cells evaluate to `Value::Null`, which only the null-aware expressions
+    /// (`IsNull`, `IsNotNull`, `IfNull`, `Coalesce`), equality comparisons and the string
+    /// expressions (which propagate it) accept.
+    ///
     /// Returns the computed `Value` or an error if the expression cannot be evaluated.
     pub fn evaluate(
         &self,
@@ -189,11 +340,12 @@ impl Expr {
                 let series = df
                     .get_column(col_name)
                     .ok_or(VeloxxError::ColumnNotFound(col_name.to_string()))?;
-                series
-                    .get_value(row_index)
-                    .ok_or(VeloxxError::InvalidOperation(format!(
-                        "Null value at row {row_index} in column {col_name}"
-                    )))
+                if row_index >= series.len() {
+                    return Err(VeloxxError::InvalidOperation(format!(
+                        "Row {row_index} out of bounds for column {col_name}"
+                    )));
+                }
+                Ok(series.get_value(row_index).unwrap_or(Value::Null))
             }
             Expr::Literal(value) => Ok(value.clone()),
             Expr::Add(left, right) => {
@@ -201,6 +353,11 @@ impl Expr {
                 let right_val = right.evaluate(df, row_index)?;
                 match (left_val, right_val) {
                     (Value::I32(l), Value::I32(r)) => Ok(Value::I32(l + r)),
+                    (Value::I64(l), Value::I64(r)) => {
+                        l.checked_add(r).map(Value::I64).ok_or_else(|| {
+                            VeloxxError::InvalidOperation("I64 overflow in addition".to_string())
+                        })
+                    }
                     (Value::F64(l), Value::F64(r)) => Ok(Value::F64(l + r)),
                     _ => Err(VeloxxError::InvalidOperation(
                         "Unsupported types for addition".to_string(),
@@ -212,6 +369,11 @@ impl Expr {
                 let right_val = right.evaluate(df, row_index)?;
                 match (left_val, right_val) {
                     (Value::I32(l), Value::I32(r)) => Ok(Value::I32(l - r)),
+                    (Value::I64(l), Value::I64(r)) => {
+                        l.checked_sub(r).map(Value::I64).ok_or_else(|| {
+                            VeloxxError::InvalidOperation("I64 overflow in subtraction".to_string())
+                        })
+                    }
                     (Value::F64(l), Value::F64(r)) => Ok(Value::F64(l - r)),
                     _ => Err(VeloxxError::InvalidOperation(
                         "Unsupported types for subtraction".to_string(),
@@ -223,6 +385,13 @@ impl Expr {
                 let right_val = right.evaluate(df, row_index)?;
                 match (left_val, right_val) {
                     (Value::I32(l), Value::I32(r)) => Ok(Value::I32(l * r)),
+                    (Value::I64(l), Value::I64(r)) => {
+                        l.checked_mul(r).map(Value::I64).ok_or_else(|| {
+                            VeloxxError::InvalidOperation(
+                                "I64 overflow in multiplication".to_string(),
+                            )
+                        })
+                    }
                     (Value::F64(l), Value::F64(r)) => Ok(Value::F64(l * r)),
                     _ => Err(VeloxxError::InvalidOperation(
                         "Unsupported types for multiplication".to_string(),
@@ -241,6 +410,16 @@ impl Expr {
                         }
                         Ok(Value::I32(l / r))
                     }
+                    (Value::I64(l), Value::I64(r)) => {
+                        if r == 0 {
+                            return Err(VeloxxError::InvalidOperation(
+                                "Division by zero".to_string(),
+                            ));
+                        }
+                        l.checked_div(r).map(Value::I64).ok_or_else(|| {
+                            VeloxxError::InvalidOperation("I64 overflow in division".to_string())
+                        })
+                    }
                     (Value::F64(l), Value::F64(r)) => {
                         if r == 0.0 {
                             return Err(VeloxxError::InvalidOperation(
@@ -337,6 +516,112 @@ impl Expr {
                     )),
                 }
             }
+            Expr::IsNull(expr) => Ok(Value::Bool(expr.evaluate(df, row_index)? == Value::Null)),
+            Expr::IsNotNull(expr) => Ok(Value::Bool(expr.evaluate(df, row_index)? != Value::Null)),
+            Expr::IfNull(expr, fallback) => match expr.evaluate(df, row_index)? {
+                Value::Null => fallback.evaluate(df, row_index),
+                value => Ok(value),
+            },
+            Expr::Coalesce(exprs) => {
+                for expr in exprs {
+                    match expr.evaluate(df, row_index)? {
+                        Value::Null => continue,
+                        value => return Ok(value),
+                    }
+                }
+                Ok(Value::Null)
+            }
+            Expr::Concat(exprs) => {
+                let mut joined = String::new();
+                for expr in exprs {
+                    match expr.evaluate(df, row_index)? {
+                        Value::Null => return Ok(Value::Null),
+                        Value::String(s) => joined.push_str(&s),
+                        value => joined.push_str(&value.to_string()),
+                    }
+                }
+                Ok(Value::String(joined))
+            }
+            Expr::Upper(expr) => map_string(expr.evaluate(df, row_index)?, "Upper", |s| {
+                Value::String(s.to_uppercase())
+            }),
+            Expr::Lower(expr) => map_string(expr.evaluate(df, row_index)?, "Lower", |s| {
+                Value::String(s.to_lowercase())
+            }),
+            Expr::StrLength(expr) => map_string(expr.evaluate(df, row_index)?, "StrLength", |s| {
+                Value::I32(s.chars().count() as i32)
+            }),
+            Expr::Case {
+                branches,
+                otherwise,
+            } => {
+                for (condition, expr) in branches {
+                    if condition.evaluate(df, row_index)? {
+                        return expr.evaluate(df, row_index);
+                    }
+                }
+                otherwise.evaluate(df, row_index)
+            }
+            Expr::Cast(expr, data_type) => match expr.evaluate(df, row_index)? {
+                Value::Null => Ok(Value::Null),
+                value => {
+                    let source_type = value.data_type();
+                    let single = Series::from_values("", vec![Some(value)], source_type)?;
+                    Ok(single
+                        .cast(data_type.clone())?
+                        .get_value(0)
+                        .unwrap_or(Value::Null))
+                }
+            },
         }
     }
+
+    /// Returns the output type the expression always produces, independent of the data, or
+    /// `None` when it depends on the evaluated columns.
+    pub(crate) fn static_type(&self) -> Option<DataType> {
+        match self {
+            Expr::Concat(_) | Expr::Upper(_) | Expr::Lower(_) => Some(DataType::String),
+            Expr::StrLength(_) => Some(DataType::I32),
+            Expr::Cast(_, data_type) => Some(data_type.clone()),
+            Expr::Case {
+                branches,
+                otherwise,
+            } => {
+                let data_type = otherwise.static_type()?;
+                branches
+                    .iter()
+                    .all(|(_, expr)| expr.static_type().as_ref() == Some(&data_type))
+                    .then_some(data_type)
+            }
+            Expr::Equals(..)
+            | Expr::NotEquals(..)
+            | Expr::GreaterThan(..)
+            | Expr::LessThan(..)
+            | Expr::GreaterThanOrEqual(..)
+            | Expr::LessThanOrEqual(..)
+            | Expr::And(..)
+            | Expr::Or(..)
+            | Expr::Not(_)
+            | Expr::IsNull(_)
+            | Expr::IsNotNull(_) => Some(DataType::Bool),
+            _ => None,
+        }
+    }
+}
+
+/// Applies `f` to a string value, propagating nulls and rejecting any other type.
+fn map_string(
+    value: Value,
+    operation: &str,
+    f: impl Fn(&str) -> Value,
+) -> Result<Value, VeloxxError> {
+    match value {
+        Value::Null => Ok(Value::Null),
+        Value::String(s) => Ok(f(&s)),
+        other => Err(VeloxxError::DataTypeMismatch(format!(
+            "{} expects a String value, got {:?}",
+            operation,
+            other.data_type()
+        ))),
+    }
 }
@@ -129,6 +129,7 @@ pub fn write_parquet_from_dataframe(
         let series = dataframe.get_column(name).unwrap();
         let arrow_type = match series.data_type() {
             crate::types::DataType::I32 => ArrowDataType::Int32,
+            crate::types::DataType::I64 => ArrowDataType::Int64,
             crate::types::DataType::F64 => ArrowDataType::Float64,
             crate::types::DataType::Bool => ArrowDataType::Boolean,
             crate::types::DataType::String => ArrowDataType::Utf8,
@@ -97,6 +97,7 @@ impl LazyDataFrame {
         for (name, series) in &df.columns {
             let dtype = match series {
                 Series::I32(_, _, _) => "i32".to_string(),
+                Series::I64(_, _, _) => "i64".to_string(),
                 Series::F64(_, _, _) => "f64".to_string(),
                 Series::Bool(_, _, _) => "bool".to_string(),
                 Series::String(_, _, _) => "string".to_string(),
@@ -193,6 +194,7 @@ impl LazyDataFrame {
                 // Create constant series
                 match value {
                     Value::I32(v) => Ok(Series::new_i32("literal", vec![Some(*v); len])),
+                    Value::I64(v) => Ok(Series::new_i64("literal", vec![Some(*v); len])),
                     Value::F64(v) => Ok(Series::new_f64("literal", vec![Some(*v); len])),
                     Value::Bool(v) => Ok(Series::new_bool("literal", vec![Some(*v); len])),
                     Value::String(v) => {
@@ -17,6 +17,9 @@ impl MemoryAnalyzer {
             Series::I32(name, values, _) => {
                 name.len() + values.len() * std::mem::size_of::<Option<i32>>()
             }
+            Series::I64(name, values, _) => {
+                name.len() + values.len() * std::mem::size_of::<Option<i64>>()
+            }
             Series::F64(name, values, _) => {
                 name.len() + values.len() * std::mem::size_of::<Option<f64>>()
             }
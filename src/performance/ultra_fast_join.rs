@@ -222,6 +222,20 @@ impl UltraFastJoin {
 
                 Ok(Series::new_i32(&prefixed_name, result_values))
             }
+            Series::I64(_, values, _) => {
+                let mut result_values = Vec::with_capacity(result_pairs.len());
+
+                for &(left_idx, right_idx) in result_pairs {
+                    let idx = if use_left { left_idx } else { right_idx };
+                    if idx < values.len() {
+                        result_values.push(Some(values[idx]));
+                    } else {
+                        result_values.push(None);
+                    }
+                }
+
+                Ok(Series::new_i64(&prefixed_name, result_values))
+            }
             Series::F64(_, values, _) => {
                 let mut result_values = Vec::with_capacity(result_pairs.len());
 
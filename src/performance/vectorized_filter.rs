@@ -88,6 +88,34 @@ impl VectorizedFilter {
         Ok(mask)
     }
 
+    /// Create a bit mask for filtering based on comparison with a scalar value (i64)
+    pub fn create_comparison_mask_i64(
+        values: &[i64],
+        bitmap: &[bool],
+        comparison_value: i64,
+        op: ComparisonOp,
+    ) -> Result<BitPackedArray, VeloxxError> {
+        let mut mask = BitPackedArray::new(values.len());
+
+        for i in 0..values.len() {
+            let result = if bitmap[i] {
+                match op {
+                    ComparisonOp::Gt => values[i] > comparison_value,
+                    ComparisonOp::Gte => values[i] >= comparison_value,
+                    ComparisonOp::Lt => values[i] < comparison_value,
+                    ComparisonOp::Lte => values[i] <= comparison_value,
+                    ComparisonOp::Eq => values[i] == comparison_value,
+                    ComparisonOp::Ne => values[i] != comparison_value,
+                }
+            } else {
+                false
+            };
+            mask.push(result);
+        }
+
+        Ok(mask)
+    }
+
     /// Fast filtering of a Series using a pre-computed bit mask
     pub fn filter_series_with_mask(
         series: &Series,
@@ -135,6 +163,26 @@ impl VectorizedFilter {
 
                 Ok(Series::I32(name.clone(), filtered_values, filtered_bitmap))
             }
+            Series::I64(name, values, bitmap) => {
+                if values.len() != mask.len() {
+                    return Err(VeloxxError::InvalidOperation(
+                        "Series and mask must have same length".to_string(),
+                    ));
+                }
+
+                let estimated_size = mask.count_ones().min(values.len() / 2);
+                let mut filtered_values = Vec::with_capacity(estimated_size);
+                let mut filtered_bitmap = Vec::with_capacity(estimated_size);
+
+                for i in 0..values.len() {
+                    if mask.get(i).unwrap_or(false) {
+                        filtered_values.push(values[i]);
+                        filtered_bitmap.push(bitmap[i]);
+                    }
+                }
+
+                Ok(Series::I64(name.clone(), filtered_values, filtered_bitmap))
+            }
             Series::String(name, values, bitmap) => {
                 if values.len() != mask.len() {
                     return Err(VeloxxError::InvalidOperation(
@@ -219,6 +267,9 @@ impl VectorizedFilter {
             (Series::I32(_, values, bitmap), Value::I32(cmp_val)) => {
                 Self::create_comparison_mask_i32(values, bitmap, *cmp_val, op)
             }
+            (Series::I64(_, values, bitmap), Value::I64(cmp_val)) => {
+                Self::create_comparison_mask_i64(values, bitmap, *cmp_val, op)
+            }
             (Series::String(_, values, bitmap), Value::String(cmp_val)) => {
                 Self::create_comparison_mask_string(values, bitmap, cmp_val, op)
             }
@@ -306,6 +357,26 @@ mod tests {
         assert_eq!(mask.count_ones(), 2); // 4.0 and 5.0 are > 3.0
     }
 
+    #[test]
+    fn test_fast_filter_i64_column() {
+        let series = Series::I64(
+            "big".to_string(),
+            vec![1, 5_000_000_000, 3, 7_000_000_000],
+            vec![true, true, false, true],
+        );
+
+        let mask = VectorizedFilter::fast_filter_single_column(
+            &series,
+            &Value::I64(4_000_000_000),
+            ComparisonOp::Gt,
+        )
+        .unwrap();
+
+        assert_eq!(mask.get(1), Some(true));
+        assert_eq!(mask.get(2), Some(false)); // null never matches
+        assert_eq!(mask.count_ones(), 2);
+    }
+
     #[test]
     fn test_string_comparison_mask() {
         let values = vec![
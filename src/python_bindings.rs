@@ -33,6 +33,7 @@ use indexmap::IndexMap;
 #[derive(Clone)]
 pub enum PyDataType {
     I32,
+    I64,
     F64,
     String,
     Bool,
@@ -45,6 +46,7 @@ impl PyDataType {
     fn __str__(&self) -> String {
         match self {
             PyDataType::I32 => "I32".to_string(),
+            PyDataType::I64 => "I64".to_string(),
             PyDataType::F64 => "F64".to_string(),
             PyDataType::String => "String".to_string(),
             PyDataType::Bool => "Bool".to_string(),
@@ -53,6 +55,35 @@ impl PyDataType {
     }
 }
 
+#[cfg(feature = "python")]
+impl From<PyDataType> for crate::types::DataType {
+    fn from(data_type: PyDataType) -> Self {
+        match data_type {
+            PyDataType::I32 => crate::types::DataType::I32,
+            PyDataType::I64 => crate::types::DataType::I64,
+            PyDataType::F64 => crate::types::DataType::F64,
+            PyDataType::String => crate::types::DataType::String,
+            PyDataType::Bool => crate::types::DataType::Bool,
+            PyDataType::DateTime => crate::types::DataType::DateTime,
+        }
+    }
+}
+
+/// Converts a `Value` into the matching Python object, with `Null` as `None`.
+#[cfg(feature = "python")]
+#[allow(deprecated)]
+fn value_to_py(py: Python<'_>, value: Value) -> PyObject {
+    match value {
+        Value::I32(v) => v.into_py(py),
+        Value::I64(v) => v.into_py(py),
+        Value::F64(v) => v.into_py(py),
+        Value::String(v) => v.into_py(py),
+        Value::Bool(v) => v.into_py(py),
+        Value::DateTime(v) => v.into_py(py),
+        Value::Null => py.None(),
+    }
+}
+
 /// Python wrapper for join types
 #[cfg(feature = "python")]
 #[pyclass]
@@ -62,6 +93,8 @@ pub enum PyJoinType {
     Left,
     Right,
     Outer,
+    LeftSemi,
+    LeftAnti,
 }
 
 /// Python wrapper for conditions
@@ -297,6 +330,92 @@ impl PyExpr {
         }
     }
 
+    /// Instance method testing whether the expression is null
+    pub fn is_null(&self) -> Self {
+        PyExpr {
+            inner: crate::expressions::Expr::IsNull(Box::new(self.inner.clone())),
+        }
+    }
+
+    /// Instance method testing whether the expression is not null
+    pub fn is_not_null(&self) -> Self {
+        PyExpr {
+            inner: crate::expressions::Expr::IsNotNull(Box::new(self.inner.clone())),
+        }
+    }
+
+    /// Instance method replacing null results with `fallback`
+    pub fn if_null(&self, fallback: &PyExpr) -> Self {
+        PyExpr {
+            inner: crate::expressions::Expr::IfNull(
+                Box::new(self.inner.clone()),
+                Box::new(fallback.inner.clone()),
+            ),
+        }
+    }
+
+    /// Produces the first non-null result of `exprs`
+    #[staticmethod]
+    pub fn coalesce(exprs: Vec<PyExpr>) -> Self {
+        PyExpr {
+            inner: crate::expressions::Expr::Coalesce(
+                exprs.into_iter().map(|expr| expr.inner).collect(),
+            ),
+        }
+    }
+
+    /// Joins the string representations of `exprs`
+    #[staticmethod]
+    pub fn concat(exprs: Vec<PyExpr>) -> Self {
+        PyExpr {
+            inner: crate::expressions::Expr::Concat(
+                exprs.into_iter().map(|expr| expr.inner).collect(),
+            ),
+        }
+    }
+
+    /// Instance method converting a string expression to uppercase
+    pub fn upper(&self) -> Self {
+        PyExpr {
+            inner: crate::expressions::Expr::Upper(Box::new(self.inner.clone())),
+        }
+    }
+
+    /// Instance method converting a string expression to lowercase
+    pub fn lower(&self) -> Self {
+        PyExpr {
+            inner: crate::expressions::Expr::Lower(Box::new(self.inner.clone())),
+        }
+    }
+
+    /// Instance method counting the characters of a string expression
+    pub fn str_length(&self) -> Self {
+        PyExpr {
+            inner: crate::expressions::Expr::StrLength(Box::new(self.inner.clone())),
+        }
+    }
+
+    /// Produces the expression of the first branch whose condition holds, else `otherwise`
+    #[staticmethod]
+    pub fn case(branches: Vec<(PyCondition, PyExpr)>, otherwise: &PyExpr) -> Self {
+        PyExpr {
+            inner: crate::expressions::Expr::Case {
+                branches: branches
+                    .into_iter()
+                    .map(|(condition, expr)| (condition.inner, expr.inner))
+                    .collect(),
+                otherwise: Box::new(otherwise.inner.clone()),
+            },
+        }
+    }
+
+    /// Instance method converting the expression's value to `target_type`
+    pub fn cast(&self, target_type: PyDataType) -> Self {
+        PyExpr {
+            inner: crate::expressions::Expr::Cast(Box::new(self.inner.clone()), target_type.into()),
+        }
+    }
+
     /// Instance method for greater than comparison
     pub fn gt(&self, other: &PyExpr) -> Self {
         PyExpr {
@@ -425,13 +544,14 @@ impl PyValue {
 
     /// Get the string representation of the value
     pub fn __str__(&self) -> String {
-        format!("{:?}", self.inner)
+        self.inner.to_string()
     }
 
     /// Get the value type as string
     pub fn get_type(&self) -> String {
         match &self.inner {
             Value::I32(_) => "i32".to_string(),
+            Value::I64(_) => "i64".to_string(),
             Value::F64(_) => "f64".to_string(),
             Value::String(_) => "string".to_string(),
             Value::Bool(_) => "bool".to_string(),
@@ -717,6 +837,7 @@ impl PySeries {
     pub fn data_type(&self) -> String {
         match &self.inner {
             Series::I32(_, _, _) => "I32".to_string(),
+            Series::I64(_, _, _) => "I64".to_string(),
             Series::F64(_, _, _) => "F64".to_string(),
             Series::String(_, _, _) => "String".to_string(),
             Series::Bool(_, _, _) => "Bool".to_string(),
@@ -725,16 +846,10 @@ impl PySeries {
     }
 
     /// Get a value at the specified index
-    #[allow(deprecated)]
     pub fn get_value(&self, index: usize) -> PyResult<Option<PyObject>> {
         Python::with_gil(|py| match self.inner.get_value(index) {
-            Some(Value::I32(v)) => Ok(Some(v.into_py(py))),
-            Some(Value::F64(v)) => Ok(Some(v.into_py(py))),
-            Some(Value::String(v)) => Ok(Some(v.into_py(py))),
-            Some(Value::Bool(v)) => Ok(Some(v.into_py(py))),
-            Some(Value::DateTime(v)) => Ok(Some(v.into_py(py))),
-            Some(Value::Null) => Ok(None),
-            None => Ok(None),
+            Some(Value::Null) | None => Ok(None),
+            Some(value) => Ok(Some(value_to_py(py, value))),
         })
     }
 
@@ -748,6 +863,16 @@ impl PySeries {
         }
     }
 
+    /// Slice the series to `length` elements starting at `offset`, clamped to bounds
+    pub fn slice(&self, offset: usize, length: usize) -> PyResult<Self> {
+        match self.inner.slice(offset, length) {
+            Ok(sliced) => Ok(PySeries { inner: sliced }),
+            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                e.to_string(),
+            )),
+        }
+    }
+
     /// Count non-null values
     pub fn count(&self) -> usize {
         self.inner.count()
@@ -926,15 +1051,7 @@ impl PySeries {
 
     /// Cast to different data type
     pub fn cast(&self, target_type: PyDataType) -> PyResult<Self> {
-        let data_type = match target_type {
-            PyDataType::I32 => crate::types::DataType::I32,
-            PyDataType::F64 => crate::types::DataType::F64,
-            PyDataType::String => crate::types::DataType::String,
-            PyDataType::Bool => crate::types::DataType::Bool,
-            PyDataType::DateTime => crate::types::DataType::DateTime,
-        };
-
-        match self.inner.cast(data_type) {
+        match self.inner.cast(target_type.into()) {
             Ok(result) => Ok(PySeries { inner: result }),
             Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
                 e.to_string(),
@@ -1058,6 +1175,22 @@ impl PyDataFrame {
         }
     }
 
+    /// Get the value of a single cell, or None if it is null
+    pub fn get_value(&self, row: usize, col: &str) -> PyResult<Option<PyObject>> {
+        match self.inner.get_value(row, col) {
+            Ok(value) => Ok(value.map(|value| Python::with_gil(|py| value_to_py(py, value)))),
+            Err(crate::VeloxxError::ColumnNotFound(_)) => {
+                Err(PyErr::new::<pyo3::exceptions::PyKeyError, _>(format!(
+                    "Column '{}' not found",
+                    col
+                )))
+            }
+            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyIndexError, _>(
+                e.to_string(),
+            )),
+        }
+    }
+
     /// Filter DataFrame using high-performance vectorized operations
     pub fn filter_gt(&self, column: &str, value: PyObject) -> PyResult<Self> {
         Python::with_gil(|py| {
@@ -1090,7 +1223,7 @@ impl PyDataFrame {
         agg_fn: &str,
     ) -> PyResult<Self> {
         use crate::dataframe::Pivot;
-        match self.inner.pivot(values, index, columns, agg_fn) {
+        match self.inner.pivot(&index, columns, &[values], agg_fn) {
             Ok(result) => Ok(PyDataFrame { inner: result }),
             Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
                 e.to_string(),
@@ -1281,6 +1414,41 @@ impl PyDataFrame {
         Ok(PyDataFrame { inner: df })
     }
 
+    /// Convert to a list of dicts, one per row, with nulls as `None`
+    pub fn to_records(&self, py: Python<'_>) -> PyResult<Vec<PyObject>> {
+        self.inner
+            .to_records()
+            .into_iter()
+            .map(|record| {
+                let dict = PyDict::new(py);
+                for (name, value) in record {
+                    dict.set_item(name, value_to_py(py, value))?;
+                }
+                Ok(dict.into_any().unbind())
+            })
+            .collect()
+    }
+
+    /// Get the first `n` rows
+    pub fn head(&self, n: usize) -> PyResult<Self> {
+        match self.inner.head(n) {
+            Ok(result) => Ok(PyDataFrame { inner: result }),
+            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                e.to_string(),
+            )),
+        }
+    }
+
+    /// Get the last `n` rows
+    pub fn tail(&self, n: usize) -> PyResult<Self> {
+        match self.inner.tail(n) {
+            Ok(result) => Ok(PyDataFrame { inner: result }),
+            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                e.to_string(),
+            )),
+        }
+    }
+
     /// Add a computed column
     pub fn with_column(&self, name: &str, expr: &PyExpr) -> PyResult<Self> {
         match self.inner.with_column(name, &expr.inner) {
@@ -1336,6 +1504,8 @@ impl PyDataFrame {
             PyJoinType::Left => crate::dataframe::join::JoinType::Left,
             PyJoinType::Right => crate::dataframe::join::JoinType::Right,
             PyJoinType::Outer => crate::dataframe::join::JoinType::Outer,
+            PyJoinType::LeftSemi => crate::dataframe::join::JoinType::LeftSemi,
+            PyJoinType::LeftAnti => crate::dataframe::join::JoinType::LeftAnti,
         };
 
         match self.inner.join(&other.inner, on_column, jt) {
@@ -1,6 +1,6 @@
 use crate::dataframe::DataFrame;
 use crate::series::Series;
-use crate::types::Value;
+use crate::types::{DataType, Value};
 use indexmap::IndexMap;
 use std::cmp::Ordering;
 
@@ -164,6 +164,17 @@ impl UltraFastQueryEngine {
         mask: &mut [bool],
     ) -> Result<(), Box<dyn std::error::Error>> {
         match condition {
+            // Mixed numeric types fall back to the row-wise evaluator, which promotes them.
+            Condition::Eq(column, value)
+            | Condition::Gt(column, value)
+            | Condition::Lt(column, value)
+                if mixes_numeric_types(df, column, value) =>
+            {
+                for (i, keep) in mask.iter_mut().enumerate() {
+                    *keep = condition.evaluate(df, i)?;
+                }
+                Ok(())
+            }
             Condition::Eq(column, value) => {
                 self.evaluate_compare(df, column, &CompareOp::Equal, value, mask)
             }
@@ -201,6 +212,12 @@ impl UltraFastQueryEngine {
                 }
                 Ok(())
             }
+            Condition::In(..) | Condition::NotIn(..) => {
+                for (i, keep) in mask.iter_mut().enumerate() {
+                    *keep = condition.evaluate(df, i)?;
+                }
+                Ok(())
+            }
         }
     }
 
@@ -319,6 +336,19 @@ impl UltraFastQueryEngine {
 
                     Series::I32(name.clone(), filtered_data, filtered_validity)
                 }
+                Series::I64(name, data, validity) => {
+                    let mut filtered_data = Vec::new();
+                    let mut filtered_validity = Vec::new();
+
+                    for (i, &include) in mask.iter().enumerate() {
+                        if include {
+                            filtered_data.push(data[i]);
+                            filtered_validity.push(validity[i]);
+                        }
+                    }
+
+                    Series::I64(name.clone(), filtered_data, filtered_validity)
+                }
                 Series::F64(name, data, validity) => {
                     let mut filtered_data = Vec::new();
                     let mut filtered_validity = Vec::new();
@@ -408,6 +438,11 @@ impl UltraFastQueryEngine {
                         let val_b = if validity[b] { Some(data[b]) } else { None };
                         val_a.cmp(&val_b)
                     }
+                    Series::I64(_, data, validity) => {
+                        let val_a = if validity[a] { Some(data[a]) } else { None };
+                        let val_b = if validity[b] { Some(data[b]) } else { None };
+                        val_a.cmp(&val_b)
+                    }
                     Series::F64(_, data, validity) => {
                         let val_a = if validity[a] { Some(data[a]) } else { None };
                         let val_b = if validity[b] { Some(data[b]) } else { None };
@@ -455,6 +490,17 @@ impl UltraFastQueryEngine {
 
                     Series::I32(name, reordered_data, reordered_validity)
                 }
+                Series::I64(name, data, validity) => {
+                    let mut reordered_data = Vec::with_capacity(data.len());
+                    let mut reordered_validity = Vec::with_capacity(validity.len());
+
+                    for &idx in &indices {
+                        reordered_data.push(data[idx]);
+                        reordered_validity.push(validity[idx]);
+                    }
+
+                    Series::I64(name, reordered_data, reordered_validity)
+                }
                 Series::F64(name, data, validity) => {
                     let mut reordered_data = Vec::with_capacity(data.len());
                     let mut reordered_validity = Vec::with_capacity(validity.len());
@@ -527,6 +573,11 @@ impl UltraFastQueryEngine {
                     let limited_validity = validity.into_iter().take(limit).collect();
                     Series::I32(name, limited_data, limited_validity)
                 }
+                Series::I64(name, data, validity) => {
+                    let limited_data = data.into_iter().take(limit).collect();
+                    let limited_validity = validity.into_iter().take(limit).collect();
+                    Series::I64(name, limited_data, limited_validity)
+                }
                 Series::F64(name, data, validity) => {
                     let limited_data = data.into_iter().take(limit).collect();
                     let limited_validity = validity.into_iter().take(limit).collect();
@@ -609,6 +660,11 @@ impl UltraFastQueryEngine {
                             .zip(mask.iter())
                             .filter(|(&valid, &include)| valid && include)
                             .count(),
+                        Series::I64(_, _, validity) => validity
+                            .iter()
+                            .zip(mask.iter())
+                            .filter(|(&valid, &include)| valid && include)
+                            .count(),
                         Series::F64(_, _, validity) => validity
                             .iter()
                             .zip(mask.iter())
@@ -807,3 +863,15 @@ impl UltraFastQueryEngine {
         })
     }
 }
+
+/// Whether `value` is numeric but of a different type than the numeric column `column`.
+fn mixes_numeric_types(df: &DataFrame, column: &str, value: &Value) -> bool {
+    let is_numeric =
+        |data_type: DataType| matches!(data_type, DataType::I32 | DataType::I64 | DataType::F64);
+    match (df.get_column(column), value) {
+        (Some(series), Value::I32(_) | Value::I64(_) | Value::F64(_)) => {
+            is_numeric(series.data_type()) && series.data_type() != value.data_type()
+        }
+        _ => false,
+    }
+}
@@ -8,12 +8,24 @@ impl Series {
     pub fn sum(&self) -> Result<Value, VeloxxError> {
         match self {
             Series::I32(_, values, bitmap) => {
-                let sum: i32 = values
+                let sum: i64 = values
                     .par_iter()
                     .zip(bitmap.par_iter())
-                    .filter_map(|(&v, &b)| if b { Some(v) } else { None })
+                    .filter_map(|(&v, &b)| if b { Some(v as i64) } else { None })
+                    .sum();
+                i32::try_from(sum).map(Value::I32).map_err(|_| {
+                    VeloxxError::InvalidOperation("Sum overflows the I32 range".to_string())
+                })
+            }
+            Series::I64(_, values, bitmap) => {
+                let sum: i128 = values
+                    .par_iter()
+                    .zip(bitmap.par_iter())
+                    .filter_map(|(&v, &b)| if b { Some(v as i128) } else { None })
                     .sum();
-                Ok(Value::I32(sum))
+                i64::try_from(sum).map(Value::I64).map_err(|_| {
+                    VeloxxError::InvalidOperation("Sum overflows the I64 range".to_string())
+                })
             }
             Series::F64(_, values, bitmap) => {
                 let sum: f64 = values
@@ -45,18 +57,30 @@ impl Series {
                     )),
                 }
             }
+            Series::I64(_, values, bitmap) => {
+                let min = values
+                    .par_iter()
+                    .zip(bitmap.par_iter())
+                    .filter_map(|(&v, &b)| if b { Some(v) } else { None })
+                    .min();
+                match min {
+                    Some(val) => Ok(Value::I64(val)),
+                    None => Err(VeloxxError::InvalidOperation(
+                        "No valid values in series".to_string(),
+                    )),
+                }
+            }
             Series::F64(_, values, bitmap) => {
                 let min = values
                     .par_iter()
                     .zip(bitmap.par_iter())
                     .filter_map(|(&v, &b)| if b { Some(v) } else { None })
-                    .reduce(|| f64::INFINITY, f64::min);
-                if min == f64::INFINITY {
-                    Err(VeloxxError::InvalidOperation(
+                    .reduce_with(f64::min);
+                match min {
+                    Some(val) => Ok(Value::F64(val)),
+                    None => Err(VeloxxError::InvalidOperation(
                         "No valid values in series".to_string(),
-                    ))
-                } else {
-                    Ok(Value::F64(min))
+                    )),
                 }
             }
             Series::String(_, values, bitmap) => {
@@ -94,18 +118,30 @@ impl Series {
                     )),
                 }
             }
+            Series::I64(_, values, bitmap) => {
+                let max = values
+                    .par_iter()
+                    .zip(bitmap.par_iter())
+                    .filter_map(|(&v, &b)| if b { Some(v) } else { None })
+                    .max();
+                match max {
+                    Some(val) => Ok(Value::I64(val)),
+                    None => Err(VeloxxError::InvalidOperation(
+                        "No valid values in series".to_string(),
+                    )),
+                }
+            }
             Series::F64(_, values, bitmap) => {
                 let max = values
                     .par_iter()
                     .zip(bitmap.par_iter())
                     .filter_map(|(&v, &b)| if b { Some(v) } else { None })
-                    .reduce(|| f64::NEG_INFINITY, f64::max);
-                if max == f64::NEG_INFINITY {
-                    Err(VeloxxError::InvalidOperation(
+                    .reduce_with(f64::max);
+                match max {
+                    Some(val) => Ok(Value::F64(val)),
+                    None => Err(VeloxxError::InvalidOperation(
                         "No valid values in series".to_string(),
-                    ))
-                } else {
-                    Ok(Value::F64(max))
+                    )),
                 }
             }
             Series::String(_, values, bitmap) => {
@@ -144,6 +180,20 @@ impl Series {
                 let sum: i32 = valid_values.iter().sum();
                 Ok(Value::F64(sum as f64 / valid_values.len() as f64))
             }
+            Series::I64(_, values, bitmap) => {
+                let valid_values: Vec<i64> = values
+                    .par_iter()
+                    .zip(bitmap.par_iter())
+                    .filter_map(|(&v, &b)| if b { Some(v) } else { None })
+                    .collect();
+                if valid_values.is_empty() {
+                    return Err(VeloxxError::InvalidOperation(
+                        "No valid values in series".to_string(),
+                    ));
+                }
+                let sum: i128 = valid_values.iter().map(|&v| v as i128).sum();
+                Ok(Value::F64(sum as f64 / valid_values.len() as f64))
+            }
             Series::F64(_, values, bitmap) => {
                 let valid_values: Vec<f64> = values
                     .par_iter()
@@ -196,6 +246,34 @@ impl Series {
                 };
                 Ok(Value::F64(variance.sqrt()))
             }
+            Series::I64(_, values, bitmap) => {
+                let valid_values: Vec<f64> = values
+                    .par_iter()
+                    .zip(bitmap.par_iter())
+                    .filter_map(|(&v, &b)| if b { Some(v as f64) } else { None })
+                    .collect();
+                if valid_values.is_empty() {
+                    return Err(VeloxxError::InvalidOperation(
+                        "No valid values in series".to_string(),
+                    ));
+                }
+                let mean: f64 = valid_values.iter().sum::<f64>() / valid_values.len() as f64;
+                let variance = if valid_values.len() > 1 {
+                    valid_values
+                        .iter()
+                        .map(|&x| {
+                            let diff = x - mean;
+                            diff * diff
+                        })
+                        .sum::<f64>()
+                        / (valid_values.len() - 1) as f64
+                } else {
+                    return Err(VeloxxError::InvalidOperation(
+                        "Standard deviation requires at least 2 values".to_string(),
+                    ));
+                };
+                Ok(Value::F64(variance.sqrt()))
+            }
             Series::F64(_, values, bitmap) => {
                 let valid_values: Vec<f64> = values
                     .par_iter()
@@ -230,41 +308,82 @@ impl Series {
         }
     }
 
+    /// Calculate the sample variance (with `n - 1` degrees of freedom) of all values in the series
+    pub fn variance(&self) -> Result<Value, VeloxxError> {
+        let valid_values: Vec<f64> = match self {
+            Series::I32(_, values, bitmap) => values
+                .iter()
+                .zip(bitmap)
+                .filter_map(|(&v, &b)| b.then_some(v as f64))
+                .collect(),
+            Series::I64(_, values, bitmap) => values
+                .iter()
+                .zip(bitmap)
+                .filter_map(|(&v, &b)| b.then_some(v as f64))
+                .collect(),
+            Series::F64(_, values, bitmap) => values
+                .iter()
+                .zip(bitmap)
+                .filter_map(|(&v, &b)| b.then_some(v))
+                .collect(),
+            _ => {
+                return Err(VeloxxError::InvalidOperation(
+                    "Variance operation not supported for this data type".to_string(),
+                ))
+            }
+        };
+        if valid_values.len() < 2 {
+            return Err(VeloxxError::InvalidOperation(
+                "Variance requires at least 2 values".to_string(),
+            ));
+        }
+        let mean = valid_values.iter().sum::<f64>() / valid_values.len() as f64;
+        let sum_sq: f64 = valid_values.iter().map(|&x| (x - mean) * (x - mean)).sum();
+        Ok(Value::F64(sum_sq / (valid_values.len() - 1) as f64))
+    }
+
     /// Calculate the median of all values in the series
     pub fn median(&self) -> Result<Value, VeloxxError> {
         match self {
             Series::I32(_, values, bitmap) => {
-                let mut valid_values: Vec<i32> = values
-                    .par_iter()
-                    .zip(bitmap.par_iter())
-                    .filter_map(|(&v, &b)| if b { Some(v) } else { None })
-                    .collect();
+                let valid_values = super::sorted_valid(values, bitmap, |a, b| a.cmp(b));
                 if valid_values.is_empty() {
                     return Err(VeloxxError::InvalidOperation(
                         "No valid values in series".to_string(),
                     ));
                 }
-                valid_values.sort_unstable();
                 let len = valid_values.len();
                 let median = if len.is_multiple_of(2) {
-                    (valid_values[len / 2 - 1] + valid_values[len / 2]) as f64 / 2.0
+                    (valid_values[len / 2 - 1] as f64 + valid_values[len / 2] as f64) / 2.0
+                } else {
+                    valid_values[len / 2] as f64
+                };
+                Ok(Value::F64(median))
+            }
+            Series::I64(_, values, bitmap) => {
+                let valid_values = super::sorted_valid(values, bitmap, |a, b| a.cmp(b));
+                if valid_values.is_empty() {
+                    return Err(VeloxxError::InvalidOperation(
+                        "No valid values in series".to_string(),
+                    ));
+                }
+                let len = valid_values.len();
+                let median = if len.is_multiple_of(2) {
+                    (valid_values[len / 2 - 1] as f64 + valid_values[len / 2] as f64) / 2.0
                 } else {
                     valid_values[len / 2] as f64
                 };
                 Ok(Value::F64(median))
             }
             Series::F64(_, values, bitmap) => {
-                let mut valid_values: Vec<f64> = values
-                    .par_iter()
-                    .zip(bitmap.par_iter())
-                    .filter_map(|(&v, &b)| if b { Some(v) } else { None })
-                    .collect();
+                let valid_values = super::sorted_valid(values, bitmap, |a, b| {
+                    a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal)
+                });
                 if valid_values.is_empty() {
                     return Err(VeloxxError::InvalidOperation(
                         "No valid values in series".to_string(),
                     ));
                 }
-                valid_values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
                 let len = valid_values.len();
                 let median = if len.is_multiple_of(2) {
                     (valid_values[len / 2 - 1] + valid_values[len / 2]) / 2.0
@@ -303,6 +422,27 @@ impl Series {
 
                 Ok(Series::I32(name.clone(), unique_values, unique_bitmap))
             }
+            Series::I64(name, values, bitmap) => {
+                use std::collections::HashSet;
+                let mut unique_values = Vec::new();
+                let mut unique_bitmap = Vec::new();
+                let mut seen = HashSet::new();
+                let mut has_null = false;
+
+                for (&val, &valid) in values.iter().zip(bitmap.iter()) {
+                    if valid && seen.insert(val) {
+                        unique_values.push(val);
+                        unique_bitmap.push(true);
+                    } else if !valid && !has_null {
+                        // Include one null value if it exists
+                        has_null = true;
+                        unique_values.push(0); // placeholder for null
+                        unique_bitmap.push(false);
+                    }
+                }
+
+                Ok(Series::I64(name.clone(), unique_values, unique_bitmap))
+            }
             Series::F64(name, values, bitmap) => {
                 use std::collections::HashSet;
                 let mut unique_values = Vec::new();
@@ -353,4 +493,36 @@ impl Series {
             )),
         }
     }
+
+    /// Returns every value that shares the highest frequency, in order of first appearance.
+    ///
+    /// Nulls are ignored. An empty or all-null series yields an empty series of the same type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::series::Series;
+    /// use veloxx::types::Value;
+    ///
+    /// let series = Series::new_i32("x", vec![Some(3), Some(1), None, Some(1), Some(3), Some(2)]);
+    /// let modes = series.modes().unwrap();
+    /// assert_eq!(modes.len(), 2);
+    /// assert_eq!(modes.get_value(0), Some(Value::I32(3)));
+    /// assert_eq!(modes.get_value(1), Some(Value::I32(1)));
+    /// ```
+    pub fn modes(&self) -> Result<Series, VeloxxError> {
+        let mut counts: indexmap::IndexMap<Value, usize> = indexmap::IndexMap::new();
+        for i in 0..self.len() {
+            if let Some(value) = self.get_value(i) {
+                *counts.entry(value).or_insert(0) += 1;
+            }
+        }
+        let max_count = counts.values().copied().max().unwrap_or(0);
+        let modes: Vec<Option<Value>> = counts
+            .into_iter()
+            .filter(|&(_, count)| count == max_count)
+            .map(|(value, _)| Some(value))
+            .collect();
+        Series::from_values(self.name(), modes, self.data_type())
+    }
 }
@@ -2,6 +2,18 @@ use crate::series::Series;
 use crate::types::Value;
 use crate::VeloxxError;
 
+/// How [`Series::round_with_mode`] resolves values lying exactly halfway between two candidates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RoundingMode {
+    /// Ties round away from zero (2.5 -> 3, -2.5 -> -3). This is the default used by
+    /// [`Series::round`] and matches `f64::round`.
+    #[default]
+    HalfAwayFromZero,
+    /// Ties round to the nearest even digit (2.5 -> 2, 3.5 -> 4), also known as banker's
+    /// rounding. Unlike half-up rounding it does not bias sums of rounded values upwards.
+    HalfEven,
+}
+
 impl Series {
     /// Filter the series to only include values at the specified indices
     pub fn filter_by_indices(&self, indices: &[usize]) -> Result<Series, VeloxxError> {
@@ -23,6 +35,23 @@ impl Series {
 
                 Ok(Series::I32(name.clone(), new_values, new_bitmap))
             }
+            Series::I64(name, values, bitmap) => {
+                let mut new_values = Vec::with_capacity(indices.len());
+                let mut new_bitmap = Vec::with_capacity(indices.len());
+
+                for &idx in indices {
+                    if idx < values.len() {
+                        new_values.push(values[idx]);
+                        new_bitmap.push(bitmap[idx]);
+                    } else {
+                        return Err(VeloxxError::InvalidOperation(
+                            "Index out of bounds".to_string(),
+                        ));
+                    }
+                }
+
+                Ok(Series::I64(name.clone(), new_values, new_bitmap))
+            }
             Series::F64(name, values, bitmap) => {
                 let mut new_values = Vec::with_capacity(indices.len());
                 let mut new_bitmap = Vec::with_capacity(indices.len());
@@ -102,6 +131,11 @@ impl Series {
                 .zip(bitmap.iter())
                 .filter_map(|(&v, &b)| if b { Some(v as f64) } else { None })
                 .collect()),
+            Series::I64(_, values, bitmap) => Ok(values
+                .iter()
+                .zip(bitmap.iter())
+                .filter_map(|(&v, &b)| if b { Some(v as f64) } else { None })
+                .collect()),
             Series::F64(_, values, bitmap) => Ok(values
                 .iter()
                 .zip(bitmap.iter())
@@ -117,6 +151,7 @@ impl Series {
     pub fn set_name(&mut self, new_name: &str) {
         match self {
             Series::I32(ref mut name, _, _) => *name = new_name.to_string(),
+            Series::I64(ref mut name, _, _) => *name = new_name.to_string(),
             Series::F64(ref mut name, _, _) => *name = new_name.to_string(),
             Series::Bool(ref mut name, _, _) => *name = new_name.to_string(),
             Series::String(ref mut name, _, _) => *name = new_name.to_string(),
@@ -124,10 +159,40 @@ impl Series {
         }
     }
 
+    /// Release unused capacity in the series' backing storage.
+    /// Returns the number of bytes reclaimed.
+    pub fn shrink_to_fit(&mut self) -> usize {
+        fn shrink<T>(values: &mut Vec<T>) -> usize {
+            let before = values.capacity();
+            values.shrink_to_fit();
+            (before - values.capacity()) * std::mem::size_of::<T>()
+        }
+
+        match self {
+            Series::I32(_, values, bitmap) => shrink(values) + shrink(bitmap),
+            Series::I64(_, values, bitmap) => shrink(values) + shrink(bitmap),
+            Series::F64(_, values, bitmap) => shrink(values) + shrink(bitmap),
+            Series::Bool(_, values, bitmap) => shrink(values) + shrink(bitmap),
+            Series::String(_, values, bitmap) => {
+                let strings: usize = values
+                    .iter_mut()
+                    .map(|s| {
+                        let before = s.capacity();
+                        s.shrink_to_fit();
+                        before - s.capacity()
+                    })
+                    .sum();
+                strings + shrink(values) + shrink(bitmap)
+            }
+            Series::DateTime(_, values, bitmap) => shrink(values) + shrink(bitmap),
+        }
+    }
+
     /// Count the number of valid (non-null) values in the series
     pub fn count(&self) -> usize {
         match self {
             Series::I32(_, _, bitmap) => bitmap.iter().filter(|&&b| b).count(),
+            Series::I64(_, _, bitmap) => bitmap.iter().filter(|&&b| b).count(),
             Series::F64(_, _, bitmap) => bitmap.iter().filter(|&&b| b).count(),
             Series::Bool(_, _, bitmap) => bitmap.iter().filter(|&&b| b).count(),
             Series::String(_, _, bitmap) => bitmap.iter().filter(|&&b| b).count(),
@@ -152,6 +217,18 @@ impl Series {
 
                 Ok(Series::I32(name, new_values, new_bitmap))
             }
+            (Series::I64(_, values, bitmap), Value::I64(fill_value)) => {
+                let mut new_values = values.clone();
+                let new_bitmap = vec![true; values.len()];
+
+                for (i, &is_valid) in bitmap.iter().enumerate() {
+                    if !is_valid {
+                        new_values[i] = *fill_value;
+                    }
+                }
+
+                Ok(Series::I64(name, new_values, new_bitmap))
+            }
             (Series::F64(_, values, bitmap), Value::F64(fill_value)) => {
                 let mut new_values = values.clone();
                 let new_bitmap = vec![true; values.len()];
@@ -194,6 +271,15 @@ impl Series {
         }
     }
 
+    /// Adds two series element-wise.
+    ///
+    /// I32 with I32 yields I32; any mix with F64 yields F64. A null in either operand yields
+    /// null, as does I32 overflow. The result is named `"{left}_add_{right}"`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `VeloxxError::InvalidOperation` if the lengths differ and
+    /// `VeloxxError::Unsupported` for non-numeric series.
     pub fn add(&self, other: &Series) -> Result<Series, VeloxxError> {
         if self.len() != other.len() {
             return Err(VeloxxError::InvalidOperation(
@@ -213,11 +299,11 @@ impl Series {
                     let v2 = values2[i];
                     let b2 = bitmap2[i];
 
-                    if b1 && b2 {
-                        result_values.push(v1 + v2);
+                    if let (true, true, Some(v)) = (b1, b2, v1.checked_add(v2)) {
+                        result_values.push(v);
                         result_bitmap.push(true);
                     } else {
-                        result_values.push(0); // Placeholder for null
+                        result_values.push(0); // Placeholder for null or overflow
                         result_bitmap.push(false);
                     }
                 }
@@ -286,6 +372,10 @@ impl Series {
         }
     }
 
+    /// Subtracts `other` from this series element-wise.
+    ///
+    /// Type promotion, null handling and errors follow [`Series::add`]. The result is named
+    /// `"{left}_sub_{right}"`.
     pub fn sub(&self, other: &Series) -> Result<Series, VeloxxError> {
         if self.len() != other.len() {
             return Err(VeloxxError::InvalidOperation(
@@ -305,11 +395,11 @@ impl Series {
                     let v2 = values2[i];
                     let b2 = bitmap2[i];
 
-                    if b1 && b2 {
-                        result_values.push(v1 - v2);
+                    if let (true, true, Some(v)) = (b1, b2, v1.checked_sub(v2)) {
+                        result_values.push(v);
                         result_bitmap.push(true);
                     } else {
-                        result_values.push(0); // Placeholder for null
+                        result_values.push(0); // Placeholder for null or overflow
                         result_bitmap.push(false);
                     }
                 }
@@ -378,6 +468,10 @@ impl Series {
         }
     }
 
+    /// Multiplies two series element-wise.
+    ///
+    /// Type promotion, null handling and errors follow [`Series::add`]. The result is named
+    /// `"{left}_mul_{right}"`.
     pub fn mul(&self, other: &Series) -> Result<Series, VeloxxError> {
         if self.len() != other.len() {
             return Err(VeloxxError::InvalidOperation(
@@ -397,11 +491,11 @@ impl Series {
                     let v2 = values2[i];
                     let b2 = bitmap2[i];
 
-                    if b1 && b2 {
-                        result_values.push(v1 * v2);
+                    if let (true, true, Some(v)) = (b1, b2, v1.checked_mul(v2)) {
+                        result_values.push(v);
                         result_bitmap.push(true);
                     } else {
-                        result_values.push(0); // Placeholder for null
+                        result_values.push(0); // Placeholder for null or overflow
                         result_bitmap.push(false);
                     }
                 }
@@ -470,6 +564,11 @@ impl Series {
         }
     }
 
+    /// Divides this series by `other` element-wise.
+    ///
+    /// Type promotion, null handling and errors follow [`Series::add`]; I32 division truncates
+    /// toward zero. Division by zero yields null for both I32 and F64 rather than `inf` or
+    /// `NaN`. The result is named `"{left}_div_{right}"`.
     pub fn div(&self, other: &Series) -> Result<Series, VeloxxError> {
         if self.len() != other.len() {
             return Err(VeloxxError::InvalidOperation(
@@ -490,12 +589,13 @@ impl Series {
                     let b2 = bitmap2[i];
 
                     if b1 && b2 {
-                        if v2 == 0 {
-                            result_values.push(0); // Divide by zero results in null
-                            result_bitmap.push(false);
-                        } else {
-                            result_values.push(v1 / v2);
+                        // Divide by zero (or i32::MIN / -1) results in null
+                        if let Some(v) = v1.checked_div(v2) {
+                            result_values.push(v);
                             result_bitmap.push(true);
+                        } else {
+                            result_values.push(0);
+                            result_bitmap.push(false);
                         }
                     } else {
                         result_values.push(0); // Placeholder for null
@@ -599,6 +699,14 @@ impl Series {
                     .collect();
                 Ok(Series::F64(name, result_values, bitmap.clone()))
             }
+            Series::I64(_, values, bitmap) => {
+                let result_values: Vec<f64> = values
+                    .iter()
+                    .zip(bitmap.iter())
+                    .map(|(&v, &b)| if b { (v as f64).powf(exponent) } else { 0.0 })
+                    .collect();
+                Ok(Series::F64(name, result_values, bitmap.clone()))
+            }
             Series::F64(_, values, bitmap) => {
                 let result_values: Vec<f64> = values
                     .iter()
@@ -628,6 +736,14 @@ impl Series {
                     .collect();
                 Ok(Series::F64(name, result_values, bitmap.clone()))
             }
+            Series::I64(_, values, bitmap) => {
+                let result_values: Vec<f64> = values
+                    .iter()
+                    .zip(bitmap.iter())
+                    .map(|(&v, &b)| if b { (v as f64).sqrt() } else { 0.0 })
+                    .collect();
+                Ok(Series::F64(name, result_values, bitmap.clone()))
+            }
             Series::F64(_, values, bitmap) => {
                 let result_values: Vec<f64> = values
                     .iter()
@@ -657,6 +773,14 @@ impl Series {
                     .collect();
                 Ok(Series::I32(name, result_values, bitmap.clone()))
             }
+            Series::I64(_, values, bitmap) => {
+                let result_values: Vec<i64> = values
+                    .iter()
+                    .zip(bitmap.iter())
+                    .map(|(&v, &b)| if b { v.abs() } else { 0 })
+                    .collect();
+                Ok(Series::I64(name, result_values, bitmap.clone()))
+            }
             Series::F64(_, values, bitmap) => {
                 let result_values: Vec<f64> = values
                     .iter()
@@ -668,4 +792,202 @@ impl Series {
             _ => unreachable!(), // Handled by is_numeric check
         }
     }
+
+    /// Limits every value to the range `[min, max]`; a `None` bound leaves that side open.
+    ///
+    /// The series keeps its name and type and nulls stay null. Integer series clip to the
+    /// integers inside the bounds, so `min = 0.5` raises values below 1 to 1.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the clipped series, `VeloxxError::InvalidOperation` if a bound is
+    /// NaN, `min > max`, or no integer lies between the bounds of an integer series, or
+    /// `VeloxxError::Unsupported` for non-numeric series.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::series::Series;
+    /// use veloxx::types::Value;
+    ///
+    /// let scores = Series::new_i32("score", vec![Some(-3), Some(7), None, Some(120)]);
+    /// let clipped = scores.clip(Some(0.0), Some(100.0)).unwrap();
+    /// assert_eq!(clipped.get_value(0), Some(Value::I32(0)));
+    /// assert_eq!(clipped.get_value(1), Some(Value::I32(7)));
+    /// assert_eq!(clipped.get_value(2), None);
+    /// assert_eq!(clipped.get_value(3), Some(Value::I32(100)));
+    /// ```
+    pub fn clip(&self, min: Option<f64>, max: Option<f64>) -> Result<Series, VeloxxError> {
+        let lower = min.unwrap_or(f64::NEG_INFINITY);
+        let upper = max.unwrap_or(f64::INFINITY);
+        if lower.is_nan() || upper.is_nan() || lower > upper {
+            return Err(VeloxxError::InvalidOperation(format!(
+                "Invalid clip bounds: min {min:?}, max {max:?}"
+            )));
+        }
+        // Float-to-int `as` casts saturate, so infinite bounds become the type's limits.
+        let no_integer_between = || {
+            VeloxxError::InvalidOperation(format!(
+                "No integer lies between clip bounds {lower} and {upper}"
+            ))
+        };
+        match self {
+            Series::I32(name, values, bitmap) => {
+                let (lo, hi) = (lower.ceil() as i32, upper.floor() as i32);
+                if lo > hi {
+                    return Err(no_integer_between());
+                }
+                let clipped = values.iter().map(|&v| v.clamp(lo, hi)).collect();
+                Ok(Series::I32(name.clone(), clipped, bitmap.clone()))
+            }
+            Series::I64(name, values, bitmap) => {
+                let (lo, hi) = (lower.ceil() as i64, upper.floor() as i64);
+                if lo > hi {
+                    return Err(no_integer_between());
+                }
+                let clipped = values.iter().map(|&v| v.clamp(lo, hi)).collect();
+                Ok(Series::I64(name.clone(), clipped, bitmap.clone()))
+            }
+            Series::F64(name, values, bitmap) => {
+                let clipped = values.iter().map(|&v| v.clamp(lower, upper)).collect();
+                Ok(Series::F64(name.clone(), clipped, bitmap.clone()))
+            }
+            _ => Err(VeloxxError::Unsupported(
+                "Clip operation only supported for numeric series".to_string(),
+            )),
+        }
+    }
+
+    /// Standardizes the series to z-scores, `(x - mean) / std_dev`, using its own mean and
+    /// sample standard deviation.
+    ///
+    /// The result is an F64 series with the same name, and nulls stay null. Use
+    /// [`Series::zscore_with`] to apply statistics fitted on other data.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the standardized series, `VeloxxError::InvalidOperation` if the
+    /// series has fewer than two values or zero variance, or `VeloxxError::Unsupported` for
+    /// non-numeric series.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::series::Series;
+    /// use veloxx::types::Value;
+    ///
+    /// let heights = Series::new_i32("height", vec![Some(160), None, Some(170), Some(180)]);
+    /// let z = heights.zscore().unwrap();
+    /// assert_eq!(z.get_value(0), Some(Value::F64(-1.0)));
+    /// assert_eq!(z.get_value(1), None);
+    /// assert_eq!(z.get_value(2), Some(Value::F64(0.0)));
+    /// ```
+    pub fn zscore(&self) -> Result<Series, VeloxxError> {
+        let (mean, std_dev) = self.zscore_params()?;
+        self.zscore_with(mean, std_dev)
+    }
+
+    /// Standardizes the series with a given `mean` and `std_dev`, `(x - mean) / std_dev`.
+    ///
+    /// This applies statistics fitted elsewhere, e.g. on a training set, so that other data
+    /// is scaled the same way. The result is an F64 series with the same name, and nulls stay
+    /// null.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the standardized series, `VeloxxError::InvalidOperation` if
+    /// `std_dev` is zero or either statistic is not finite, or `VeloxxError::Unsupported` for
+    /// non-numeric series.
+    pub fn zscore_with(&self, mean: f64, std_dev: f64) -> Result<Series, VeloxxError> {
+        if !self.is_numeric() {
+            return Err(VeloxxError::Unsupported(
+                "Z-scores are only supported for numeric series".to_string(),
+            ));
+        }
+        if std_dev == 0.0 || !std_dev.is_finite() || !mean.is_finite() {
+            return Err(VeloxxError::InvalidOperation(format!(
+                "Cannot standardize '{}' with mean {} and standard deviation {}",
+                self.name(),
+                mean,
+                std_dev
+            )));
+        }
+        let scaled = (0..self.len())
+            .map(|i| self.get_numeric_f64(i).map(|v| (v - mean) / std_dev))
+            .collect();
+        Ok(Series::new_f64(self.name(), scaled))
+    }
+
+    /// The mean and sample standard deviation [`Series::zscore`] scales by.
+    pub(crate) fn zscore_params(&self) -> Result<(f64, f64), VeloxxError> {
+        if !self.is_numeric() {
+            return Err(VeloxxError::Unsupported(
+                "Z-scores are only supported for numeric series".to_string(),
+            ));
+        }
+        let mean = self.mean()?.as_f64().unwrap_or(f64::NAN);
+        let std_dev = self.std_dev()?.as_f64().unwrap_or(f64::NAN);
+        if std_dev == 0.0 {
+            return Err(VeloxxError::InvalidOperation(format!(
+                "Cannot standardize '{}' because it has zero variance",
+                self.name()
+            )));
+        }
+        Ok((mean, std_dev))
+    }
+
+    /// Rounds every value to `decimals` decimal places, resolving ties away from zero.
+    ///
+    /// Equivalent to `round_with_mode(decimals, RoundingMode::HalfAwayFromZero)`.
+    pub fn round(&self, decimals: u32) -> Result<Series, VeloxxError> {
+        self.round_with_mode(decimals, RoundingMode::default())
+    }
+
+    /// Rounds every value to `decimals` decimal places using banker's rounding, so that
+    /// 2.5 rounds to 2 and 3.5 rounds to 4.
+    ///
+    /// Equivalent to `round_with_mode(decimals, RoundingMode::HalfEven)`.
+    pub fn round_half_even(&self, decimals: u32) -> Result<Series, VeloxxError> {
+        self.round_with_mode(decimals, RoundingMode::HalfEven)
+    }
+
+    /// Rounds every value to `decimals` decimal places, resolving ties according to `mode`.
+    ///
+    /// `I32` and `I64` series are returned unchanged since they have no fractional part. Nulls
+    /// stay null and the series keeps its name.
+    ///
+    /// # Errors
+    ///
+    /// Returns `VeloxxError::Unsupported` for non-numeric series.
+    pub fn round_with_mode(
+        &self,
+        decimals: u32,
+        mode: RoundingMode,
+    ) -> Result<Series, VeloxxError> {
+        match self {
+            Series::I32(..) | Series::I64(..) => Ok(self.clone()),
+            Series::F64(name, values, bitmap) => {
+                let scale = 10f64.powi(decimals as i32);
+                let round_scaled = |v: f64| match mode {
+                    RoundingMode::HalfAwayFromZero => v.round(),
+                    RoundingMode::HalfEven => v.round_ties_even(),
+                };
+                let result_values: Vec<f64> = values
+                    .iter()
+                    .zip(bitmap.iter())
+                    .map(|(&v, &b)| {
+                        if b {
+                            round_scaled(v * scale) / scale
+                        } else {
+                            0.0
+                        }
+                    })
+                    .collect();
+                Ok(Series::F64(name.clone(), result_values, bitmap.clone()))
+            }
+            _ => Err(VeloxxError::Unsupported(
+                "Round operation only supported for numeric series".to_string(),
+            )),
+        }
+    }
 }
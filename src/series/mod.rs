@@ -4,7 +4,8 @@ use crate::VeloxxError;
 // Arrow imports only when the `arrow` feature is enabled and not targeting WASM
 #[cfg(all(feature = "arrow", not(target_arch = "wasm32")))]
 use arrow::array::{
-    ArrayRef, BooleanArray, Float64Array, Int32Array, StringArray, TimestampNanosecondArray,
+    ArrayRef, BooleanArray, Float64Array, Int32Array, Int64Array, StringArray,
+    TimestampNanosecondArray,
 };
 #[cfg(all(feature = "arrow", not(target_arch = "wasm32")))]
 use arrow::datatypes::{DataType as ArrowDataType, TimeUnit};
@@ -17,16 +18,69 @@ use std::sync::Arc;
 #[derive(Debug, PartialEq, Clone)]
 pub enum Series {
     I32(String, Vec<i32>, Vec<bool>),
+    I64(String, Vec<i64>, Vec<bool>),
     F64(String, Vec<f64>, Vec<bool>),
     Bool(String, Vec<bool>, Vec<bool>),
     String(String, Vec<String>, Vec<bool>),
     DateTime(String, Vec<i64>, Vec<bool>),
 }
 
+/// How [`Series::quantile`] picks a value when the requested rank falls between two
+/// sorted values `lower` and `higher`, at fraction `f` of the way from one to the other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Interpolation {
+    /// `lower + (higher - lower) * f`.
+    #[default]
+    Linear,
+    /// Always `lower`.
+    Lower,
+    /// Always `higher`.
+    Higher,
+    /// Whichever of `lower` and `higher` is closer; ties go to `higher`.
+    Nearest,
+    /// `(lower + higher) / 2`.
+    Midpoint,
+}
+
+/// Collects the non-null values of a column and sorts them with `compare`.
+fn sorted_valid<T: Copy + Send + Sync>(
+    values: &[T],
+    bitmap: &[bool],
+    compare: impl Fn(&T, &T) -> std::cmp::Ordering + Sync,
+) -> Vec<T> {
+    use rayon::prelude::*;
+    let mut valid: Vec<T> = values
+        .par_iter()
+        .zip(bitmap.par_iter())
+        .filter_map(|(&v, &b)| if b { Some(v) } else { None })
+        .collect();
+    valid.par_sort_unstable_by(compare);
+    valid
+}
+
+/// Locates quantile `q` in `n` sorted values as `(lower, higher, weight)`, where the result
+/// is `values[lower] + (values[higher] - values[lower]) * weight`.
+fn quantile_position(n: usize, q: f64, interpolation: Interpolation) -> (usize, usize, f64) {
+    let rank = (n - 1) as f64 * q;
+    let lower = rank.floor() as usize;
+    let higher = rank.ceil() as usize;
+    match interpolation {
+        Interpolation::Linear => (lower, higher, rank - lower as f64),
+        Interpolation::Lower => (lower, lower, 0.0),
+        Interpolation::Higher => (higher, higher, 0.0),
+        Interpolation::Nearest => {
+            let nearest = rank.round() as usize;
+            (nearest, nearest, 0.0)
+        }
+        Interpolation::Midpoint => (lower, higher, 0.5),
+    }
+}
+
 impl Series {
     pub fn name(&self) -> &str {
         match self {
             Series::I32(name, _, _) => name,
+            Series::I64(name, _, _) => name,
             Series::F64(name, _, _) => name,
             Series::Bool(name, _, _) => name,
             Series::String(name, _, _) => name,
@@ -37,6 +91,7 @@ impl Series {
     pub fn len(&self) -> usize {
         match self {
             Series::I32(_, values, _) => values.len(),
+            Series::I64(_, values, _) => values.len(),
             Series::F64(_, values, _) => values.len(),
             Series::Bool(_, values, _) => values.len(),
             Series::String(_, values, _) => values.len(),
@@ -51,6 +106,7 @@ impl Series {
     pub fn data_type(&self) -> DataType {
         match self {
             Series::I32(_, _, _) => DataType::I32,
+            Series::I64(_, _, _) => DataType::I64,
             Series::F64(_, _, _) => DataType::F64,
             Series::Bool(_, _, _) => DataType::Bool,
             Series::String(_, _, _) => DataType::String,
@@ -67,6 +123,13 @@ impl Series {
                     None
                 }
             }
+            Series::I64(_, values, validity) => {
+                if index < values.len() && validity[index] {
+                    Some(Value::I64(values[index]))
+                } else {
+                    None
+                }
+            }
             Series::F64(_, values, validity) => {
                 if index < values.len() && validity[index] {
                     Some(Value::F64(values[index]))
@@ -99,7 +162,60 @@ impl Series {
     }
 
     pub fn is_numeric(&self) -> bool {
-        matches!(self, Series::I32(_, _, _) | Series::F64(_, _, _))
+        matches!(
+            self,
+            Series::I32(_, _, _) | Series::I64(_, _, _) | Series::F64(_, _, _)
+        )
+    }
+
+    /// Returns whether every value is greater than or equal to the one before it.
+    ///
+    /// A null anywhere (or a NaN in an F64 series) breaks monotonicity, so such a series is
+    /// never monotonic. Empty and single-value series are monotonic. Every type can be
+    /// checked: strings compare lexicographically and `false` sorts before `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::series::Series;
+    ///
+    /// let ts = Series::new_datetime("ts", vec![Some(10), Some(10), Some(30)]);
+    /// assert!(ts.is_monotonic_increasing());
+    /// assert!(!ts.is_monotonic_decreasing());
+    /// assert!(!Series::new_i32("n", vec![Some(1), None, Some(2)]).is_monotonic_increasing());
+    /// ```
+    pub fn is_monotonic_increasing(&self) -> bool {
+        self.is_monotonic_by(|ordering| ordering != std::cmp::Ordering::Greater)
+    }
+
+    /// Returns whether every value is less than or equal to the one before it.
+    ///
+    /// Nulls and NaNs break monotonicity as for [`Series::is_monotonic_increasing`].
+    pub fn is_monotonic_decreasing(&self) -> bool {
+        self.is_monotonic_by(|ordering| ordering != std::cmp::Ordering::Less)
+    }
+
+    /// Checks, in a single pass, that there are no nulls and that every adjacent pair of
+    /// values is comparable with an ordering accepted by `accept`.
+    fn is_monotonic_by(&self, accept: fn(std::cmp::Ordering) -> bool) -> bool {
+        fn check<T: PartialOrd>(
+            values: &[T],
+            validity: &[bool],
+            accept: fn(std::cmp::Ordering) -> bool,
+        ) -> bool {
+            validity.iter().all(|&valid| valid)
+                && values
+                    .windows(2)
+                    .all(|pair| pair[0].partial_cmp(&pair[1]).is_some_and(accept))
+        }
+        match self {
+            Series::I32(_, values, validity) => check(values, validity, accept),
+            Series::I64(_, values, validity) => check(values, validity, accept),
+            Series::F64(_, values, validity) => check(values, validity, accept),
+            Series::Bool(_, values, validity) => check(values, validity, accept),
+            Series::String(_, values, validity) => check(values, validity, accept),
+            Series::DateTime(_, values, validity) => check(values, validity, accept),
+        }
     }
 
     /// Element-wise equality comparison between two series. Returns a Bool series mask.
@@ -117,6 +233,7 @@ impl Series {
             let b = other.get_value(i);
             match (a, b) {
                 (Some(Value::I32(av)), Some(Value::I32(bv))) => result.push(Some(av == bv)),
+                (Some(Value::I64(av)), Some(Value::I64(bv))) => result.push(Some(av == bv)),
                 (Some(Value::F64(av)), Some(Value::F64(bv))) => {
                     result.push(Some((av - bv).abs() < f64::EPSILON))
                 }
@@ -131,6 +248,42 @@ impl Series {
         Ok(Series::new_bool("expected", result))
     }
 
+    /// Checks whether two series match, allowing F64 values to differ by up to `tol`.
+    ///
+    /// The series must have the same name, type and length. Cells match when both are null,
+    /// when two F64 values are within `tol` of each other (so equal infinities match), or
+    /// when any other values are exactly equal. Unlike `==`, the placeholders stored under
+    /// nulls are ignored.
+    ///
+    /// NaN matches NaN here, so a series compares equal to itself even when it holds NaN.
+    /// This suits tests of numeric transformations, but differs from IEEE comparison, where
+    /// NaN never equals anything.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::series::Series;
+    ///
+    /// let computed = Series::new_f64("ratio", vec![Some(0.1 + 0.2), None, Some(f64::NAN)]);
+    /// let expected = Series::new_f64("ratio", vec![Some(0.3), None, Some(f64::NAN)]);
+    /// assert!(computed != expected);
+    /// assert!(computed.approx_eq(&expected, 1e-12));
+    /// assert!(!computed.approx_eq(&Series::new_f64("ratio", vec![Some(0.3), None, None]), 1e-12));
+    /// ```
+    pub fn approx_eq(&self, other: &Series, tol: f64) -> bool {
+        self.name() == other.name()
+            && self.data_type() == other.data_type()
+            && self.len() == other.len()
+            && (0..self.len()).all(|i| match (self.get_value(i), other.get_value(i)) {
+                (None, None) => true,
+                (Some(Value::F64(a)), Some(Value::F64(b))) => {
+                    (a.is_nan() && b.is_nan()) || a == b || (a - b).abs() <= tol
+                }
+                (Some(a), Some(b)) => a == b,
+                _ => false,
+            })
+    }
+
     /// Element-wise greater-than comparison between two numeric series. Returns a Bool series mask.
     pub fn gt(&self, other: &Series) -> Result<Series, VeloxxError> {
         if self.len() != other.len() {
@@ -149,6 +302,91 @@ impl Series {
         Ok(Series::new_bool("expected", result))
     }
 
+    /// Membership test against the values of another series. Returns a Bool series mask
+    /// that is true where the cell appears in `values`; null cells stay null.
+    pub fn is_in(&self, values: &Series) -> Result<Series, VeloxxError> {
+        if self.data_type() != values.data_type() {
+            return Err(VeloxxError::DataTypeMismatch(format!(
+                "Cannot test membership of {:?} series against {:?} values",
+                self.data_type(),
+                values.data_type()
+            )));
+        }
+
+        let lookup: std::collections::HashSet<Value> = (0..values.len())
+            .filter_map(|i| values.get_value(i))
+            .collect();
+        let result: Vec<Option<bool>> = (0..self.len())
+            .map(|i| self.get_value(i).map(|v| lookup.contains(&v)))
+            .collect();
+        Ok(Series::new_bool(self.name(), result))
+    }
+
+    /// Range test against two scalar bounds. Returns a Bool series mask that is true where
+    /// `low <= cell <= high` (or `low < cell < high` when `inclusive` is false); null cells
+    /// stay null.
+    ///
+    /// Numeric series accept `I32` or `F64` bounds; other series require bounds of their own
+    /// type, otherwise `VeloxxError::DataTypeMismatch` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::series::Series;
+    /// use veloxx::types::Value;
+    ///
+    /// let ages = Series::new_i32("age", vec![Some(17), Some(18), None, Some(65)]);
+    /// let mask = ages.between(&Value::I32(18), &Value::F64(64.5), true).unwrap();
+    /// assert_eq!(mask.get_value(0), Some(Value::Bool(false)));
+    /// assert_eq!(mask.get_value(1), Some(Value::Bool(true)));
+    /// assert_eq!(mask.get_value(2), None);
+    /// assert_eq!(mask.get_value(3), Some(Value::Bool(false)));
+    /// ```
+    pub fn between(
+        &self,
+        low: &Value,
+        high: &Value,
+        inclusive: bool,
+    ) -> Result<Series, VeloxxError> {
+        let dtype = self.data_type();
+        for bound in [low, high] {
+            let compatible = match bound {
+                Value::I32(_) | Value::I64(_) | Value::F64(_) => self.is_numeric(),
+                Value::Null => false,
+                other => other.data_type() == dtype,
+            };
+            if !compatible {
+                return Err(VeloxxError::DataTypeMismatch(format!(
+                    "Cannot compare {dtype:?} series against bound {bound:?}"
+                )));
+            }
+        }
+
+        use std::cmp::Ordering;
+        let result: Vec<Option<bool>> = (0..self.len())
+            .map(|i| {
+                self.get_value(i).map(|v| {
+                    let compare = |bound: &Value| {
+                        if self.is_numeric() {
+                            v.partial_cmp_numeric(bound)
+                        } else {
+                            v.partial_cmp(bound)
+                        }
+                    };
+                    let above = compare(low);
+                    let below = compare(high);
+                    if inclusive {
+                        matches!(above, Some(Ordering::Greater | Ordering::Equal))
+                            && matches!(below, Some(Ordering::Less | Ordering::Equal))
+                    } else {
+                        above == Some(Ordering::Greater) && below == Some(Ordering::Less)
+                    }
+                })
+            })
+            .collect();
+        Ok(Series::new_bool(self.name(), result))
+    }
+
     /// Element-wise logical AND for boolean series.
     pub fn and(&self, other: &Series) -> Result<Series, VeloxxError> {
         if self.len() != other.len() {
@@ -228,6 +466,19 @@ impl Series {
                     }
                     Ok(Series::new_i32(name, out))
                 }
+                Series::I64(name, vals, vmap) => {
+                    let mut out: Vec<Option<i64>> = Vec::new();
+                    for i in 0..vals.len() {
+                        if validity[i] && values[i] {
+                            if vmap[i] {
+                                out.push(Some(vals[i]));
+                            } else {
+                                out.push(None);
+                            }
+                        }
+                    }
+                    Ok(Series::new_i64(name, out))
+                }
                 Series::F64(name, vals, vmap) => {
                     let mut out: Vec<Option<f64>> = Vec::new();
                     for i in 0..vals.len() {
@@ -438,6 +689,136 @@ impl Series {
             )),
         }
     }
+
+    /// Encodes an ordinal string series as integer ranks in a caller-provided order.
+    ///
+    /// Each value is mapped to its index in `order`, so that comparisons on the codes follow
+    /// the semantic ordering of the categories (e.g. `"low" < "medium" < "high"`). Unlike
+    /// [`DataFrame::factorize`](crate::dataframe::DataFrame::factorize), which numbers values
+    /// by first appearance, the codes do not depend on the data. Values not listed in `order`
+    /// become null, and nulls stay null.
+    ///
+    /// # Arguments
+    ///
+    /// * `order` - The categories from lowest to highest.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok(Series)` containing an `I32` series with the same name,
+    /// `Err(VeloxxError::DataTypeMismatch)` if the series is not a `String` series, or
+    /// `Err(VeloxxError::InvalidOperation)` if `order` lists a category more than once.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::series::Series;
+    /// use veloxx::types::Value;
+    ///
+    /// let sizes = Series::new_string(
+    ///     "size",
+    ///     vec![Some("high".to_string()), Some("low".to_string()), Some("huge".to_string()), None],
+    /// );
+    /// let codes = sizes.encode_ordinal(&["low", "medium", "high"]).unwrap();
+    /// assert_eq!(codes.get_value(0), Some(Value::I32(2)));
+    /// assert_eq!(codes.get_value(1), Some(Value::I32(0)));
+    /// assert_eq!(codes.get_value(2), None);
+    /// assert_eq!(codes.get_value(3), None);
+    /// ```
+    pub fn encode_ordinal(&self, order: &[&str]) -> Result<Series, VeloxxError> {
+        let Series::String(name, values, validity) = self else {
+            return Err(VeloxxError::DataTypeMismatch(format!(
+                "Cannot encode series '{}' as ordinal: expected a String series",
+                self.name()
+            )));
+        };
+
+        let mut ranks: std::collections::HashMap<&str, i32> =
+            std::collections::HashMap::with_capacity(order.len());
+        for (rank, category) in order.iter().enumerate() {
+            if ranks.insert(*category, rank as i32).is_some() {
+                return Err(VeloxxError::InvalidOperation(format!(
+                    "Category '{}' is listed more than once in the ordinal order",
+                    category
+                )));
+            }
+        }
+
+        let codes: Vec<Option<i32>> = values
+            .iter()
+            .zip(validity.iter())
+            .map(|(value, &is_valid)| {
+                if is_valid {
+                    ranks.get(value.as_str()).copied()
+                } else {
+                    None
+                }
+            })
+            .collect();
+        Ok(Series::new_i32(name, codes))
+    }
+
+    /// One-hot encodes a string series into a `DataFrame` of boolean indicator columns.
+    ///
+    /// There is one column per distinct value, named `<prefix>_<value>` and ordered by the
+    /// sorted values. Each row is `true` in the column of its value and `false` elsewhere; a
+    /// null row is `false` in every column. Every indicator has the same length as the series,
+    /// so the columns can be joined back onto the frame the series came from.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - The prefix of the indicator column names.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok(DataFrame)` containing the indicator columns, or
+    /// `Err(VeloxxError::DataTypeMismatch)` if the series is not a `String` series.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::series::Series;
+    /// use veloxx::types::Value;
+    ///
+    /// let colors = Series::new_string(
+    ///     "color",
+    ///     vec![Some("red".to_string()), Some("blue".to_string()), None],
+    /// );
+    /// let dummies = colors.to_dummies("color").unwrap();
+    /// assert_eq!(dummies.column_names(), vec!["color_blue", "color_red"]);
+    /// let red = dummies.get_column("color_red").unwrap();
+    /// assert_eq!(red.get_value(0), Some(Value::Bool(true)));
+    /// assert_eq!(red.get_value(2), Some(Value::Bool(false)));
+    /// ```
+    pub fn to_dummies(&self, prefix: &str) -> Result<crate::dataframe::DataFrame, VeloxxError> {
+        let Series::String(_, values, validity) = self else {
+            return Err(VeloxxError::DataTypeMismatch(format!(
+                "Cannot build dummies from series '{}': expected a String series",
+                self.name()
+            )));
+        };
+
+        let categories: std::collections::BTreeSet<&str> = values
+            .iter()
+            .zip(validity.iter())
+            .filter(|(_, &is_valid)| is_valid)
+            .map(|(value, _)| value.as_str())
+            .collect();
+
+        let mut columns = indexmap::IndexMap::with_capacity(categories.len());
+        for category in categories {
+            let column_name = format!("{}_{}", prefix, category);
+            let indicators: Vec<Option<bool>> = values
+                .iter()
+                .zip(validity.iter())
+                .map(|(value, &is_valid)| Some(is_valid && value == category))
+                .collect();
+            columns.insert(
+                column_name.clone(),
+                Series::new_bool(&column_name, indicators),
+            );
+        }
+        Ok(crate::dataframe::DataFrame::new(columns))
+    }
     /// Returns numeric value as f64 at index if present and valid, else None
     fn get_numeric_f64(&self, index: usize) -> Option<f64> {
         match self {
@@ -455,6 +836,13 @@ impl Series {
                     None
                 }
             }
+            Series::I64(_, values, validity) => {
+                if index < values.len() && validity[index] {
+                    Some(values[index] as f64)
+                } else {
+                    None
+                }
+            }
             _ => None,
         }
     }
@@ -472,6 +860,20 @@ impl Series {
         }
     }
 
+    /// Returns i64 value at index if present and valid, else None
+    pub fn get_i64(&self, index: usize) -> Option<i64> {
+        match self {
+            Series::I64(_, values, validity) => {
+                if index < values.len() && validity[index] {
+                    Some(values[index])
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        }
+    }
+
     /// Returns f64 value at index if present and valid, else None
     pub fn get_f64(&self, index: usize) -> Option<f64> {
         match self {
@@ -498,90 +900,101 @@ impl Series {
             _ => None,
         }
     }
-    /// Compute the percentile for a given value (0.0 to 100.0) using parallel sorting.
+    /// Compute the percentile for a given value (0.0 to 100.0), picking the nearest value.
+    ///
+    /// Equivalent to [`Series::quantile`] at `pct / 100.0` with [`Interpolation::Nearest`].
     pub fn percentile(&self, pct: f64) -> Result<Option<Value>, VeloxxError> {
         if !(0.0..=100.0).contains(&pct) {
             return Err(VeloxxError::InvalidOperation(
                 "Percentile must be between 0.0 and 100.0".to_string(),
             ));
         }
-        let prob = pct / 100.0;
-        match self {
-            Series::I32(_, values, bitmap) => {
-                use rayon::prelude::*;
-                let mut non_null_data: Vec<i32> = values
-                    .par_iter()
-                    .zip(bitmap.par_iter())
-                    .filter_map(|(&v, &b)| if b { Some(v) } else { None })
-                    .collect();
-                if non_null_data.is_empty() {
-                    return Ok(None);
-                }
-                non_null_data.par_sort_unstable();
-                let n = non_null_data.len();
-                let pos = ((n - 1) as f64 * prob).round() as usize;
-                Ok(Some(Value::I32(non_null_data[pos])))
-            }
-            Series::F64(_, values, bitmap) => {
-                use rayon::prelude::*;
-                let mut non_null_data: Vec<f64> = values
-                    .par_iter()
-                    .zip(bitmap.par_iter())
-                    .filter_map(|(&v, &b)| if b { Some(v) } else { None })
-                    .collect();
-                if non_null_data.is_empty() {
-                    return Ok(None);
-                }
-                non_null_data
-                    .par_sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
-                let n = non_null_data.len();
-                let pos = ((n - 1) as f64 * prob).round() as usize;
-                Ok(Some(Value::F64(non_null_data[pos])))
-            }
-            _ => Err(VeloxxError::Unsupported(format!(
-                "Percentile operation not supported for {:?} series.",
-                self.data_type()
-            ))),
-        }
+        self.quantile(pct / 100.0, Interpolation::Nearest)
     }
-    /// Compute the quantile for a given probability (0.0 to 1.0) using parallel sorting.
-    pub fn quantile(&self, prob: f64) -> Result<Option<Value>, VeloxxError> {
-        if !(0.0..=1.0).contains(&prob) {
+
+    /// Compute the quantile `q` (0.0 to 1.0) of the non-null values.
+    ///
+    /// Values are sorted and `q` is mapped to the fractional rank `(n - 1) * q`, which
+    /// `interpolation` resolves when it falls between two values. `I32` and `I64` series keep
+    /// their type for [`Interpolation::Lower`], [`Interpolation::Higher`] and
+    /// [`Interpolation::Nearest`] and return `F64` otherwise; `F64` series return `F64`;
+    /// `DateTime` series return `DateTime`, rounded to the nearest unit.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(None)` if there are no non-null values, `Err(VeloxxError::InvalidOperation)` if `q`
+    /// is outside `0.0..=1.0`, or `Err(VeloxxError::Unsupported)` for `Bool` and `String` series.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::series::{Interpolation, Series};
+    /// use veloxx::types::Value;
+    ///
+    /// let series = Series::new_i32("x", vec![Some(4), Some(1), None, Some(3), Some(2)]);
+    /// assert_eq!(series.quantile(0.5, Interpolation::Linear).unwrap(), Some(Value::F64(2.5)));
+    /// assert_eq!(series.quantile(0.5, Interpolation::Lower).unwrap(), Some(Value::I32(2)));
+    /// assert_eq!(series.quantile(0.5, Interpolation::Higher).unwrap(), Some(Value::I32(3)));
+    /// ```
+    pub fn quantile(
+        &self,
+        q: f64,
+        interpolation: Interpolation,
+    ) -> Result<Option<Value>, VeloxxError> {
+        if !(0.0..=1.0).contains(&q) {
             return Err(VeloxxError::InvalidOperation(
                 "Quantile probability must be between 0.0 and 1.0".to_string(),
             ));
         }
+        let exact = matches!(
+            interpolation,
+            Interpolation::Lower | Interpolation::Higher | Interpolation::Nearest
+        );
         match self {
             Series::I32(_, values, bitmap) => {
-                use rayon::prelude::*;
-                let mut non_null_data: Vec<i32> = values
-                    .par_iter()
-                    .zip(bitmap.par_iter())
-                    .filter_map(|(&v, &b)| if b { Some(v) } else { None })
-                    .collect();
-                if non_null_data.is_empty() {
+                let sorted = sorted_valid(values, bitmap, |a, b| a.cmp(b));
+                if sorted.is_empty() {
+                    return Ok(None);
+                }
+                let (lower, higher, weight) = quantile_position(sorted.len(), q, interpolation);
+                if exact {
+                    return Ok(Some(Value::I32(sorted[lower])));
+                }
+                let (a, b) = (sorted[lower] as f64, sorted[higher] as f64);
+                Ok(Some(Value::F64(a + (b - a) * weight)))
+            }
+            Series::I64(_, values, bitmap) => {
+                let sorted = sorted_valid(values, bitmap, |a, b| a.cmp(b));
+                if sorted.is_empty() {
                     return Ok(None);
                 }
-                non_null_data.par_sort_unstable();
-                let n = non_null_data.len();
-                let pos = ((n - 1) as f64 * prob).round() as usize;
-                Ok(Some(Value::I32(non_null_data[pos])))
+                let (lower, higher, weight) = quantile_position(sorted.len(), q, interpolation);
+                if exact {
+                    return Ok(Some(Value::I64(sorted[lower])));
+                }
+                let (a, b) = (sorted[lower] as f64, sorted[higher] as f64);
+                Ok(Some(Value::F64(a + (b - a) * weight)))
             }
             Series::F64(_, values, bitmap) => {
-                use rayon::prelude::*;
-                let mut non_null_data: Vec<f64> = values
-                    .par_iter()
-                    .zip(bitmap.par_iter())
-                    .filter_map(|(&v, &b)| if b { Some(v) } else { None })
-                    .collect();
-                if non_null_data.is_empty() {
+                let sorted = sorted_valid(values, bitmap, |a, b| {
+                    a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal)
+                });
+                if sorted.is_empty() {
                     return Ok(None);
                 }
-                non_null_data
-                    .par_sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
-                let n = non_null_data.len();
-                let pos = ((n - 1) as f64 * prob).round() as usize;
-                Ok(Some(Value::F64(non_null_data[pos])))
+                let (lower, higher, weight) = quantile_position(sorted.len(), q, interpolation);
+                let (a, b) = (sorted[lower], sorted[higher]);
+                Ok(Some(Value::F64(a + (b - a) * weight)))
+            }
+            Series::DateTime(_, values, bitmap) => {
+                let sorted = sorted_valid(values, bitmap, |a, b| a.cmp(b));
+                if sorted.is_empty() {
+                    return Ok(None);
+                }
+                let (lower, higher, weight) = quantile_position(sorted.len(), q, interpolation);
+                let (a, b) = (sorted[lower], sorted[higher]);
+                let offset = ((b - a) as f64 * weight).round() as i64;
+                Ok(Some(Value::DateTime(a + offset)))
             }
             _ => Err(VeloxxError::Unsupported(format!(
                 "Quantile operation not supported for {:?} series.",
@@ -607,6 +1020,24 @@ impl Series {
         Series::I32(name.to_string(), values, bitmap)
     }
 
+    pub fn new_i64(name: &str, data: Vec<Option<i64>>) -> Self {
+        let mut values = Vec::with_capacity(data.len());
+        let mut bitmap = Vec::with_capacity(data.len());
+        for v in data {
+            match v {
+                Some(val) => {
+                    values.push(val);
+                    bitmap.push(true);
+                }
+                None => {
+                    values.push(0); // placeholder
+                    bitmap.push(false);
+                }
+            }
+        }
+        Series::I64(name.to_string(), values, bitmap)
+    }
+
     pub fn new_f64(name: &str, data: Vec<Option<f64>>) -> Self {
         let mut values = Vec::with_capacity(data.len());
         let mut bitmap = Vec::with_capacity(data.len());
@@ -691,6 +1122,14 @@ impl Series {
                 let bitmap: Vec<bool> = arr.iter().map(|x| x.is_some()).collect();
                 Ok(Series::I32(name, values, bitmap))
             }
+            ArrowDataType::Int64 => {
+                let arr = array.as_any().downcast_ref::<Int64Array>().ok_or_else(|| {
+                    VeloxxError::Parsing("Failed to downcast to Int64Array".to_string())
+                })?;
+                let values: Vec<i64> = arr.iter().map(|x| x.unwrap_or(0)).collect();
+                let bitmap: Vec<bool> = arr.iter().map(|x| x.is_some()).collect();
+                Ok(Series::I64(name, values, bitmap))
+            }
             ArrowDataType::Float64 => {
                 let arr = array
                     .as_any()
@@ -755,6 +1194,13 @@ impl Series {
                     .map(|(&v, &b)| if b { Some(v) } else { None });
                 Arc::new(Int32Array::from_iter(iter))
             }
+            Series::I64(_, values, bitmap) => {
+                let iter = values
+                    .iter()
+                    .zip(bitmap.iter())
+                    .map(|(&v, &b)| if b { Some(v) } else { None });
+                Arc::new(Int64Array::from_iter(iter))
+            }
             Series::F64(_, values, bitmap) => {
                 let iter = values
                     .iter()
@@ -820,6 +1266,19 @@ impl Series {
                 }
                 Ok(Series::I32(name, values, bitmap))
             }
+            DataType::I64 => {
+                let mut values = Vec::new();
+                let mut bitmap = Vec::new();
+                for s in series_list {
+                    if let Series::I64(_, v, b) = s {
+                        values.extend(v);
+                        bitmap.extend(b);
+                    } else {
+                        unreachable!();
+                    }
+                }
+                Ok(Series::I64(name, values, bitmap))
+            }
             DataType::F64 => {
                 let mut values = Vec::new();
                 let mut bitmap = Vec::new();
@@ -875,54 +1334,72 @@ impl Series {
         }
     }
 
-    pub fn value_counts(&self) -> Result<Series, VeloxxError> {
-        let name = format!("{}_value_counts", self.name());
-        match self {
-            Series::I32(_, values, bitmap) => {
-                let mut counts = indexmap::IndexMap::new();
-                for (&v, &b) in values.iter().zip(bitmap.iter()) {
-                    if b {
-                        *counts.entry(v).or_insert(0usize) += 1;
-                    }
-                }
-                let mut unique_values = Vec::with_capacity(counts.len());
-                let mut unique_counts = Vec::with_capacity(counts.len());
-                let mut unique_bitmap = Vec::with_capacity(counts.len());
-                for (val, count) in counts {
-                    unique_values.push(val);
-                    unique_counts.push(count as i32);
-                    unique_bitmap.push(true);
-                }
-                // Return as a two-column Series: values and counts (as I32)
-                // Here, we return a Series::I32 with values being the counts, and the name indicating value_counts
-                // For a more complex return, consider a DataFrame or tuple
-                Ok(Series::I32(name, unique_counts, unique_bitmap))
-            }
-            Series::F64(_, values, bitmap) => {
-                let mut counts = indexmap::IndexMap::new();
-                for (&v, &b) in values.iter().zip(bitmap.iter()) {
-                    if b {
-                        *counts.entry(v.to_bits()).or_insert(0usize) += 1;
-                    }
-                }
-                let mut unique_values = Vec::with_capacity(counts.len());
-                let mut unique_counts = Vec::with_capacity(counts.len());
-                let mut unique_bitmap = Vec::with_capacity(counts.len());
-                for (bits, count) in counts {
-                    unique_values.push(f64::from_bits(bits));
-                    unique_counts.push(count as i32);
-                    unique_bitmap.push(true);
-                }
-                // Return as a two-column Series: values and counts (as I32)
-                // Here, we return a Series::I32 with values being the counts, and the name indicating value_counts
-                // For a more complex return, consider a DataFrame or tuple
-                Ok(Series::I32(name, unique_counts, unique_bitmap))
-            }
-            _ => Err(VeloxxError::Unsupported(
-                "value_counts only supported for I32 and F64 series".to_string(),
-            )),
-        }
+    /// Counts how often each distinct value occurs, ignoring nulls.
+    ///
+    /// Equivalent to `value_counts_opt(true)`.
+    pub fn value_counts(&self) -> Result<crate::dataframe::DataFrame, VeloxxError> {
+        self.value_counts_opt(true)
+    }
+
+    /// Counts how often each distinct value occurs, as a frequency table.
+    ///
+    /// The result has a column named after the series, holding each distinct value with the
+    /// series' type, and an I32 `count` column. Rows are sorted by count descending, then by
+    /// value ascending. With `dropna` set nulls are skipped; otherwise they are counted as
+    /// one more row, placed after the values with the same count.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok(DataFrame)` with the frequency table, or
+    /// `Err(VeloxxError::InvalidOperation)` if the series itself is named `count`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::series::Series;
+    /// use veloxx::types::Value;
+    ///
+    /// let colors = Series::new_string("color", vec![
+    ///     Some("red".to_string()),
+    ///     None,
+    ///     Some("blue".to_string()),
+    ///     Some("red".to_string()),
+    /// ]);
+    /// let counts = colors.value_counts_opt(false).unwrap();
+    /// assert_eq!(counts.column_names(), vec!["color", "count"]);
+    /// assert_eq!(counts.get_value(0, "color").unwrap(), Some(Value::String("red".to_string())));
+    /// assert_eq!(counts.get_value(0, "count").unwrap(), Some(Value::I32(2)));
+    /// assert_eq!(counts.get_value(2, "color").unwrap(), None);
+    /// ```
+    pub fn value_counts_opt(
+        &self,
+        dropna: bool,
+    ) -> Result<crate::dataframe::DataFrame, VeloxxError> {
+        let mut counts: indexmap::IndexMap<Option<Value>, usize> = indexmap::IndexMap::new();
+        for i in 0..self.len() {
+            let value = self.get_value(i);
+            if value.is_some() || !dropna {
+                *counts.entry(value).or_insert(0) += 1;
+            }
+        }
+        let mut counts: Vec<(Option<Value>, usize)> = counts.into_iter().collect();
+        counts.sort_by(|(a, count_a), (b, count_b)| {
+            count_b
+                .cmp(count_a)
+                .then_with(|| a.is_none().cmp(&b.is_none()))
+                .then_with(|| a.cmp(b))
+        });
+
+        let (values, counts): (Vec<Option<Value>>, Vec<Option<i32>>) = counts
+            .into_iter()
+            .map(|(value, count)| (value, Some(count as i32)))
+            .unzip();
+        crate::dataframe::DataFrame::from_series(vec![
+            Series::from_values(self.name(), values, self.data_type())?,
+            Series::new_i32("count", counts),
+        ])
     }
+
     /// Interpolates null values using linear interpolation for numeric series.
     ///
     /// This method performs linear interpolation on null values. It only works
@@ -947,6 +1424,11 @@ impl Series {
                 .zip(bitmap.iter())
                 .map(|(&v, &b)| (if b { Some(v as f64) } else { None }, b))
                 .unzip(),
+            Series::I64(_, values, bitmap) => values
+                .iter()
+                .zip(bitmap.iter())
+                .map(|(&v, &b)| (if b { Some(v as f64) } else { None }, b))
+                .unzip(),
             Series::F64(_, values, bitmap) => values
                 .iter()
                 .zip(bitmap.iter())
@@ -1008,6 +1490,13 @@ impl Series {
                     .collect();
                 Ok(Series::I32(name, rounded, interpolated_bitmap))
             }
+            Series::I64(_, _, _) => {
+                let rounded: Vec<i64> = interpolated_values
+                    .iter()
+                    .map(|&v| v.round() as i64)
+                    .collect();
+                Ok(Series::I64(name, rounded, interpolated_bitmap))
+            }
             Series::F64(_, _, _) => Ok(Series::F64(name, interpolated_values, interpolated_bitmap)),
             _ => unreachable!(),
         }
@@ -1030,6 +1519,13 @@ impl Series {
                 new_bitmap.extend(bitmap2.iter().cloned());
                 Ok(Series::I32(new_name, new_values, new_bitmap))
             }
+            (Series::I64(_, values1, bitmap1), Series::I64(_, values2, bitmap2)) => {
+                let mut new_values = values1.clone();
+                let mut new_bitmap = bitmap1.clone();
+                new_values.extend(values2.iter().cloned());
+                new_bitmap.extend(bitmap2.iter().cloned());
+                Ok(Series::I64(new_name, new_values, new_bitmap))
+            }
             (Series::F64(_, values1, bitmap1), Series::F64(_, values2, bitmap2)) => {
                 let mut new_values = values1.clone();
                 let mut new_bitmap = bitmap1.clone();
@@ -1078,6 +1574,19 @@ impl Series {
         }
     }
 
+    pub fn get_data_i64(&self) -> Result<Vec<Option<i64>>, VeloxxError> {
+        match self {
+            Series::I64(_, values, validity) => Ok(values
+                .iter()
+                .zip(validity.iter())
+                .map(|(&v, &b)| if b { Some(v) } else { None })
+                .collect()),
+            _ => Err(VeloxxError::DataTypeMismatch(
+                "Expected I64 series".to_string(),
+            )),
+        }
+    }
+
     pub fn get_data_f64(&self) -> Result<Vec<Option<f64>>, VeloxxError> {
         match self {
             Series::F64(_, values, validity) => Ok(values
@@ -1130,7 +1639,154 @@ impl Series {
         }
     }
 
+    /// Consumes an I32 series, returning its values with nulls as `None`.
+    pub fn into_vec_i32(self) -> Result<Vec<Option<i32>>, VeloxxError> {
+        match self {
+            Series::I32(_, values, validity) => Ok(values
+                .into_iter()
+                .zip(validity)
+                .map(|(v, b)| if b { Some(v) } else { None })
+                .collect()),
+            _ => Err(VeloxxError::DataTypeMismatch(
+                "Expected I32 series".to_string(),
+            )),
+        }
+    }
+
+    /// Consumes an I64 series, returning its values with nulls as `None`.
+    pub fn into_vec_i64(self) -> Result<Vec<Option<i64>>, VeloxxError> {
+        match self {
+            Series::I64(_, values, validity) => Ok(values
+                .into_iter()
+                .zip(validity)
+                .map(|(v, b)| if b { Some(v) } else { None })
+                .collect()),
+            _ => Err(VeloxxError::DataTypeMismatch(
+                "Expected I64 series".to_string(),
+            )),
+        }
+    }
+
+    /// Consumes an F64 series, returning its values with nulls as `None`.
+    pub fn into_vec_f64(self) -> Result<Vec<Option<f64>>, VeloxxError> {
+        match self {
+            Series::F64(_, values, validity) => Ok(values
+                .into_iter()
+                .zip(validity)
+                .map(|(v, b)| if b { Some(v) } else { None })
+                .collect()),
+            _ => Err(VeloxxError::DataTypeMismatch(
+                "Expected F64 series".to_string(),
+            )),
+        }
+    }
+
+    /// Consumes a Bool series, returning its values with nulls as `None`.
+    pub fn into_vec_bool(self) -> Result<Vec<Option<bool>>, VeloxxError> {
+        match self {
+            Series::Bool(_, values, validity) => Ok(values
+                .into_iter()
+                .zip(validity)
+                .map(|(v, b)| if b { Some(v) } else { None })
+                .collect()),
+            _ => Err(VeloxxError::DataTypeMismatch(
+                "Expected Bool series".to_string(),
+            )),
+        }
+    }
+
+    /// Consumes a String series, returning its values with nulls as `None`.
+    pub fn into_vec_string(self) -> Result<Vec<Option<String>>, VeloxxError> {
+        match self {
+            Series::String(_, values, validity) => Ok(values
+                .into_iter()
+                .zip(validity)
+                .map(|(v, b)| if b { Some(v) } else { None })
+                .collect()),
+            _ => Err(VeloxxError::DataTypeMismatch(
+                "Expected String series".to_string(),
+            )),
+        }
+    }
+
+    /// Consumes a DateTime series, returning its values with nulls as `None`.
+    pub fn into_vec_datetime(self) -> Result<Vec<Option<i64>>, VeloxxError> {
+        match self {
+            Series::DateTime(_, values, validity) => Ok(values
+                .into_iter()
+                .zip(validity)
+                .map(|(v, b)| if b { Some(v) } else { None })
+                .collect()),
+            _ => Err(VeloxxError::DataTypeMismatch(
+                "Expected DateTime series".to_string(),
+            )),
+        }
+    }
+
+    /// Copies the values of an I32 series, with nulls as `None`.
+    ///
+    /// Equivalent to [`Series::get_data_i32`].
+    pub fn as_vec_i32(&self) -> Result<Vec<Option<i32>>, VeloxxError> {
+        self.get_data_i32()
+    }
+
+    /// Copies the values of an I64 series, with nulls as `None`.
+    ///
+    /// Equivalent to [`Series::get_data_i64`].
+    pub fn as_vec_i64(&self) -> Result<Vec<Option<i64>>, VeloxxError> {
+        self.get_data_i64()
+    }
+
+    /// Copies the values of an F64 series, with nulls as `None`.
+    ///
+    /// Equivalent to [`Series::get_data_f64`].
+    pub fn as_vec_f64(&self) -> Result<Vec<Option<f64>>, VeloxxError> {
+        self.get_data_f64()
+    }
+
+    /// Copies the values of a Bool series, with nulls as `None`.
+    ///
+    /// Equivalent to [`Series::get_data_bool`].
+    pub fn as_vec_bool(&self) -> Result<Vec<Option<bool>>, VeloxxError> {
+        self.get_data_bool()
+    }
+
+    /// Borrows the values of a String series as `&str`, with nulls as `None`.
+    pub fn as_vec_string(&self) -> Result<Vec<Option<&str>>, VeloxxError> {
+        match self {
+            Series::String(_, values, validity) => Ok(values
+                .iter()
+                .zip(validity.iter())
+                .map(|(v, &b)| if b { Some(v.as_str()) } else { None })
+                .collect()),
+            _ => Err(VeloxxError::DataTypeMismatch(
+                "Expected String series".to_string(),
+            )),
+        }
+    }
+
+    /// Copies the values of a DateTime series, with nulls as `None`.
+    ///
+    /// Equivalent to [`Series::get_data_datetime`].
+    pub fn as_vec_datetime(&self) -> Result<Vec<Option<i64>>, VeloxxError> {
+        self.get_data_datetime()
+    }
+
     /// Cast series to a different data type
+    ///
+    /// I32 to I64 and I32/I64 to F64 are always allowed; I64 values beyond 2^53 lose precision
+    /// as F64. F64 to I32 or I64 truncates fractional parts toward zero (3.9 becomes 3, -3.9
+    /// becomes -3). NaN and values outside the target range (including I64 to I32) become null
+    /// rather than wrapping or saturating.
+    /// Strings that fail to parse as F64 also become null. Strings cast to DateTime are parsed
+    /// with [`Series::parse_datetime_multi`] as `%Y-%m-%dT%H:%M:%S`, `%Y-%m-%d %H:%M:%S` or
+    /// `%Y-%m-%d`, and become null if none match. Use [`Series::try_cast`] to find the rows
+    /// affected by such lossy conversions.
+    ///
+    /// Bool to F64 maps `false` to 0.0 and `true` to 1.0. I32, I64 and F64 to Bool follow the
+    /// non-zero-is-true convention: 0 (and -0.0) becomes `false` and any other value `true`,
+    /// so a count column becomes a "has any" flag. NaN has no truth value and becomes null.
+    /// Nulls stay null in both directions.
     pub fn cast(&self, to_type: DataType) -> Result<Series, VeloxxError> {
         let name = self.name();
         let target_type = to_type.clone();
@@ -1140,10 +1796,90 @@ impl Series {
                 let new_values: Vec<f64> = values.iter().map(|&x| x as f64).collect();
                 Ok(Series::F64(name.to_string(), new_values, bitmap.clone()))
             }
-            // F64 to I32 (with truncation)
+            // I32 to I64 (lossless)
+            (Series::I32(_, values, bitmap), DataType::I64) => {
+                let new_values: Vec<i64> = values.iter().map(|&x| x as i64).collect();
+                Ok(Series::I64(name.to_string(), new_values, bitmap.clone()))
+            }
+            // I64 to F64
+            (Series::I64(_, values, bitmap), DataType::F64) => {
+                let new_values: Vec<f64> = values.iter().map(|&x| x as f64).collect();
+                Ok(Series::F64(name.to_string(), new_values, bitmap.clone()))
+            }
+            // I64 to I32 (out-of-range values become null)
+            (Series::I64(_, values, bitmap), DataType::I32) => {
+                let (new_values, new_bitmap): (Vec<i32>, Vec<bool>) = values
+                    .iter()
+                    .zip(bitmap.iter())
+                    .map(|(&x, &valid)| match i32::try_from(x) {
+                        Ok(v) if valid => (v, true),
+                        _ => (0, false),
+                    })
+                    .unzip();
+                Ok(Series::I32(name.to_string(), new_values, new_bitmap))
+            }
+            // F64 to I64 (truncating toward zero, out-of-range values become null)
+            (Series::F64(_, values, bitmap), DataType::I64) => {
+                let (new_values, new_bitmap): (Vec<i64>, Vec<bool>) = values
+                    .iter()
+                    .zip(bitmap.iter())
+                    .map(|(&x, &valid)| {
+                        let truncated = x.trunc();
+                        // i64::MAX as f64 rounds up to 2^63, which is itself out of range.
+                        if valid && truncated >= i64::MIN as f64 && truncated < i64::MAX as f64 {
+                            (truncated as i64, true)
+                        } else {
+                            (0, false)
+                        }
+                    })
+                    .unzip();
+                Ok(Series::I64(name.to_string(), new_values, new_bitmap))
+            }
+            // F64 to I32 (truncating toward zero, out-of-range values become null)
             (Series::F64(_, values, bitmap), DataType::I32) => {
-                let new_values: Vec<i32> = values.iter().map(|&x| x as i32).collect();
-                Ok(Series::I32(name.to_string(), new_values, bitmap.clone()))
+                let (new_values, new_bitmap): (Vec<i32>, Vec<bool>) = values
+                    .iter()
+                    .zip(bitmap.iter())
+                    .map(|(&x, &valid)| {
+                        let truncated = x.trunc();
+                        if valid && truncated >= i32::MIN as f64 && truncated <= i32::MAX as f64 {
+                            (truncated as i32, true)
+                        } else {
+                            (0, false)
+                        }
+                    })
+                    .unzip();
+                Ok(Series::I32(name.to_string(), new_values, new_bitmap))
+            }
+            // Bool to F64 (false is 0.0, true is 1.0)
+            (Series::Bool(_, values, bitmap), DataType::F64) => {
+                let new_values: Vec<f64> = values.iter().map(|&x| f64::from(u8::from(x))).collect();
+                Ok(Series::F64(name.to_string(), new_values, bitmap.clone()))
+            }
+            // I32 to Bool (non-zero is true)
+            (Series::I32(_, values, bitmap), DataType::Bool) => {
+                let new_values: Vec<bool> = values.iter().map(|&x| x != 0).collect();
+                Ok(Series::Bool(name.to_string(), new_values, bitmap.clone()))
+            }
+            // I64 to Bool (non-zero is true)
+            (Series::I64(_, values, bitmap), DataType::Bool) => {
+                let new_values: Vec<bool> = values.iter().map(|&x| x != 0).collect();
+                Ok(Series::Bool(name.to_string(), new_values, bitmap.clone()))
+            }
+            // F64 to Bool (non-zero is true, NaN becomes null)
+            (Series::F64(_, values, bitmap), DataType::Bool) => {
+                let (new_values, new_bitmap): (Vec<bool>, Vec<bool>) = values
+                    .iter()
+                    .zip(bitmap.iter())
+                    .map(|(&x, &valid)| {
+                        if valid && !x.is_nan() {
+                            (x != 0.0, true)
+                        } else {
+                            (false, false)
+                        }
+                    })
+                    .unzip();
+                Ok(Series::Bool(name.to_string(), new_values, new_bitmap))
             }
             // String to any numeric type (try parsing)
             (Series::String(_, values, bitmap), DataType::F64) => {
@@ -1168,6 +1904,10 @@ impl Series {
                 }
                 Ok(Series::F64(name.to_string(), new_values, new_bitmap))
             }
+            // String to DateTime (ISO 8601 dates and date-times)
+            (Series::String(_, _, _), DataType::DateTime) => {
+                self.parse_datetime_multi(&["%Y-%m-%dT%H:%M:%S", "%Y-%m-%d %H:%M:%S", "%Y-%m-%d"])
+            }
             // Same type - just clone
             (_, target_type) if self.data_type() == target_type => Ok(self.clone()),
             // Unsupported conversion
@@ -1179,6 +1919,36 @@ impl Series {
         }
     }
 
+    /// Casts the series like [`Series::cast`], also reporting which rows lost information.
+    ///
+    /// The returned indices are the rows whose non-null value became null (unparseable
+    /// strings, NaN or out-of-range floats) or changed value (F64 to I32 with a fractional
+    /// part), so callers can reject or inspect lossy conversions instead of trusting them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::series::Series;
+    /// use veloxx::types::DataType;
+    ///
+    /// let amounts = Series::new_f64("amount", vec![Some(3.0), Some(3.9), None, Some(1e12)]);
+    /// let (cast, lossy_rows) = amounts.try_cast(DataType::I32).unwrap();
+    /// assert_eq!(cast.get_data_i32().unwrap(), vec![Some(3), Some(3), None, None]);
+    /// assert_eq!(lossy_rows, vec![1, 3]);
+    /// ```
+    pub fn try_cast(&self, to_type: DataType) -> Result<(Series, Vec<usize>), VeloxxError> {
+        let cast = self.cast(to_type)?;
+        let lossy_rows = (0..self.len())
+            .filter(|&i| match (self.get_value(i), cast.get_value(i)) {
+                (Some(_), None) => true,
+                (Some(Value::F64(before)), Some(Value::I32(after))) => before != after as f64,
+                (Some(Value::F64(before)), Some(Value::I64(after))) => before != after as f64,
+                _ => false,
+            })
+            .collect();
+        Ok((cast, lossy_rows))
+    }
+
     /// Calculate correlation between two numeric series
     pub fn correlation(&self, other: &Series) -> Result<Option<f64>, VeloxxError> {
         // Both series must be numeric and same length
@@ -1276,6 +2046,89 @@ impl Series {
         Ok(unique_series.len())
     }
 
+    /// Assigns every value to one of `n_buckets` buckets by a stable hash of the value.
+    ///
+    /// The hash (FNV-1a over the value's type and bytes, as used by
+    /// [`DataFrame::row_hashes`](crate::dataframe::DataFrame::row_hashes)) is not randomized,
+    /// so a value lands in the same bucket on every run, which makes the buckets suitable
+    /// for reproducible A/B assignment or sharding. The result is an I32 series with the same
+    /// name holding buckets in `0..n_buckets`; nulls stay null.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the bucket series, or `VeloxxError::InvalidOperation` if
+    /// `n_buckets` is zero or larger than `i32::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::series::Series;
+    ///
+    /// let users = Series::new_i64("user_id", vec![Some(42), Some(7), Some(42), None]);
+    /// let buckets = users.hash_bucket(2).unwrap();
+    /// assert_eq!(buckets.get_value(0), buckets.get_value(2));
+    /// assert_eq!(buckets.get_value(3), None);
+    /// ```
+    pub fn hash_bucket(&self, n_buckets: usize) -> Result<Series, VeloxxError> {
+        if n_buckets == 0 || n_buckets > i32::MAX as usize {
+            return Err(VeloxxError::InvalidOperation(format!(
+                "hash_bucket needs between 1 and {} buckets, got {}",
+                i32::MAX,
+                n_buckets
+            )));
+        }
+        let buckets = (0..self.len())
+            .map(|i| {
+                self.get_value(i).map(|value| {
+                    (crate::dataframe::manipulation::stable_hash(&value) % n_buckets as u64) as i32
+                })
+            })
+            .collect();
+        Ok(Series::new_i32(self.name(), buckets))
+    }
+
+    /// Returns the sub-range of `length` elements starting at `offset`.
+    ///
+    /// The range is clamped to the bounds of the series: an `offset` at or past the end
+    /// yields an empty series rather than an error, and a `length` reaching past the end
+    /// stops at the last element. The slice keeps the series name and type.
+    pub fn slice(&self, offset: usize, length: usize) -> Result<Self, VeloxxError> {
+        let start = offset.min(self.len());
+        let end = start.saturating_add(length).min(self.len());
+        Ok(match self {
+            Series::I32(name, values, bitmap) => Series::I32(
+                name.clone(),
+                values[start..end].to_vec(),
+                bitmap[start..end].to_vec(),
+            ),
+            Series::I64(name, values, bitmap) => Series::I64(
+                name.clone(),
+                values[start..end].to_vec(),
+                bitmap[start..end].to_vec(),
+            ),
+            Series::F64(name, values, bitmap) => Series::F64(
+                name.clone(),
+                values[start..end].to_vec(),
+                bitmap[start..end].to_vec(),
+            ),
+            Series::Bool(name, values, bitmap) => Series::Bool(
+                name.clone(),
+                values[start..end].to_vec(),
+                bitmap[start..end].to_vec(),
+            ),
+            Series::String(name, values, bitmap) => Series::String(
+                name.clone(),
+                values[start..end].to_vec(),
+                bitmap[start..end].to_vec(),
+            ),
+            Series::DateTime(name, values, bitmap) => Series::DateTime(
+                name.clone(),
+                values[start..end].to_vec(),
+                bitmap[start..end].to_vec(),
+            ),
+        })
+    }
+
     pub fn filter(&self, indices: &[usize]) -> Result<Self, VeloxxError> {
         match self {
             Series::I32(name, values, bitmap) => {
@@ -1294,6 +2147,22 @@ impl Series {
                 }
                 Ok(Series::I32(name.clone(), new_values, new_bitmap))
             }
+            Series::I64(name, values, bitmap) => {
+                let mut new_values = Vec::with_capacity(indices.len());
+                let mut new_bitmap = Vec::with_capacity(indices.len());
+                for &idx in indices {
+                    if idx < values.len() {
+                        new_values.push(values[idx]);
+                        new_bitmap.push(bitmap[idx]);
+                    } else {
+                        return Err(VeloxxError::InvalidOperation(format!(
+                            "Index {} out of bounds",
+                            idx
+                        )));
+                    }
+                }
+                Ok(Series::I64(name.clone(), new_values, new_bitmap))
+            }
             Series::F64(name, values, bitmap) => {
                 let mut new_values = Vec::with_capacity(indices.len());
                 let mut new_bitmap = Vec::with_capacity(indices.len());
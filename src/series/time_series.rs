@@ -4,143 +4,149 @@ use crate::VeloxxError;
 impl Series {
     /// Calculates a rolling mean (moving average) over a specified window size.
     ///
-    /// This function computes the mean of values within a sliding window of the specified size.
-    /// For numeric series (I32, F64), it returns a new F64 series with the rolling means.
-    /// For non-numeric series, it returns an error.
+    /// Each position averages the non-null values among itself and the `window_size - 1`
+    /// values before it. Nulls inside a window are skipped in both the sum and the count, and
+    /// a position is null unless its window holds at least `min_periods` non-null values, so
+    /// `min_periods == window_size` nulls the first `window_size - 1` rows while
+    /// `min_periods == 1` produces a value from the first row. I32, I64, F64 and DateTime
+    /// series are supported and always produce an F64 series.
     ///
     /// # Arguments
     ///
     /// * `window_size` - The size of the rolling window. Must be greater than 0.
+    /// * `min_periods` - The minimum number of non-null values a window needs to produce a
+    ///   result. Must be between 1 and `window_size`.
     ///
     /// # Returns
     ///
     /// A `Result` containing a new `Series` with rolling means, or a `VeloxxError` if:
     /// - The window size is 0 or greater than the series length
-    /// - The series contains non-numeric data
+    /// - `min_periods` is 0 or greater than the window size
+    /// - The series is not numeric or DateTime (`VeloxxError::Unsupported`)
     ///
     /// # Examples
     ///
     /// ```rust
     /// use veloxx::series::Series;
+    /// use veloxx::types::Value;
     ///
     /// let series = Series::new_f64("values", vec![Some(1.0), Some(2.0), Some(3.0), Some(4.0), Some(5.0)]);
-    /// let rolling_mean = series.rolling_mean(3).unwrap();
-    /// // Result: [None, None, Some(2.0), Some(3.0), Some(4.0)]
+    /// let rolling_mean = series.rolling_mean(3, 3).unwrap();
+    /// assert_eq!(rolling_mean.get_value(1), None);
+    /// assert_eq!(rolling_mean.get_value(2), Some(Value::F64(2.0)));
+    ///
+    /// let eager = series.rolling_mean(3, 1).unwrap();
+    /// assert_eq!(eager.get_value(1), Some(Value::F64(1.5)));
     /// ```
-    pub fn rolling_mean(&self, window_size: usize) -> Result<Series, VeloxxError> {
-        if window_size == 0 {
-            return Err(VeloxxError::InvalidOperation(
-                "Window size must be greater than 0".to_string(),
-            ));
-        }
-
-        if window_size > self.len() {
-            return Err(VeloxxError::InvalidOperation(
-                "Window size cannot be greater than series length".to_string(),
-            ));
-        }
-
-        let new_name = format!("{}_rolling_mean_{}", self.name(), window_size);
-
-        match self {
-            Series::I32(_name, data, validity) => {
-                let mut result = Vec::with_capacity(data.len());
-
-                for i in 0..data.len() {
-                    if i < window_size - 1 {
-                        result.push(None);
-                    } else {
-                        let window_start = i + 1 - window_size;
-                        // Collect only valid values in the window
-                        let mut valid_values = Vec::new();
-                        for j in window_start..=i {
-                            if validity[j] {
-                                valid_values.push(data[j]);
-                            }
-                        }
-
-                        if valid_values.is_empty() {
-                            result.push(None);
-                        } else {
-                            let sum: i32 = valid_values.iter().sum();
-                            let mean = sum as f64 / valid_values.len() as f64;
-                            result.push(Some(mean));
-                        }
-                    }
-                }
-
-                let result_validity: Vec<bool> = result.iter().map(|x| x.is_some()).collect();
-                let result_values: Vec<f64> =
-                    result.into_iter().map(|x| x.unwrap_or(0.0)).collect();
-                Ok(Series::F64(
-                    new_name.clone(),
-                    result_values,
-                    result_validity,
-                ))
-            }
-            Series::F64(_name, data, validity) => {
-                let mut result = Vec::with_capacity(data.len());
-
-                for i in 0..data.len() {
-                    if i < window_size - 1 {
-                        result.push(None);
-                    } else {
-                        let window_start = i + 1 - window_size;
-                        // Collect only valid values in the window
-                        let mut valid_values = Vec::new();
-                        for j in window_start..=i {
-                            if validity[j] {
-                                valid_values.push(data[j]);
-                            }
-                        }
-
-                        if valid_values.is_empty() {
-                            result.push(None);
-                        } else {
-                            let sum: f64 = valid_values.iter().sum();
-                            let mean = sum / valid_values.len() as f64;
-                            result.push(Some(mean));
-                        }
-                    }
-                }
-
-                let result_validity: Vec<bool> = result.iter().map(|x| x.is_some()).collect();
-                let result_values: Vec<f64> =
-                    result.into_iter().map(|x| x.unwrap_or(0.0)).collect();
-                Ok(Series::F64(new_name, result_values, result_validity))
-            }
-            _ => Err(VeloxxError::InvalidOperation(
-                "Rolling mean is only supported for numeric series (I32, F64)".to_string(),
-            )),
-        }
+    pub fn rolling_mean(
+        &self,
+        window_size: usize,
+        min_periods: usize,
+    ) -> Result<Series, VeloxxError> {
+        let windows = self.rolling_sums_and_counts(window_size, min_periods, "Rolling mean")?;
+        let result: Vec<Option<f64>> = windows
+            .into_iter()
+            .map(|window| window.map(|(sum, count)| sum / count as f64))
+            .collect();
+        Ok(Series::new_f64(
+            &format!("{}_rolling_mean_{}", self.name(), window_size),
+            result,
+        ))
     }
 
     /// Calculates a rolling sum over a specified window size.
     ///
-    /// This function computes the sum of values within a sliding window of the specified size.
-    /// For numeric series (I32, F64), it returns a new series of the same type with the rolling sums.
-    /// For non-numeric series, it returns an error.
+    /// Each position sums the non-null values among itself and the `window_size - 1` values
+    /// before it. Nulls inside a window are skipped, and a position is null unless its window
+    /// holds at least `min_periods` non-null values. I32, I64, F64 and DateTime series are
+    /// supported and always produce an F64 series.
     ///
     /// # Arguments
     ///
     /// * `window_size` - The size of the rolling window. Must be greater than 0.
+    /// * `min_periods` - The minimum number of non-null values a window needs to produce a
+    ///   result. Must be between 1 and `window_size`.
     ///
     /// # Returns
     ///
     /// A `Result` containing a new `Series` with rolling sums, or a `VeloxxError` if:
     /// - The window size is 0 or greater than the series length
-    /// - The series contains non-numeric data
+    /// - `min_periods` is 0 or greater than the window size
+    /// - The series is not numeric or DateTime (`VeloxxError::Unsupported`)
     ///
     /// # Examples
     ///
     /// ```rust
     /// use veloxx::series::Series;
+    /// use veloxx::types::Value;
     ///
     /// let series = Series::new_i32("values", vec![Some(1), Some(2), Some(3), Some(4), Some(5)]);
-    /// let rolling_sum = series.rolling_sum(3).unwrap();
-    /// // Result: [None, None, Some(6), Some(9), Some(12)]
+    /// let rolling_sum = series.rolling_sum(3, 3).unwrap();
+    /// assert_eq!(rolling_sum.get_value(1), None);
+    /// assert_eq!(rolling_sum.get_value(4), Some(Value::F64(12.0)));
     /// ```
-    pub fn rolling_sum(&self, window_size: usize) -> Result<Series, VeloxxError> {
+    pub fn rolling_sum(
+        &self,
+        window_size: usize,
+        min_periods: usize,
+    ) -> Result<Series, VeloxxError> {
+        let windows = self.rolling_sums_and_counts(window_size, min_periods, "Rolling sum")?;
+        let result: Vec<Option<f64>> = windows
+            .into_iter()
+            .map(|window| window.map(|(sum, _)| sum))
+            .collect();
+        Ok(Series::new_f64(
+            &format!("{}_rolling_sum_{}", self.name(), window_size),
+            result,
+        ))
+    }
+
+    /// Returns the sum and count of the non-null values in each trailing window, or `None`
+    /// where the window holds fewer than `min_periods` of them.
+    fn rolling_sums_and_counts(
+        &self,
+        window_size: usize,
+        min_periods: usize,
+        operation: &str,
+    ) -> Result<Vec<Option<(f64, usize)>>, VeloxxError> {
+        self.check_rolling_window(window_size, min_periods)?;
+
+        let values: Vec<Option<f64>> = match self {
+            Series::I32(..) | Series::I64(..) | Series::F64(..) => {
+                (0..self.len()).map(|i| self.get_numeric_f64(i)).collect()
+            }
+            Series::DateTime(_, data, validity) => data
+                .iter()
+                .zip(validity.iter())
+                .map(|(&v, &b)| if b { Some(v as f64) } else { None })
+                .collect(),
+            _ => {
+                return Err(VeloxxError::Unsupported(format!(
+                    "{} is only supported for numeric and DateTime series",
+                    operation
+                )))
+            }
+        };
+
+        let result = (0..values.len())
+            .map(|i| {
+                let window = &values[(i + 1).saturating_sub(window_size)..=i];
+                let (sum, count) = window
+                    .iter()
+                    .flatten()
+                    .fold((0.0, 0), |(sum, count), &v| (sum + v, count + 1));
+                (count >= min_periods).then_some((sum, count))
+            })
+            .collect();
+        Ok(result)
+    }
+
+    /// Checks that `window_size` fits the series and `min_periods` fits the window.
+    fn check_rolling_window(
+        &self,
+        window_size: usize,
+        min_periods: usize,
+    ) -> Result<(), VeloxxError> {
         if window_size == 0 {
             return Err(VeloxxError::InvalidOperation(
                 "Window size must be greater than 0".to_string(),
@@ -153,75 +159,33 @@ impl Series {
             ));
         }
 
-        let name = format!("{}_rolling_sum_{}", self.name(), window_size);
-
-        match self {
-            Series::I32(_, data, _) => {
-                let mut result = Vec::with_capacity(data.len());
-
-                for i in 0..data.len() {
-                    if i < window_size - 1 {
-                        result.push(None);
-                    } else {
-                        let window_start = i + 1 - window_size;
-                        let window_data: Vec<i32> = data[window_start..=i].to_vec();
-
-                        if window_data.is_empty() {
-                            result.push(None);
-                        } else {
-                            let sum: i32 = window_data.iter().sum();
-                            result.push(Some(sum));
-                        }
-                    }
-                }
-
-                let validity: Vec<bool> = result.iter().map(|x| x.is_some()).collect();
-                let values: Vec<i32> = result.into_iter().map(|x| x.unwrap_or(0)).collect();
-                Ok(Series::I32(name, values, validity))
-            }
-            Series::F64(_, data, _) => {
-                let mut result = Vec::with_capacity(data.len());
-
-                for i in 0..data.len() {
-                    if i < window_size - 1 {
-                        result.push(None);
-                    } else {
-                        let window_start = i + 1 - window_size;
-                        let window_data: Vec<f64> = data[window_start..=i].to_vec();
-
-                        if window_data.is_empty() {
-                            result.push(None);
-                        } else {
-                            let sum: f64 = window_data.iter().sum();
-                            result.push(Some(sum));
-                        }
-                    }
-                }
-
-                let validity: Vec<bool> = result.iter().map(|x| x.is_some()).collect();
-                let values: Vec<f64> = result.into_iter().map(|x| x.unwrap_or(0.0)).collect();
-                Ok(Series::F64(name, values, validity))
-            }
-            _ => Err(VeloxxError::InvalidOperation(
-                "Rolling sum is only supported for numeric series (I32, F64)".to_string(),
-            )),
+        if min_periods == 0 || min_periods > window_size {
+            return Err(VeloxxError::InvalidOperation(format!(
+                "min_periods must be between 1 and the window size ({}), got {}",
+                window_size, min_periods
+            )));
         }
+        Ok(())
     }
 
     /// Calculates a rolling minimum over a specified window size.
     ///
-    /// This function finds the minimum value within a sliding window of the specified size.
-    /// For numeric series (I32, F64), it returns a new series of the same type with the rolling minimums.
-    /// For non-numeric series, it returns an error.
+    /// Each position takes the minimum of the non-null values among itself and the
+    /// `window_size - 1` values before it. Nulls inside a window are skipped, and a position is
+    /// null unless its window holds at least `min_periods` non-null values. I32, I64 and F64
+    /// series are supported and keep their type.
     ///
     /// # Arguments
     ///
     /// * `window_size` - The size of the rolling window. Must be greater than 0.
+    /// * `min_periods` - The minimum number of non-null values a window needs to produce a
+    ///   result. Must be between 1 and `window_size`.
     ///
     /// # Returns
     ///
     /// A `Result` containing a new `Series` with rolling minimums, or a `VeloxxError` if:
     /// - The window size is 0 or greater than the series length
+    /// - `min_periods` is 0 or greater than the window size
     /// - The series contains non-numeric data
     ///
     /// # Examples
@@ -230,91 +194,57 @@ impl Series {
     /// use veloxx::series::Series;
     ///
     /// let series = Series::new_i32("values", vec![Some(5), Some(2), Some(8), Some(1), Some(9)]);
-    /// let rolling_min = series.rolling_min(3).unwrap();
-    /// // Result: [None, None, Some(2), Some(1), Some(1)]
+    /// let rolling_min = series.rolling_min(3, 3).unwrap();
+    /// assert_eq!(rolling_min.get_data_i32().unwrap(), vec![None, None, Some(2), Some(1), Some(1)]);
+    ///
+    /// let eager = series.rolling_min(3, 1).unwrap();
+    /// assert_eq!(eager.get_data_i32().unwrap(), vec![Some(5), Some(2), Some(2), Some(1), Some(1)]);
     /// ```
-    pub fn rolling_min(&self, window_size: usize) -> Result<Series, VeloxxError> {
-        if window_size == 0 {
-            return Err(VeloxxError::InvalidOperation(
-                "Window size must be greater than 0".to_string(),
-            ));
-        }
-
-        if window_size > self.len() {
-            return Err(VeloxxError::InvalidOperation(
-                "Window size cannot be greater than series length".to_string(),
-            ));
-        }
-
+    pub fn rolling_min(
+        &self,
+        window_size: usize,
+        min_periods: usize,
+    ) -> Result<Series, VeloxxError> {
+        self.check_rolling_window(window_size, min_periods)?;
         let name = format!("{}_rolling_min_{}", self.name(), window_size);
 
         match self {
-            Series::I32(_, data, _) => {
-                let mut result = Vec::with_capacity(data.len());
-
-                for i in 0..data.len() {
-                    if i < window_size - 1 {
-                        result.push(None);
-                    } else {
-                        let window_start = i + 1 - window_size;
-                        let window_data: Vec<i32> = data[window_start..=i].to_vec();
-
-                        if window_data.is_empty() {
-                            result.push(None);
-                        } else {
-                            let min = *window_data.iter().min().unwrap();
-                            result.push(Some(min));
-                        }
-                    }
-                }
-
-                let validity: Vec<bool> = result.iter().map(|x| x.is_some()).collect();
-                let values: Vec<i32> = result.into_iter().map(|x| x.unwrap_or(0)).collect();
-                Ok(Series::I32(name, values, validity))
-            }
-            Series::F64(_, data, _) => {
-                let mut result = Vec::with_capacity(data.len());
-
-                for i in 0..data.len() {
-                    if i < window_size - 1 {
-                        result.push(None);
-                    } else {
-                        let window_start = i + 1 - window_size;
-                        let window_data: Vec<f64> = data[window_start..=i].to_vec();
-
-                        if window_data.is_empty() {
-                            result.push(None);
-                        } else {
-                            let min = window_data.iter().fold(f64::INFINITY, |a, &b| a.min(b));
-                            result.push(Some(min));
-                        }
-                    }
-                }
-
-                let validity: Vec<bool> = result.iter().map(|x| x.is_some()).collect();
-                let values: Vec<f64> = result.into_iter().map(|x| x.unwrap_or(0.0)).collect();
-                Ok(Series::F64(name, values, validity))
-            }
+            Series::I32(_, data, validity) => Ok(Series::new_i32(
+                &name,
+                rolling_fold(data, validity, window_size, min_periods, i32::min),
+            )),
+            Series::I64(_, data, validity) => Ok(Series::new_i64(
+                &name,
+                rolling_fold(data, validity, window_size, min_periods, i64::min),
+            )),
+            Series::F64(_, data, validity) => Ok(Series::new_f64(
+                &name,
+                rolling_fold(data, validity, window_size, min_periods, f64::min),
+            )),
             _ => Err(VeloxxError::InvalidOperation(
-                "Rolling min is only supported for numeric series (I32, F64)".to_string(),
+                "Rolling min is only supported for numeric series (I32, I64, F64)".to_string(),
             )),
         }
     }
 
     /// Calculates a rolling maximum over a specified window size.
     ///
-    /// This function finds the maximum value within a sliding window of the specified size.
-    /// For numeric series (I32, F64), it returns a new series of the same type with the rolling maximums.
-    /// For non-numeric series, it returns an error.
+    /// Each position takes the maximum of the non-null values among itself and the
+    /// `window_size - 1` values before it. Nulls inside a window are skipped, and a position is
+    /// null unless its window holds at least `min_periods` non-null values. I32, I64 and F64
+    /// series are supported and keep their type.
     ///
     /// # Arguments
     ///
     /// * `window_size` - The size of the rolling window. Must be greater than 0.
+    /// * `min_periods` - The minimum number of non-null values a window needs to produce a
+    ///   result. Must be between 1 and `window_size`.
     ///
     /// # Returns
     ///
     /// A `Result` containing a new `Series` with rolling maximums, or a `VeloxxError` if:
     /// - The window size is 0 or greater than the series length
+    /// - `min_periods` is 0 or greater than the window size
     /// - The series contains non-numeric data
     ///
     /// # Examples
@@ -323,91 +253,54 @@ impl Series {
     /// use veloxx::series::Series;
     ///
     /// let series = Series::new_i32("values", vec![Some(5), Some(2), Some(8), Some(1), Some(9)]);
-    /// let rolling_max = series.rolling_max(3).unwrap();
-    /// // Result: [None, None, Some(8), Some(8), Some(9)]
+    /// let rolling_max = series.rolling_max(3, 3).unwrap();
+    /// assert_eq!(rolling_max.get_data_i32().unwrap(), vec![None, None, Some(8), Some(8), Some(9)]);
     /// ```
-    pub fn rolling_max(&self, window_size: usize) -> Result<Series, VeloxxError> {
-        if window_size == 0 {
-            return Err(VeloxxError::InvalidOperation(
-                "Window size must be greater than 0".to_string(),
-            ));
-        }
-
-        if window_size > self.len() {
-            return Err(VeloxxError::InvalidOperation(
-                "Window size cannot be greater than series length".to_string(),
-            ));
-        }
-
+    pub fn rolling_max(
+        &self,
+        window_size: usize,
+        min_periods: usize,
+    ) -> Result<Series, VeloxxError> {
+        self.check_rolling_window(window_size, min_periods)?;
         let name = format!("{}_rolling_max_{}", self.name(), window_size);
 
         match self {
-            Series::I32(_, data, _) => {
-                let mut result = Vec::with_capacity(data.len());
-
-                for i in 0..data.len() {
-                    if i < window_size - 1 {
-                        result.push(None);
-                    } else {
-                        let window_start = i + 1 - window_size;
-                        let window_data: Vec<i32> = data[window_start..=i].to_vec();
-
-                        if window_data.is_empty() {
-                            result.push(None);
-                        } else {
-                            let max = *window_data.iter().max().unwrap();
-                            result.push(Some(max));
-                        }
-                    }
-                }
-
-                let validity: Vec<bool> = result.iter().map(|x| x.is_some()).collect();
-                let values: Vec<i32> = result.into_iter().map(|x| x.unwrap_or(0)).collect();
-                Ok(Series::I32(name, values, validity))
-            }
-            Series::F64(_, data, _) => {
-                let mut result = Vec::with_capacity(data.len());
-
-                for i in 0..data.len() {
-                    if i < window_size - 1 {
-                        result.push(None);
-                    } else {
-                        let window_start = i + 1 - window_size;
-                        let window_data: Vec<f64> = data[window_start..=i].to_vec();
-
-                        if window_data.is_empty() {
-                            result.push(None);
-                        } else {
-                            let max = window_data.iter().fold(f64::NEG_INFINITY, |a, &b| a.max(b));
-                            result.push(Some(max));
-                        }
-                    }
-                }
-
-                let validity: Vec<bool> = result.iter().map(|x| x.is_some()).collect();
-                let values: Vec<f64> = result.into_iter().map(|x| x.unwrap_or(0.0)).collect();
-                Ok(Series::F64(name, values, validity))
-            }
+            Series::I32(_, data, validity) => Ok(Series::new_i32(
+                &name,
+                rolling_fold(data, validity, window_size, min_periods, i32::max),
+            )),
+            Series::I64(_, data, validity) => Ok(Series::new_i64(
+                &name,
+                rolling_fold(data, validity, window_size, min_periods, i64::max),
+            )),
+            Series::F64(_, data, validity) => Ok(Series::new_f64(
+                &name,
+                rolling_fold(data, validity, window_size, min_periods, f64::max),
+            )),
             _ => Err(VeloxxError::InvalidOperation(
-                "Rolling max is only supported for numeric series (I32, F64)".to_string(),
+                "Rolling max is only supported for numeric series (I32, I64, F64)".to_string(),
             )),
         }
     }
 
-    /// Calculates a rolling standard deviation over a specified window size.
+    /// Calculates a rolling sample standard deviation over a specified window size.
     ///
-    /// This function computes the standard deviation of values within a sliding window of the specified size.
-    /// For numeric series (I32, F64), it returns a new F64 series with the rolling standard deviations.
-    /// For non-numeric series, it returns an error.
+    /// Each position takes the standard deviation of the non-null values among itself and the
+    /// `window_size - 1` values before it. Nulls inside a window are skipped, and a position is
+    /// null unless its window holds at least `min_periods` non-null values, and always when it
+    /// holds fewer than 2. I32, I64 and F64 series are supported and produce an F64 series.
     ///
     /// # Arguments
     ///
     /// * `window_size` - The size of the rolling window. Must be greater than 1.
+    /// * `min_periods` - The minimum number of non-null values a window needs to produce a
+    ///   result. Must be between 1 and `window_size`.
     ///
     /// # Returns
     ///
     /// A `Result` containing a new `Series` with rolling standard deviations, or a `VeloxxError` if:
     /// - The window size is less than 2 or greater than the series length
+    /// - `min_periods` is 0 or greater than the window size
     /// - The series contains non-numeric data
     ///
     /// # Examples
@@ -416,87 +309,112 @@ impl Series {
     /// use veloxx::series::Series;
     ///
     /// let series = Series::new_f64("values", vec![Some(1.0), Some(2.0), Some(3.0), Some(4.0), Some(5.0)]);
-    /// let rolling_std = series.rolling_std(3).unwrap();
+    /// let rolling_std = series.rolling_std(3, 3).unwrap();
+    /// assert_eq!(rolling_std.get_value(1), None);
+    ///
+    /// // Two values are enough for a standard deviation.
+    /// let eager = series.rolling_std(3, 2).unwrap();
+    /// assert!(eager.get_value(1).is_some());
     /// ```
-    pub fn rolling_std(&self, window_size: usize) -> Result<Series, VeloxxError> {
+    pub fn rolling_std(
+        &self,
+        window_size: usize,
+        min_periods: usize,
+    ) -> Result<Series, VeloxxError> {
         if window_size < 2 {
             return Err(VeloxxError::InvalidOperation(
                 "Window size must be at least 2 for standard deviation".to_string(),
             ));
         }
-
-        if window_size > self.len() {
+        self.check_rolling_window(window_size, min_periods)?;
+        if !matches!(self, Series::I32(..) | Series::I64(..) | Series::F64(..)) {
             return Err(VeloxxError::InvalidOperation(
-                "Window size cannot be greater than series length".to_string(),
+                "Rolling standard deviation is only supported for numeric series (I32, I64, F64)"
+                    .to_string(),
             ));
         }
 
-        let name = format!("{}_rolling_std_{}", self.name(), window_size);
-
-        match self {
-            Series::I32(_, data, _) => {
-                let mut result = Vec::with_capacity(data.len());
-
-                for i in 0..data.len() {
-                    if i < window_size - 1 {
-                        result.push(None);
-                    } else {
-                        let window_start = i + 1 - window_size;
-                        let window_data: Vec<f64> = data[window_start..=i]
-                            .iter()
-                            .copied()
-                            .map(|v| v as f64)
-                            .collect();
-
-                        if window_data.len() < 2 {
-                            result.push(None);
-                        } else {
-                            let mean = window_data.iter().sum::<f64>() / window_data.len() as f64;
-                            let variance =
-                                window_data.iter().map(|&x| (x - mean).powi(2)).sum::<f64>()
-                                    / (window_data.len() - 1) as f64;
-                            let std_dev = variance.sqrt();
-                            result.push(Some(std_dev));
-                        }
-                    }
+        let values: Vec<Option<f64>> = (0..self.len()).map(|i| self.get_numeric_f64(i)).collect();
+        let result: Vec<Option<f64>> = (0..values.len())
+            .map(|i| {
+                let window: Vec<f64> = values[(i + 1).saturating_sub(window_size)..=i]
+                    .iter()
+                    .flatten()
+                    .copied()
+                    .collect();
+                if window.len() < min_periods.max(2) {
+                    return None;
                 }
+                let mean = window.iter().sum::<f64>() / window.len() as f64;
+                let variance = window.iter().map(|&x| (x - mean).powi(2)).sum::<f64>()
+                    / (window.len() - 1) as f64;
+                Some(variance.sqrt())
+            })
+            .collect();
+        Ok(Series::new_f64(
+            &format!("{}_rolling_std_{}", self.name(), window_size),
+            result,
+        ))
+    }
 
-                let validity: Vec<bool> = result.iter().map(|x| x.is_some()).collect();
-                let values: Vec<f64> = result.into_iter().map(|x| x.unwrap_or(0.0)).collect();
-                Ok(Series::F64(name, values, validity))
-            }
-            Series::F64(_, data, _) => {
-                let mut result = Vec::with_capacity(data.len());
+    /// Applies a custom function to each trailing window of `window_size` values.
+    ///
+    /// For every position from `window_size - 1` onwards, `f` receives the window ending at
+    /// that position, oldest value first, with nulls passed as `None`, and its result becomes
+    /// the output value. Earlier positions, which have no full window, are null. I32, I64 and
+    /// F64 series are supported and produce an F64 series named
+    /// `{name}_rolling_apply_{window_size}`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the new series, or a `VeloxxError` if:
+    /// - The window size is 0 or greater than the series length
+    /// - The series is not numeric (`VeloxxError::Unsupported`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::series::Series;
+    /// use veloxx::types::Value;
+    ///
+    /// let prices = Series::new_f64("price", vec![Some(3.0), Some(7.0), Some(5.0), None]);
+    /// let range = prices
+    ///     .rolling_apply(2, |window| {
+    ///         let (a, b) = (window[0]?, window[1]?);
+    ///         Some((a - b).abs())
+    ///     })
+    ///     .unwrap();
+    /// assert_eq!(range.get_value(0), None);
+    /// assert_eq!(range.get_value(1), Some(Value::F64(4.0)));
+    /// assert_eq!(range.get_value(2), Some(Value::F64(2.0)));
+    /// assert_eq!(range.get_value(3), None);
+    /// ```
+    pub fn rolling_apply(
+        &self,
+        window_size: usize,
+        f: impl Fn(&[Option<f64>]) -> Option<f64>,
+    ) -> Result<Series, VeloxxError> {
+        self.check_rolling_window(window_size, window_size)?;
+        if !self.is_numeric() {
+            return Err(VeloxxError::Unsupported(
+                "Rolling apply is only supported for numeric series".to_string(),
+            ));
+        }
 
-                for i in 0..data.len() {
-                    if i < window_size - 1 {
-                        result.push(None);
-                    } else {
-                        let window_start = i + 1 - window_size;
-                        let window_data: Vec<f64> = data[window_start..=i].to_vec();
-
-                        if window_data.len() < 2 {
-                            result.push(None);
-                        } else {
-                            let mean = window_data.iter().sum::<f64>() / window_data.len() as f64;
-                            let variance =
-                                window_data.iter().map(|&x| (x - mean).powi(2)).sum::<f64>()
-                                    / (window_data.len() - 1) as f64;
-                            let std_dev = variance.sqrt();
-                            result.push(Some(std_dev));
-                        }
-                    }
+        let values: Vec<Option<f64>> = (0..self.len()).map(|i| self.get_numeric_f64(i)).collect();
+        let result: Vec<Option<f64>> = (0..values.len())
+            .map(|i| {
+                if i + 1 < window_size {
+                    None
+                } else {
+                    f(&values[i + 1 - window_size..=i])
                 }
-
-                let validity: Vec<bool> = result.iter().map(|x| x.is_some()).collect();
-                let values: Vec<f64> = result.into_iter().map(|x| x.unwrap_or(0.0)).collect();
-                Ok(Series::F64(name, values, validity))
-            }
-            _ => Err(VeloxxError::InvalidOperation(
-                "Rolling standard deviation is only supported for numeric series (I32, F64)"
-                    .to_string(),
-            )),
-        }
+            })
+            .collect();
+        Ok(Series::new_f64(
+            &format!("{}_rolling_apply_{}", self.name(), window_size),
+            result,
+        ))
     }
 
     /// Calculates percentage change between consecutive values.
@@ -616,16 +534,489 @@ impl Series {
             )),
         }
     }
+
+    /// Calculates the cumulative mean (running average) of a numeric series.
+    ///
+    /// Each position holds the mean of all non-null values up to and including it, as an F64
+    /// series named `{name}_cummean`. A null stays null in the result and is left out of the
+    /// running mean, which carries on unchanged to the next non-null value, so
+    /// `[2, 4, null, 6]` gives `[2.0, 3.0, null, 4.0]`. I32, I64 and F64 series are supported.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the F64 series of running means, or `VeloxxError::Unsupported`
+    /// if the series is not numeric.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::series::Series;
+    /// use veloxx::types::Value;
+    ///
+    /// let series = Series::new_i32("latency", vec![Some(2), Some(4), None, Some(6)]);
+    /// let cummean = series.cummean().unwrap();
+    /// assert_eq!(cummean.get_value(1), Some(Value::F64(3.0)));
+    /// assert_eq!(cummean.get_value(2), None);
+    /// assert_eq!(cummean.get_value(3), Some(Value::F64(4.0)));
+    /// ```
+    pub fn cummean(&self) -> Result<Series, VeloxxError> {
+        if !matches!(self, Series::I32(..) | Series::I64(..) | Series::F64(..)) {
+            return Err(VeloxxError::Unsupported(format!(
+                "Cumulative mean is only supported for numeric series (I32, I64, F64), not {:?}",
+                self.data_type()
+            )));
+        }
+        let mut sum = 0.0;
+        let mut count = 0usize;
+        let means: Vec<Option<f64>> = (0..self.len())
+            .map(|i| {
+                let value = self.get_numeric_f64(i)?;
+                sum += value;
+                count += 1;
+                Some(sum / count as f64)
+            })
+            .collect();
+        Ok(Series::new_f64(&format!("{}_cummean", self.name()), means))
+    }
+
+    /// Calculate the cumulative count of the series
+    ///
+    /// Each element receives its zero-based position, i.e. the number of elements that
+    /// precede it. Nulls are counted like any other element.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::series::Series;
+    ///
+    /// let series = Series::new_string("events", vec![Some("a".to_string()), None, Some("b".to_string())]);
+    /// let cumcount = series.cumcount();
+    /// // Result: [Some(0), Some(1), Some(2)]
+    /// ```
+    pub fn cumcount(&self) -> Series {
+        let name = format!("{}_cumcount", self.name());
+        Series::I32(
+            name,
+            (0..self.len() as i32).collect(),
+            vec![true; self.len()],
+        )
+    }
+
+    /// Calculates the difference between each value and the value `periods` positions earlier.
+    ///
+    /// Each element is `self[i] - self[i - periods]`; a negative `periods` compares with a later
+    /// value instead. Elements whose counterpart falls outside the series, or where either
+    /// value is null, are null, as is a result that overflows. I32, I64 and F64 series keep
+    /// their type. DateTime values are Unix timestamps in seconds, so a DateTime series
+    /// produces an I64 series of elapsed seconds, which can be aggregated directly, e.g. with
+    /// `mean` for the average gap between events. The result is named `{name}_diff`.
+    ///
+    /// # Arguments
+    ///
+    /// * `periods` - How many positions back to look (forward when negative).
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the series of differences, or `VeloxxError::Unsupported` for
+    /// Bool and String series.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::series::Series;
+    /// use veloxx::types::Value;
+    ///
+    /// let events = Series::new_datetime("at", vec![Some(1_000), Some(1_060), None, Some(1_300)]);
+    /// let gaps = events.diff(1).unwrap();
+    /// assert_eq!(gaps.get_value(0), None);
+    /// assert_eq!(gaps.get_value(1), Some(Value::I64(60)));
+    /// assert_eq!(gaps.get_value(2), None);
+    /// assert_eq!(gaps.get_value(3), None);
+    /// ```
+    pub fn diff(&self, periods: i64) -> Result<Series, VeloxxError> {
+        let name = format!("{}_diff", self.name());
+        match self {
+            Series::I32(_, data, validity) => Ok(Series::new_i32(
+                &name,
+                shifted_diff(data, validity, periods, |a, b| a.checked_sub(b)),
+            )),
+            Series::I64(_, data, validity) | Series::DateTime(_, data, validity) => {
+                Ok(Series::new_i64(
+                    &name,
+                    shifted_diff(data, validity, periods, |a, b| a.checked_sub(b)),
+                ))
+            }
+            Series::F64(_, data, validity) => Ok(Series::new_f64(
+                &name,
+                shifted_diff(data, validity, periods, |a, b| Some(a - b)),
+            )),
+            Series::Bool(..) | Series::String(..) => Err(VeloxxError::Unsupported(format!(
+                "diff is only supported for numeric and DateTime series, not {:?}",
+                self.data_type()
+            ))),
+        }
+    }
+
+    /// Delta-encodes an integer series: the first non-null value is kept and every later
+    /// non-null value is replaced by its difference from the previous non-null value.
+    ///
+    /// Sorted columns such as ids or timestamps turn into runs of small numbers, which
+    /// serialize far more compactly. Nulls stay null and are skipped when taking differences.
+    /// Arithmetic wraps on overflow, so [`Series::delta_decode`] always restores the original
+    /// values. The series keeps its name and type.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the encoded series, or `VeloxxError::Unsupported` for F64, Bool
+    /// and String series.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::series::Series;
+    /// use veloxx::types::Value;
+    ///
+    /// let ids = Series::new_i64("id", vec![Some(1_000), Some(1_001), None, Some(1_004)]);
+    /// let encoded = ids.delta_encode().unwrap();
+    /// assert_eq!(encoded.get_value(0), Some(Value::I64(1_000)));
+    /// assert_eq!(encoded.get_value(1), Some(Value::I64(1)));
+    /// assert_eq!(encoded.get_value(2), None);
+    /// assert_eq!(encoded.get_value(3), Some(Value::I64(3)));
+    /// assert_eq!(encoded.delta_decode().unwrap(), ids);
+    /// ```
+    pub fn delta_encode(&self) -> Result<Series, VeloxxError> {
+        match self {
+            Series::I32(name, data, validity) => Ok(Series::I32(
+                name.clone(),
+                delta_fold(data, validity, |value, prev| {
+                    (value.wrapping_sub(prev), value)
+                }),
+                validity.clone(),
+            )),
+            Series::I64(name, data, validity) => Ok(Series::I64(
+                name.clone(),
+                delta_fold(data, validity, |value, prev| {
+                    (value.wrapping_sub(prev), value)
+                }),
+                validity.clone(),
+            )),
+            Series::DateTime(name, data, validity) => Ok(Series::DateTime(
+                name.clone(),
+                delta_fold(data, validity, |value, prev| {
+                    (value.wrapping_sub(prev), value)
+                }),
+                validity.clone(),
+            )),
+            _ => Err(VeloxxError::Unsupported(format!(
+                "delta_encode is only supported for I32, I64 and DateTime series, not {:?}",
+                self.data_type()
+            ))),
+        }
+    }
+
+    /// Reverses [`Series::delta_encode`], turning a first value followed by differences back
+    /// into the original values.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the decoded series, or `VeloxxError::Unsupported` for F64, Bool
+    /// and String series.
+    pub fn delta_decode(&self) -> Result<Series, VeloxxError> {
+        match self {
+            Series::I32(name, data, validity) => Ok(Series::I32(
+                name.clone(),
+                delta_fold(data, validity, |delta, prev| {
+                    let value = prev.wrapping_add(delta);
+                    (value, value)
+                }),
+                validity.clone(),
+            )),
+            Series::I64(name, data, validity) => Ok(Series::I64(
+                name.clone(),
+                delta_fold(data, validity, |delta, prev| {
+                    let value = prev.wrapping_add(delta);
+                    (value, value)
+                }),
+                validity.clone(),
+            )),
+            Series::DateTime(name, data, validity) => Ok(Series::DateTime(
+                name.clone(),
+                delta_fold(data, validity, |delta, prev| {
+                    let value = prev.wrapping_add(delta);
+                    (value, value)
+                }),
+                validity.clone(),
+            )),
+            _ => Err(VeloxxError::Unsupported(format!(
+                "delta_decode is only supported for I32, I64 and DateTime series, not {:?}",
+                self.data_type()
+            ))),
+        }
+    }
+
+    /// Parses a String series into a DateTime series of Unix timestamps in seconds.
+    ///
+    /// `format` supports the specifiers `%Y` (year), `%m` (month), `%d` (day), `%H` (hour),
+    /// `%M` (minute), `%S` (second) and `%%` (a literal `%`); every other character must
+    /// match literally. Cells are trimmed before parsing and must match the whole format.
+    /// Cells that do not parse, and null cells, become null.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::series::Series;
+    /// use veloxx::types::Value;
+    ///
+    /// let series = Series::new_string("day", vec![Some("2024-01-02".to_string()), Some("n/a".to_string())]);
+    /// let parsed = series.parse_datetime("%Y-%m-%d").unwrap();
+    /// assert_eq!(parsed.get_value(0), Some(Value::DateTime(1_704_153_600)));
+    /// assert_eq!(parsed.get_value(1), None);
+    /// ```
+    pub fn parse_datetime(&self, format: &str) -> Result<Series, VeloxxError> {
+        self.parse_datetime_multi(&[format])
+    }
+
+    /// Parses a String series into a DateTime series, trying several formats per cell.
+    ///
+    /// Each cell is parsed with the first of `formats` that matches it, using the same
+    /// format syntax as [`Series::parse_datetime`]; a cell becomes null only if no format
+    /// matches. This handles columns that mix formats, e.g. after merging sources.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok(Series)` containing the DateTime series,
+    /// `Err(VeloxxError::Unsupported)` if the series is not a String series, or
+    /// `Err(VeloxxError::InvalidOperation)` if `formats` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::series::Series;
+    /// use veloxx::types::Value;
+    ///
+    /// let series = Series::new_string(
+    ///     "day",
+    ///     vec![Some("2024-01-02".to_string()), Some("01/02/2024".to_string())],
+    /// );
+    /// let parsed = series.parse_datetime_multi(&["%Y-%m-%d", "%m/%d/%Y"]).unwrap();
+    /// assert_eq!(parsed.get_value(0), parsed.get_value(1));
+    /// ```
+    pub fn parse_datetime_multi(&self, formats: &[&str]) -> Result<Series, VeloxxError> {
+        let Series::String(name, values, bitmap) = self else {
+            return Err(VeloxxError::Unsupported(format!(
+                "Datetime parsing not supported for {:?} series.",
+                self.data_type()
+            )));
+        };
+        if formats.is_empty() {
+            return Err(VeloxxError::InvalidOperation(
+                "At least one datetime format is required".to_string(),
+            ));
+        }
+
+        let parsed: Vec<Option<i64>> = values
+            .iter()
+            .zip(bitmap.iter())
+            .map(|(value, &valid)| {
+                if !valid {
+                    return None;
+                }
+                let value = value.trim();
+                formats
+                    .iter()
+                    .find_map(|format| parse_timestamp(value, format))
+            })
+            .collect();
+        Ok(Series::new_datetime(name, parsed))
+    }
+}
+
+/// Parses `input` against a strftime-style `format`, returning Unix seconds.
+fn parse_timestamp(input: &str, format: &str) -> Option<i64> {
+    let (mut year, mut month, mut day) = (1970i64, 1u32, 1u32);
+    let (mut hour, mut minute, mut second) = (0u32, 0u32, 0u32);
+    let mut rest = input;
+    let mut spec = format.chars();
+    while let Some(c) = spec.next() {
+        if c != '%' {
+            rest = rest.strip_prefix(c)?;
+            continue;
+        }
+        match spec.next()? {
+            'Y' => year = take_digits(&mut rest, 4)? as i64,
+            'm' => month = take_digits(&mut rest, 2)?,
+            'd' => day = take_digits(&mut rest, 2)?,
+            'H' => hour = take_digits(&mut rest, 2)?,
+            'M' => minute = take_digits(&mut rest, 2)?,
+            'S' => second = take_digits(&mut rest, 2)?,
+            '%' => rest = rest.strip_prefix('%')?,
+            _ => return None,
+        }
+    }
+    if !rest.is_empty()
+        || !(1..=12).contains(&month)
+        || day == 0
+        || day > days_in_month(year, month)
+        || hour > 23
+        || minute > 59
+        || second > 59
+    {
+        return None;
+    }
+    let days = days_from_civil(year, month, day);
+    Some(days * 86_400 + (hour * 3_600 + minute * 60 + second) as i64)
+}
+
+/// Consumes between one and `max_len` leading ASCII digits from `rest`.
+fn take_digits(rest: &mut &str, max_len: usize) -> Option<u32> {
+    let len = rest
+        .bytes()
+        .take(max_len)
+        .take_while(|b| b.is_ascii_digit())
+        .count();
+    if len == 0 {
+        return None;
+    }
+    let (digits, tail) = rest.split_at(len);
+    *rest = tail;
+    digits.parse().ok()
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Days since 1970-01-01 for a proleptic Gregorian date.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_index = (month as i64 + 9) % 12;
+    let day_of_year = (153 * month_index + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Folds the non-null values of each trailing window with `combine`, or `None` where the window
+/// holds fewer than `min_periods` of them.
+fn rolling_fold<T: Copy>(
+    data: &[T],
+    validity: &[bool],
+    window_size: usize,
+    min_periods: usize,
+    combine: impl Fn(T, T) -> T,
+) -> Vec<Option<T>> {
+    (0..data.len())
+        .map(|i| {
+            let start = (i + 1).saturating_sub(window_size);
+            let mut valid = data[start..=i]
+                .iter()
+                .zip(&validity[start..=i])
+                .filter(|(_, &is_valid)| is_valid)
+                .map(|(&value, _)| value);
+            let first = valid.next()?;
+            let (folded, count) = valid.fold((first, 1), |(acc, count), value| {
+                (combine(acc, value), count + 1)
+            });
+            (count >= min_periods).then_some(folded)
+        })
+        .collect()
+}
+
+/// Applies `sub(data[i], data[i - periods])` wherever both values exist and are valid.
+fn shifted_diff<T: Copy>(
+    data: &[T],
+    validity: &[bool],
+    periods: i64,
+    sub: impl Fn(T, T) -> Option<T>,
+) -> Vec<Option<T>> {
+    (0..data.len())
+        .map(|i| {
+            let j = usize::try_from((i as i64).checked_sub(periods)?).ok()?;
+            if j >= data.len() || !validity[i] || !validity[j] {
+                return None;
+            }
+            sub(data[i], data[j])
+        })
+        .collect()
+}
+
+/// Walks the non-null values in order, passing each one with the value carried from the
+/// previous non-null position to `step`, which returns the output and the next carried value.
+/// The first non-null value is copied through unchanged and null positions keep their data.
+fn delta_fold<T: Copy>(data: &[T], validity: &[bool], step: impl Fn(T, T) -> (T, T)) -> Vec<T> {
+    let mut carried = None;
+    data.iter()
+        .zip(validity)
+        .map(|(&value, &is_valid)| {
+            if !is_valid {
+                return value;
+            }
+            let (output, next) = match carried {
+                None => (value, value),
+                Some(prev) => step(value, prev),
+            };
+            carried = Some(next);
+            output
+        })
+        .collect()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_datetime_multi_formats() {
+        let series = Series::new_string(
+            "when",
+            vec![
+                Some("2024-03-01 12:30:05".to_string()),
+                Some("2024-03-01".to_string()),
+                Some("03/01/2024".to_string()),
+                Some("2023-02-29".to_string()),
+                Some("garbage".to_string()),
+                None,
+            ],
+        );
+        let parsed = series
+            .parse_datetime_multi(&["%Y-%m-%d %H:%M:%S", "%Y-%m-%d", "%m/%d/%Y"])
+            .unwrap();
+
+        let midnight = 1_709_251_200;
+        assert_eq!(parsed.name(), "when");
+        assert_eq!(
+            parsed.get_value(0),
+            Some(crate::types::Value::DateTime(midnight + 45_005))
+        );
+        assert_eq!(
+            parsed.get_value(1),
+            Some(crate::types::Value::DateTime(midnight))
+        );
+        assert_eq!(
+            parsed.get_value(2),
+            Some(crate::types::Value::DateTime(midnight))
+        );
+        assert_eq!(parsed.get_value(3), None);
+        assert_eq!(parsed.get_value(4), None);
+        assert_eq!(parsed.get_value(5), None);
+
+        assert_eq!(parse_timestamp("1969-12-31", "%Y-%m-%d"), Some(-86_400));
+        assert!(series.parse_datetime_multi(&[]).is_err());
+        assert!(Series::new_i32("n", vec![Some(1)])
+            .parse_datetime("%Y")
+            .is_err());
+    }
+
     #[test]
     fn test_rolling_mean_i32() {
         let series = Series::new_i32("test", vec![Some(1), Some(2), Some(3), Some(4), Some(5)]);
-        let result = series.rolling_mean(3).unwrap();
+        let result = series.rolling_mean(3, 3).unwrap();
 
         match result {
             Series::F64(_, values, _) => {
@@ -640,7 +1031,7 @@ mod tests {
     #[test]
     fn test_rolling_sum_f64() {
         let series = Series::new_f64("test", vec![Some(1.5), Some(2.5), Some(3.5), Some(4.5)]);
-        let result = series.rolling_sum(2).unwrap();
+        let result = series.rolling_sum(2, 2).unwrap();
 
         match result {
             Series::F64(_, values, _) => {
@@ -652,12 +1043,71 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_rolling_mean_and_sum_min_periods() {
+        let series = Series::new_i32("x", vec![Some(1), Some(2), Some(3), Some(4), Some(5)]);
+
+        let mean = series.rolling_mean(3, 3).unwrap();
+        assert_eq!(mean.name(), "x_rolling_mean_3");
+        assert_eq!(
+            mean.get_data_f64().unwrap(),
+            vec![None, None, Some(2.0), Some(3.0), Some(4.0)]
+        );
+        let sum = series.rolling_sum(3, 3).unwrap();
+        assert_eq!(
+            sum.get_data_f64().unwrap(),
+            vec![None, None, Some(6.0), Some(9.0), Some(12.0)]
+        );
+
+        let eager = series.rolling_sum(3, 1).unwrap();
+        assert_eq!(
+            eager.get_data_f64().unwrap(),
+            vec![Some(1.0), Some(3.0), Some(6.0), Some(9.0), Some(12.0)]
+        );
+    }
+
+    #[test]
+    fn test_rolling_mean_skips_nulls_in_window() {
+        let series = Series::new_f64("x", vec![Some(1.0), None, Some(3.0), None, None, Some(6.0)]);
+
+        let mean = series.rolling_mean(3, 2).unwrap();
+        assert_eq!(
+            mean.get_data_f64().unwrap(),
+            vec![None, None, Some(2.0), None, None, None]
+        );
+        let sum = series.rolling_sum(3, 1).unwrap();
+        assert_eq!(
+            sum.get_data_f64().unwrap(),
+            vec![
+                Some(1.0),
+                Some(1.0),
+                Some(4.0),
+                Some(3.0),
+                Some(3.0),
+                Some(6.0)
+            ]
+        );
+
+        let stamps = Series::new_datetime("t", vec![Some(10), Some(20), None]);
+        assert_eq!(
+            stamps.rolling_mean(2, 1).unwrap().get_data_f64().unwrap(),
+            vec![Some(10.0), Some(15.0), Some(20.0)]
+        );
+
+        assert!(series.rolling_mean(3, 0).is_err());
+        assert!(series.rolling_mean(3, 4).is_err());
+        assert!(matches!(
+            Series::new_bool("b", vec![Some(true)]).rolling_sum(1, 1),
+            Err(VeloxxError::Unsupported(_))
+        ));
+    }
+
     #[test]
     fn test_rolling_min_max() {
         let series = Series::new_i32("test", vec![Some(5), Some(2), Some(8), Some(1), Some(9)]);
 
-        let min_result = series.rolling_min(3).unwrap();
-        let max_result = series.rolling_max(3).unwrap();
+        let min_result = series.rolling_min(3, 3).unwrap();
+        let max_result = series.rolling_max(3, 3).unwrap();
 
         match (min_result, max_result) {
             (Series::I32(_, min_values, _), Series::I32(_, max_values, _)) => {
@@ -676,7 +1126,7 @@ mod tests {
     #[test]
     fn test_rolling_std() {
         let series = Series::new_f64("test", vec![Some(1.0), Some(2.0), Some(3.0), Some(4.0)]);
-        let result = series.rolling_std(3).unwrap();
+        let result = series.rolling_std(3, 3).unwrap();
 
         match result {
             Series::F64(_, values, _) => {
@@ -686,6 +1136,46 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_rolling_min_max_std_min_periods_with_nulls() {
+        let series = Series::new_f64("x", vec![Some(4.0), None, Some(2.0), Some(6.0), None]);
+
+        let min = series.rolling_min(3, 1).unwrap();
+        assert_eq!(min.name(), "x_rolling_min_3");
+        assert_eq!(
+            min.get_data_f64().unwrap(),
+            vec![Some(4.0), Some(4.0), Some(2.0), Some(2.0), Some(2.0)]
+        );
+        let max = series.rolling_max(3, 2).unwrap();
+        assert_eq!(
+            max.get_data_f64().unwrap(),
+            vec![None, None, Some(4.0), Some(6.0), Some(6.0)]
+        );
+        let strict = series.rolling_max(3, 3).unwrap();
+        assert_eq!(
+            strict.get_data_f64().unwrap(),
+            vec![None, None, None, None, None]
+        );
+
+        // A standard deviation needs two values even when min_periods is 1.
+        let std = series.rolling_std(2, 1).unwrap();
+        assert_eq!(std.get_value(0), None);
+        assert_eq!(std.get_value(1), None);
+        assert_eq!(std.get_value(2), None);
+        let pair = std.get_f64(3).unwrap();
+        assert!((pair - 8.0_f64.sqrt()).abs() < 1e-10);
+
+        let big = Series::new_i64("n", vec![Some(1 << 40), None, Some(-(1 << 40))]);
+        assert_eq!(
+            big.rolling_min(2, 1).unwrap().get_data_i64().unwrap(),
+            vec![Some(1 << 40), Some(1 << 40), Some(-(1 << 40))]
+        );
+
+        assert!(series.rolling_min(3, 0).is_err());
+        assert!(series.rolling_max(3, 4).is_err());
+        assert!(series.rolling_std(1, 1).is_err());
+    }
+
     #[test]
     fn test_pct_change() {
         let series = Series::new_f64("test", vec![Some(100.0), Some(110.0), Some(99.0)]);
@@ -719,7 +1209,7 @@ mod tests {
     #[test]
     fn test_rolling_operations_with_nulls() {
         let series = Series::new_i32("test", vec![Some(1), None, Some(3), Some(4), None]);
-        let result = series.rolling_mean(3).unwrap();
+        let result = series.rolling_mean(3, 1).unwrap();
 
         match result {
             Series::F64(_, values, _) => {
@@ -736,14 +1226,77 @@ mod tests {
         let series = Series::new_i32("test", vec![Some(1), Some(2), Some(3)]);
 
         // Test zero window size
-        assert!(series.rolling_mean(0).is_err());
+        assert!(series.rolling_mean(0, 1).is_err());
 
         // Test window size greater than series length
-        assert!(series.rolling_mean(5).is_err());
+        assert!(series.rolling_mean(5, 1).is_err());
 
         // Test non-numeric series
         let string_series =
             Series::new_string("test", vec![Some("a".to_string()), Some("b".to_string())]);
-        assert!(string_series.rolling_mean(2).is_err());
+        assert!(string_series.rolling_mean(2, 1).is_err());
+    }
+
+    #[test]
+    fn test_cumcount() {
+        let series = Series::new_f64("test", vec![Some(1.0), None, Some(3.0)]);
+        let result = series.cumcount();
+
+        assert_eq!(result.name(), "test_cumcount");
+        assert_eq!(result.get_value(0), Some(crate::types::Value::I32(0)));
+        assert_eq!(result.get_value(1), Some(crate::types::Value::I32(1)));
+        assert_eq!(result.get_value(2), Some(crate::types::Value::I32(2)));
+    }
+
+    #[test]
+    fn test_datetime_diff_in_seconds() {
+        let events = Series::new_datetime(
+            "at",
+            vec![Some(1_000), Some(1_060), Some(1_300), None, Some(1_400)],
+        );
+        let gaps = events.diff(1).unwrap();
+        assert_eq!(gaps.name(), "at_diff");
+        assert_eq!(gaps.data_type(), crate::types::DataType::I64);
+        let values: Vec<Option<crate::types::Value>> =
+            (0..gaps.len()).map(|i| gaps.get_value(i)).collect();
+        assert_eq!(
+            values,
+            vec![
+                None,
+                Some(crate::types::Value::I64(60)),
+                Some(crate::types::Value::I64(240)),
+                None,
+                None,
+            ]
+        );
+        assert_eq!(gaps.mean().unwrap(), crate::types::Value::F64(150.0));
+
+        assert!(matches!(
+            Series::new_string("s", vec![Some("a".to_string())]).diff(1),
+            Err(VeloxxError::Unsupported(_))
+        ));
+    }
+
+    #[test]
+    fn test_cummean_skips_nulls() {
+        let series = Series::new_i32("x", vec![None, Some(2), Some(4), None, Some(6)]);
+        let cummean = series.cummean().unwrap();
+        assert_eq!(cummean.name(), "x_cummean");
+        let values: Vec<Option<crate::types::Value>> =
+            (0..cummean.len()).map(|i| cummean.get_value(i)).collect();
+        assert_eq!(
+            values,
+            vec![
+                None,
+                Some(crate::types::Value::F64(2.0)),
+                Some(crate::types::Value::F64(3.0)),
+                None,
+                Some(crate::types::Value::F64(4.0)),
+            ]
+        );
+        assert!(matches!(
+            Series::new_bool("b", vec![Some(true)]).cummean(),
+            Err(VeloxxError::Unsupported(_))
+        ));
     }
 }
@@ -1,3 +1,4 @@
+use crate::VeloxxError;
 use serde::{Deserialize, Serialize};
 use std::hash::{Hash, Hasher};
 
@@ -40,6 +41,8 @@ pub enum DataType {
     String,
     /// DateTime type, represented as a Unix timestamp (i64).
     DateTime,
+    /// 64-bit signed integer type.
+    I64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, bincode::Encode, bincode::Decode)]
@@ -78,6 +81,10 @@ pub enum Value {
     String(String),
     /// A DateTime value, represented as a Unix timestamp (i64).
     DateTime(i64),
+    /// A 64-bit signed integer value.
+    ///
+    /// Declared last so serialized discriminants of the older variants stay stable.
+    I64(i64),
 }
 
 impl Value {
@@ -101,6 +108,7 @@ impl Value {
     pub fn data_type(&self) -> DataType {
         match self {
             Value::I32(_) => DataType::I32,
+            Value::I64(_) => DataType::I64,
             Value::F64(_) => DataType::F64,
             Value::Bool(_) => DataType::Bool,
             Value::String(_) => DataType::String,
@@ -127,6 +135,26 @@ impl Value {
         }
     }
 
+    /// Attempts to convert the `Value` into an `i64`.
+    /// Returns `Some(i64)` if the `Value` is `I64` or `I32`, otherwise `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::types::Value;
+    ///
+    /// assert_eq!(Value::I64(5_000_000_000).as_i64(), Some(5_000_000_000));
+    /// assert_eq!(Value::I32(10).as_i64(), Some(10));
+    /// assert_eq!(Value::F64(10.0).as_i64(), None);
+    /// ```
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Value::I64(v) => Some(*v),
+            Value::I32(v) => Some(*v as i64),
+            _ => None,
+        }
+    }
+
     /// Attempts to convert the `Value` into an `f64`.
     /// Returns `Some(f64)` if the `Value` is `F64`, otherwise `None`.
     ///
@@ -181,6 +209,24 @@ impl Value {
         }
     }
 
+    /// Attempts to convert the `Value` into a string slice.
+    /// Returns `Some(&str)` if the `Value` is `String`, otherwise `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::types::Value;
+    ///
+    /// assert_eq!(Value::String("hello".to_string()).as_str(), Some("hello"));
+    /// assert_eq!(Value::I32(0).as_str(), None);
+    /// ```
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(v) => Some(v.as_str()),
+            _ => None,
+        }
+    }
+
     /// Attempts to convert the `Value` into an `i64` (for DateTime).
     /// Returns `Some(i64)` if the `Value` is `DateTime`, otherwise `None`.
     ///
@@ -229,6 +275,7 @@ impl PartialEq for Value {
         match (self, other) {
             (Value::Null, Value::Null) => true,
             (Value::I32(l), Value::I32(r)) => l == r,
+            (Value::I64(l), Value::I64(r)) => l == r,
             (Value::F64(l), Value::F64(r)) => l.to_bits() == r.to_bits(), // Compare bitwise for f64
             (Value::Bool(l), Value::Bool(r)) => l == r,
             (Value::String(l), Value::String(r)) => l == r,
@@ -247,6 +294,9 @@ impl std::fmt::Display for Value {
         match self {
             Value::Null => write!(f, "null"),
             Value::I32(v) => write!(f, "{}", v),
+            Value::I64(v) => write!(f, "{}", v),
+            // Keep a trailing ".0" on whole floats so they read differently from integers.
+            Value::F64(v) if v.is_finite() && v.fract() == 0.0 => write!(f, "{:.1}", v),
             Value::F64(v) => write!(f, "{}", v),
             Value::Bool(v) => write!(f, "{}", v),
             Value::String(v) => write!(f, "{}", v),
@@ -258,19 +308,111 @@ impl std::fmt::Display for Value {
 impl Eq for Value {}
 
 impl Value {
+    /// Compares two numeric values (`I32`, `I64` or `F64`) across types.
+    ///
+    /// Comparisons are exact: integers compare as `i64`, and an integer compared with an `F64`
+    /// is not first rounded to `f64`, so `I64` values above 2^53 keep their order. NaN
+    /// compares greater than every other number and equal to itself, and values of different
+    /// numeric types compare `Equal` when they have the same magnitude. Returns `None` if
+    /// either value is not numeric.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::cmp::Ordering;
+    /// use veloxx::types::Value;
+    ///
+    /// assert_eq!(Value::I32(2).cmp_numeric(&Value::F64(2.5)), Some(Ordering::Less));
+    /// assert_eq!(Value::F64(f64::NAN).cmp_numeric(&Value::I64(i64::MAX)), Some(Ordering::Greater));
+    /// assert_eq!(Value::I32(1).cmp_numeric(&Value::String("1".to_string())), None);
+    /// let big = 1i64 << 53;
+    /// assert_eq!(Value::I64(big + 1).cmp_numeric(&Value::F64(big as f64)), Some(Ordering::Greater));
+    /// ```
+    pub fn cmp_numeric(&self, other: &Value) -> Option<std::cmp::Ordering> {
+        let as_i64 = |value: &Value| match value {
+            Value::I32(v) => Some(*v as i64),
+            Value::I64(v) => Some(*v),
+            _ => None,
+        };
+        let as_f64 = |value: &Value| match value {
+            Value::F64(v) => Some(*v),
+            _ => as_i64(value).map(|v| v as f64),
+        };
+        match (as_i64(self), as_i64(other)) {
+            (Some(a), Some(b)) => return Some(a.cmp(&b)),
+            (Some(a), None) => {
+                if let Value::F64(b) = other {
+                    return Some(cmp_i64_f64(a, *b));
+                }
+            }
+            (None, Some(b)) => {
+                if let Value::F64(a) = self {
+                    return Some(cmp_i64_f64(b, *a).reverse());
+                }
+            }
+            (None, None) => {}
+        }
+        let (a, b) = (as_f64(self)?, as_f64(other)?);
+        Some(match (a.is_nan(), b.is_nan()) {
+            (true, true) => std::cmp::Ordering::Equal,
+            (true, false) => std::cmp::Ordering::Greater,
+            (false, true) => std::cmp::Ordering::Less,
+            (false, false) => a.partial_cmp(&b).expect("neither value is NaN"),
+        })
+    }
+
+    /// Like [`Value::cmp_numeric`], but follows IEEE semantics: returns `None` if either
+    /// value is NaN or not numeric.
+    pub(crate) fn partial_cmp_numeric(&self, other: &Value) -> Option<std::cmp::Ordering> {
+        let is_nan = |value: &Value| matches!(value, Value::F64(v) if v.is_nan());
+        if is_nan(self) || is_nan(other) {
+            return None;
+        }
+        self.cmp_numeric(other)
+    }
+
     // Helper to get a discriminant for ordering incomparable types
     fn discriminant(&self) -> u8 {
         match self {
             Value::Null => 0,
             Value::I32(_) => 1,
-            Value::F64(_) => 2,
-            Value::Bool(_) => 3,
-            Value::String(_) => 4,
-            Value::DateTime(_) => 5,
+            Value::I64(_) => 2,
+            Value::F64(_) => 3,
+            Value::Bool(_) => 4,
+            Value::String(_) => 5,
+            Value::DateTime(_) => 6,
         }
     }
 }
 
+/// Compares an integer with a float exactly, without rounding `a` to the nearest `f64`.
+/// NaN compares greater than every integer, matching [`Value::cmp_numeric`].
+fn cmp_i64_f64(a: i64, b: f64) -> std::cmp::Ordering {
+    use std::cmp::Ordering;
+
+    if b.is_nan() {
+        return Ordering::Less;
+    }
+    // 2^63 is exactly representable, so these bounds are exact.
+    if b >= 9_223_372_036_854_775_808.0 {
+        return Ordering::Less;
+    }
+    if b < -9_223_372_036_854_775_808.0 {
+        return Ordering::Greater;
+    }
+    let whole = b.trunc();
+    a.cmp(&(whole as i64)).then_with(|| {
+        let fract = b - whole;
+        if fract > 0.0 {
+            Ordering::Less
+        } else if fract < 0.0 {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    })
+}
+
 impl Hash for Value {
     /// Implements the `Hash` trait for `Value`.
     ///
@@ -294,6 +436,7 @@ impl Hash for Value {
         match self {
             Value::Null => 0.hash(state),
             Value::I32(v) => v.hash(state),
+            Value::I64(v) => v.hash(state),
             Value::F64(v) => v.to_bits().hash(state), // Hash bitwise for f64
             Value::Bool(v) => v.hash(state),
             Value::String(v) => v.hash(state),
@@ -303,11 +446,10 @@ impl Hash for Value {
 }
 
 impl PartialOrd for Value {
-    /// Compares two `Value` instances for partial ordering.
+    /// Compares two `Value` instances, always agreeing with [`Ord::cmp`].
     ///
-    /// Numeric and boolean values are compared directly. Strings are compared lexicographically.
-    /// `Null` values are considered less than any non-`Null` value.
-    /// Returns `None` for comparisons between incomparable types (e.g., `I32` and `String`).
+    /// The ordering is total, so this never returns `None`; see [`Value::cmp_numeric`] for
+    /// an order that ignores the concrete numeric type.
     ///
     /// # Examples
     ///
@@ -317,32 +459,10 @@ impl PartialOrd for Value {
     /// assert!(Value::I32(5) < Value::I32(10));
     /// assert!(Value::F64(3.0) <= Value::F64(3.14));
     /// assert!(Value::Null < Value::I32(1));
-    /// assert_eq!(Value::I32(1).partial_cmp(&Value::String("a".to_string())), None);
+    /// assert!(Value::I32(1) < Value::String("a".to_string()));
     /// ```
-    #[allow(clippy::non_canonical_partial_ord_impl)]
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        use std::cmp::Ordering;
-
-        match (self, other) {
-            // Null comparisons - Null is less than everything except Null
-            (Value::Null, Value::Null) => Some(Ordering::Equal),
-            (Value::Null, _) => Some(Ordering::Less),
-            (_, Value::Null) => Some(Ordering::Greater),
-
-            // Same type comparisons
-            (Value::I32(a), Value::I32(b)) => a.partial_cmp(b),
-            (Value::F64(a), Value::F64(b)) => a.partial_cmp(b),
-            (Value::Bool(a), Value::Bool(b)) => a.partial_cmp(b),
-            (Value::String(a), Value::String(b)) => a.partial_cmp(b),
-            (Value::DateTime(a), Value::DateTime(b)) => a.partial_cmp(b),
-
-            // Cross-type numeric comparisons
-            (Value::I32(a), Value::F64(b)) => (*a as f64).partial_cmp(b),
-            (Value::F64(a), Value::I32(b)) => a.partial_cmp(&(*b as f64)),
-
-            // Different types - return None for incomparable types
-            _ => None,
-        }
+        Some(self.cmp(other))
     }
 }
 
@@ -350,9 +470,14 @@ impl Ord for Value {
     /// Compares two `Value` instances for total ordering.
     ///
     /// This implementation provides a consistent ordering for all `Value` variants.
-    /// Numeric and boolean values are compared directly. Strings are compared lexicographically.
+    /// Numeric values are compared with [`Value::cmp_numeric`], so `I32`, `I64` and `F64`
+    /// interleave by magnitude and NaN sorts after every other number. Boolean values are
+    /// compared directly and strings lexicographically.
     /// `Null` values are ordered before all other values.
     /// When comparing values of different concrete types, a fixed discriminant is used to establish an order.
+    /// Values that are numerically equal but not `==` (such as `I32(7)` and `I64(7)`, or
+    /// `0.0` and `-0.0`) are further ordered by discriminant and then by
+    /// [`f64::total_cmp`], so `cmp` returns `Equal` exactly when the values are equal.
     ///
     /// # Examples
     ///
@@ -363,12 +488,69 @@ impl Ord for Value {
     /// assert_eq!(Value::I32(5).cmp(&Value::I32(10)), Ordering::Less);
     /// assert_eq!(Value::Null.cmp(&Value::I32(1)), Ordering::Less);
     /// assert_eq!(Value::String("apple".to_string()).cmp(&Value::String("banana".to_string())), Ordering::Less);
+    /// assert_eq!(Value::F64(f64::NAN).cmp(&Value::F64(f64::INFINITY)), Ordering::Greater);
+    /// assert_eq!(Value::I32(7).cmp(&Value::I64(7)), Ordering::Less);
     /// ```
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        if let Some(ord) = self.partial_cmp(other) {
-            ord
-        } else {
-            self.discriminant().cmp(&other.discriminant())
+        use std::cmp::Ordering;
+
+        let ordering = match (self, other) {
+            (Value::Null, Value::Null) => Ordering::Equal,
+            (Value::Null, _) => Ordering::Less,
+            (_, Value::Null) => Ordering::Greater,
+            (Value::Bool(a), Value::Bool(b)) => a.cmp(b),
+            (Value::String(a), Value::String(b)) => a.cmp(b),
+            (Value::DateTime(a), Value::DateTime(b)) => a.cmp(b),
+            _ => self.cmp_numeric(other).unwrap_or(Ordering::Equal),
+        };
+        ordering
+            .then_with(|| self.discriminant().cmp(&other.discriminant()))
+            .then_with(|| match (self, other) {
+                (Value::F64(a), Value::F64(b)) => a.total_cmp(b),
+                _ => Ordering::Equal,
+            })
+    }
+}
+
+/// Implements `TryFrom<Value>` for the Rust type held by one `Value` variant.
+///
+/// The conversion fails with `VeloxxError::DataTypeMismatch` for any other variant,
+/// including `Value::Null`.
+macro_rules! impl_try_from_value {
+    ($target:ty, $variant:ident) => {
+        impl TryFrom<Value> for $target {
+            type Error = VeloxxError;
+
+            fn try_from(value: Value) -> Result<Self, Self::Error> {
+                match value {
+                    Value::$variant(v) => Ok(v),
+                    other => Err(VeloxxError::DataTypeMismatch(format!(
+                        "Expected {} value, found {:?}",
+                        stringify!($variant),
+                        other
+                    ))),
+                }
+            }
+        }
+    };
+}
+
+impl_try_from_value!(i32, I32);
+impl_try_from_value!(f64, F64);
+impl_try_from_value!(bool, Bool);
+impl_try_from_value!(String, String);
+
+/// Accepts both `I64` and `DateTime`, whose timestamps are stored as `i64`.
+impl TryFrom<Value> for i64 {
+    type Error = VeloxxError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::I64(v) | Value::DateTime(v) => Ok(v),
+            other => Err(VeloxxError::DataTypeMismatch(format!(
+                "Expected I64 or DateTime value, found {:?}",
+                other
+            ))),
         }
     }
 }
@@ -393,6 +575,8 @@ pub enum FlatValue {
     String(Vec<u8>), // Store byte representation
     /// A DateTime value, represented as a Unix timestamp (i64).
     DateTime(i64),
+    /// A 64-bit signed integer value.
+    I64(i64),
 }
 
 impl From<Value> for FlatValue {
@@ -418,6 +602,7 @@ impl From<Value> for FlatValue {
         match value {
             Value::Null => FlatValue::Null,
             Value::I32(v) => FlatValue::I32(v),
+            Value::I64(v) => FlatValue::I64(v),
             Value::F64(v) => FlatValue::F64(v.to_bits()),
             Value::Bool(v) => FlatValue::Bool(v),
             Value::String(v) => FlatValue::String(v.into_bytes()),
@@ -449,6 +634,7 @@ impl From<FlatValue> for Value {
         match flat_value {
             FlatValue::Null => Value::Null,
             FlatValue::I32(v) => Value::I32(v),
+            FlatValue::I64(v) => Value::I64(v),
             FlatValue::F64(v) => Value::F64(f64::from_bits(v)),
             FlatValue::Bool(v) => Value::Bool(v),
             FlatValue::String(v) => Value::String(String::from_utf8(v).unwrap_or_default()), // Handle potential UTF-8 errors
@@ -112,6 +112,26 @@ impl WasmDataFrame {
             .into_boxed_slice()
     }
 
+    /// First `n` rows
+    #[wasm_bindgen(js_name = head)]
+    pub fn head(&self, n: usize) -> Result<WasmDataFrame, JsValue> {
+        let df = self
+            .df
+            .head(n)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        Ok(WasmDataFrame { df })
+    }
+
+    /// Last `n` rows
+    #[wasm_bindgen(js_name = tail)]
+    pub fn tail(&self, n: usize) -> Result<WasmDataFrame, JsValue> {
+        let df = self
+            .df
+            .tail(n)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        Ok(WasmDataFrame { df })
+    }
+
     /// High-performance filtering using vectorized operations
     #[wasm_bindgen(js_name = filterGt)]
     pub fn filter_gt(&self, column: &str, value: JsValue) -> Result<WasmDataFrame, JsValue> {
@@ -194,6 +214,7 @@ impl WasmDataFrame {
                     }
                     match series.get_value(j) {
                         Some(Value::I32(v)) => json.push_str(&v.to_string()),
+                        Some(Value::I64(v)) => json.push_str(&v.to_string()),
                         Some(Value::F64(v)) => json.push_str(&v.to_string()),
                         Some(Value::String(v)) => json.push_str(&format!("\"{}\"", v)),
                         Some(Value::Bool(v)) => json.push_str(&v.to_string()),
@@ -274,12 +295,22 @@ impl WasmSeries {
         self.inner.name().to_string()
     }
 
+    /// Slice the series to `length` elements starting at `offset`, clamped to bounds
+    #[wasm_bindgen(js_name = slice)]
+    pub fn slice(&self, offset: usize, length: usize) -> Result<WasmSeries, JsValue> {
+        match self.inner.slice(offset, length) {
+            Ok(result) => Ok(WasmSeries { inner: result }),
+            Err(e) => Err(JsValue::from_str(&e.to_string())),
+        }
+    }
+
     /// Sum using SIMD optimization
     #[wasm_bindgen(js_name = sum)]
     pub fn sum(&self) -> Option<f64> {
         match self.inner.sum() {
             Ok(Value::F64(v)) => Some(v),
             Ok(Value::I32(v)) => Some(v as f64),
+            Ok(Value::I64(v)) => Some(v as f64),
             _ => None,
         }
     }
@@ -384,6 +384,7 @@ impl WindowFunction {
                     (*series).get_value(idx).and_then(|v| match v {
                         Value::F64(f) => Some(f),
                         Value::I32(n) => Some(n as f64),
+                        Value::I64(n) => Some(n as f64),
                         _ => None,
                     })
                 })
@@ -468,6 +469,18 @@ impl WindowFunction {
                     .collect();
                 Series::new_i32(&column_name_result, i32_values)
             }
+            Series::I64(_, _, _) => {
+                let i64_values: Vec<Option<i64>> = lag_lead_values
+                    .into_iter()
+                    .map(|v| {
+                        v.and_then(|val| match val {
+                            Value::I64(i) => Some(i),
+                            _ => None,
+                        })
+                    })
+                    .collect();
+                Series::new_i64(&column_name_result, i64_values)
+            }
             Series::F64(_, _, _) => {
                 let f64_values: Vec<Option<f64>> = lag_lead_values
                     .into_iter()
@@ -509,7 +522,7 @@ impl WindowFunction {
                 // For DateTime, we'll convert to string representation
                 let string_values: Vec<Option<String>> = lag_lead_values
                     .into_iter()
-                    .map(|v| v.map(|val| format!("{:?}", val)))
+                    .map(|v| v.map(|val| val.to_string()))
                     .collect();
                 Series::new_string(&column_name_result, string_values)
             }
@@ -577,6 +590,7 @@ impl WindowFunction {
                     (*series).get_value(idx).and_then(|v| match v {
                         Value::F64(f) => Some(f),
                         Value::I32(n) => Some(n as f64),
+                        Value::I64(n) => Some(n as f64),
                         _ => None,
                     })
                 })
@@ -612,6 +626,27 @@ impl RankingFunction {
     }
 }
 
+/// Ranking functions evaluated over partitions by [`DataFrame::window_rank`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WindowFunc {
+    /// Sequential number of the row within its partition, starting at 1
+    RowNumber,
+    /// Rank with gaps: tied rows share a rank and the next rank skips ahead
+    Rank,
+    /// Rank without gaps: tied rows share a rank and the next rank is consecutive
+    DenseRank,
+}
+
+impl WindowFunc {
+    pub fn name(&self) -> &str {
+        match self {
+            WindowFunc::RowNumber => "row_number",
+            WindowFunc::Rank => "rank",
+            WindowFunc::DenseRank => "dense_rank",
+        }
+    }
+}
+
 /// Aggregate functions
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum AggregateFunction {
@@ -633,3 +668,397 @@ impl AggregateFunction {
         }
     }
 }
+
+impl DataFrame {
+    /// Computes a cumulative sum of `value_col` that restarts for every group.
+    ///
+    /// Groups are identified by the unique combinations of values in the `by` columns.
+    /// The running total follows the original row order within each group, and the
+    /// returned series is aligned with the rows of the `DataFrame`. Null values stay
+    /// null in the output and do not contribute to the running total.
+    ///
+    /// # Arguments
+    ///
+    /// * `by` - Column names identifying a group
+    /// * `value_col` - Numeric column (`I32`, `I64` or `F64`) to accumulate
+    ///
+    /// # Returns
+    ///
+    /// A series named `"{value_col}_cumsum"` with the same type as `value_col`. For integer
+    /// columns, a running sum that overflows is null from that row to the end of its group.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::series::Series;
+    /// use veloxx::types::Value;
+    /// use indexmap::IndexMap;
+    ///
+    /// let mut columns = IndexMap::new();
+    /// columns.insert("store".to_string(), Series::new_string("store", vec![Some("a".to_string()), Some("b".to_string()), Some("a".to_string())]));
+    /// columns.insert("sales".to_string(), Series::new_i32("sales", vec![Some(1), Some(10), Some(2)]));
+    /// let df = DataFrame::new(columns);
+    ///
+    /// let running = df.group_cumsum(&["store"], "sales").unwrap();
+    /// assert_eq!(running.get_value(2), Some(Value::I32(3)));
+    /// assert_eq!(running.get_value(1), Some(Value::I32(10)));
+    /// ```
+    pub fn group_cumsum(&self, by: &[&str], value_col: &str) -> Result<Series, VeloxxError> {
+        let series = self
+            .get_column(value_col)
+            .ok_or_else(|| VeloxxError::ColumnNotFound(value_col.to_string()))?;
+        let groups = self.group_row_indices(by)?;
+        let name = format!("{}_cumsum", value_col);
+
+        match series {
+            Series::I32(_, data, validity) => Ok(Series::new_i32(
+                &name,
+                checked_group_cumsum(data, validity, &groups, i32::checked_add),
+            )),
+            Series::I64(_, data, validity) => Ok(Series::new_i64(
+                &name,
+                checked_group_cumsum(data, validity, &groups, i64::checked_add),
+            )),
+            Series::F64(_, data, validity) => {
+                let mut result = vec![None; data.len()];
+                for indices in &groups {
+                    let mut running_sum = 0.0f64;
+                    for &i in indices {
+                        if validity[i] {
+                            running_sum += data[i];
+                            result[i] = Some(running_sum);
+                        }
+                    }
+                }
+                Ok(Series::new_f64(&name, result))
+            }
+            _ => Err(VeloxxError::InvalidOperation(
+                "Cumulative sum is only supported for numeric series (I32, I64, F64)".to_string(),
+            )),
+        }
+    }
+
+    /// Numbers the rows of each group 0, 1, 2, ... in their current order.
+    ///
+    /// Groups are identified by the unique combinations of values in the `by` columns and
+    /// the count restarts at 0 for every group. Sort the `DataFrame` first (for example with
+    /// [`DataFrame::sort_within_groups`]) if the numbering should follow a particular order.
+    ///
+    /// # Arguments
+    ///
+    /// * `by` - Column names identifying a group
+    ///
+    /// # Returns
+    ///
+    /// An `I32` series named `"cumcount"`, aligned with the rows of the `DataFrame`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::series::Series;
+    /// use veloxx::types::Value;
+    /// use indexmap::IndexMap;
+    ///
+    /// let mut columns = IndexMap::new();
+    /// columns.insert("customer".to_string(), Series::new_string("customer", vec![Some("a".to_string()), Some("b".to_string()), Some("a".to_string())]));
+    /// let df = DataFrame::new(columns);
+    ///
+    /// let order_number = df.group_cumcount(&["customer"]).unwrap();
+    /// assert_eq!(order_number.get_value(2), Some(Value::I32(1)));
+    /// ```
+    pub fn group_cumcount(&self, by: &[&str]) -> Result<Series, VeloxxError> {
+        let mut counts = vec![None; self.row_count()];
+        for indices in self.group_row_indices(by)? {
+            for (pos, row) in indices.into_iter().enumerate() {
+                counts[row] = Some(pos as i32);
+            }
+        }
+        Ok(Series::new_i32("cumcount", counts))
+    }
+
+    /// Computes a rolling mean of `value_col` that restarts for every group.
+    ///
+    /// Within each group (identified by the `by` columns) the window slides over the
+    /// group's rows in their original order. The first `window - 1` rows of a group
+    /// are null, as are windows containing only nulls. The returned series is aligned
+    /// with the rows of the `DataFrame`.
+    ///
+    /// # Arguments
+    ///
+    /// * `by` - Column names identifying a group
+    /// * `value_col` - Numeric column (`I32`, `I64` or `F64`) to average
+    /// * `window` - Number of rows in the window
+    ///
+    /// # Returns
+    ///
+    /// An `F64` series named `"{value_col}_rolling_mean_{window}"`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::series::Series;
+    /// use veloxx::types::Value;
+    /// use indexmap::IndexMap;
+    ///
+    /// let mut columns = IndexMap::new();
+    /// columns.insert("store".to_string(), Series::new_string("store", vec![Some("a".to_string()), Some("b".to_string()), Some("a".to_string())]));
+    /// columns.insert("sales".to_string(), Series::new_f64("sales", vec![Some(1.0), Some(10.0), Some(3.0)]));
+    /// let df = DataFrame::new(columns);
+    ///
+    /// let rolling = df.group_rolling_mean(&["store"], "sales", 2).unwrap();
+    /// assert_eq!(rolling.get_value(0), None);
+    /// assert_eq!(rolling.get_value(1), None);
+    /// assert_eq!(rolling.get_value(2), Some(Value::F64(2.0)));
+    /// ```
+    pub fn group_rolling_mean(
+        &self,
+        by: &[&str],
+        value_col: &str,
+        window: usize,
+    ) -> Result<Series, VeloxxError> {
+        if window == 0 {
+            return Err(VeloxxError::InvalidOperation(
+                "Window size must be greater than 0".to_string(),
+            ));
+        }
+        let series = self
+            .get_column(value_col)
+            .ok_or_else(|| VeloxxError::ColumnNotFound(value_col.to_string()))?;
+        if !matches!(series, Series::I32(..) | Series::I64(..) | Series::F64(..)) {
+            return Err(VeloxxError::InvalidOperation(
+                "Rolling mean is only supported for numeric series (I32, I64, F64)".to_string(),
+            ));
+        }
+        let groups = self.group_row_indices(by)?;
+
+        let mut result = vec![None; series.len()];
+        for indices in &groups {
+            for rows in indices.windows(window) {
+                let window_values: Vec<f64> = rows
+                    .iter()
+                    .filter_map(|&idx| match series.get_value(idx) {
+                        Some(Value::I32(n)) => Some(n as f64),
+                        Some(Value::I64(n)) => Some(n as f64),
+                        Some(Value::F64(f)) => Some(f),
+                        _ => None,
+                    })
+                    .collect();
+                if !window_values.is_empty() {
+                    let sum: f64 = window_values.iter().sum();
+                    result[rows[window - 1]] = Some(sum / window_values.len() as f64);
+                }
+            }
+        }
+
+        let name = format!("{}_rolling_mean_{}", value_col, window);
+        Ok(Series::new_f64(&name, result))
+    }
+
+    /// Ranks rows within each partition according to `order_by`.
+    ///
+    /// Rows are partitioned by the `partition_by` columns (an empty slice treats the whole
+    /// `DataFrame` as one partition) and ordered within each partition by `order_by`,
+    /// a list of `(column, ascending)` pairs. Ties follow SQL semantics: `RowNumber`
+    /// numbers tied rows in their original order, `Rank` gives them the same rank and
+    /// leaves a gap, and `DenseRank` gives them the same rank without a gap.
+    ///
+    /// # Arguments
+    ///
+    /// * `partition_by` - Column names identifying a partition
+    /// * `order_by` - `(column, ascending)` pairs defining the order within a partition
+    /// * `func` - Ranking function to evaluate
+    ///
+    /// # Returns
+    ///
+    /// An `I32` series named after the function (e.g. `"rank"`), aligned with the rows of the `DataFrame`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::series::Series;
+    /// use veloxx::types::Value;
+    /// use veloxx::window_functions::WindowFunc;
+    /// use indexmap::IndexMap;
+    ///
+    /// let mut columns = IndexMap::new();
+    /// columns.insert("region".to_string(), Series::new_string("region", vec![Some("N".to_string()), Some("S".to_string()), Some("N".to_string())]));
+    /// columns.insert("sales".to_string(), Series::new_i32("sales", vec![Some(10), Some(5), Some(20)]));
+    /// let df = DataFrame::new(columns);
+    ///
+    /// let ranks = df
+    ///     .window_rank(&["region"], vec![("sales".to_string(), false)], WindowFunc::Rank)
+    ///     .unwrap();
+    /// assert_eq!(ranks.get_value(0), Some(Value::I32(2)));
+    /// assert_eq!(ranks.get_value(1), Some(Value::I32(1)));
+    /// assert_eq!(ranks.get_value(2), Some(Value::I32(1)));
+    /// ```
+    pub fn window_rank(
+        &self,
+        partition_by: &[&str],
+        order_by: Vec<(String, bool)>,
+        func: WindowFunc,
+    ) -> Result<Series, VeloxxError> {
+        let partitions = self.sorted_group_indices(partition_by, &order_by)?;
+        let order_series: Vec<&Series> = order_by
+            .iter()
+            .filter_map(|(name, _)| self.get_column(name))
+            .collect();
+        let is_tie = |a: usize, b: usize| {
+            order_series
+                .iter()
+                .all(|series| series.get_value(a) == series.get_value(b))
+        };
+
+        let mut ranks = vec![None; self.row_count()];
+        for indices in &partitions {
+            let mut rank = 0i32;
+            let mut dense_rank = 0i32;
+            for (pos, &row) in indices.iter().enumerate() {
+                let tied = pos > 0 && is_tie(indices[pos - 1], row);
+                if !tied {
+                    rank = pos as i32 + 1;
+                    dense_rank += 1;
+                }
+                ranks[row] = Some(match func {
+                    WindowFunc::RowNumber => pos as i32 + 1,
+                    WindowFunc::Rank => rank,
+                    WindowFunc::DenseRank => dense_rank,
+                });
+            }
+        }
+
+        Ok(Series::new_i32(func.name(), ranks))
+    }
+
+    /// Returns the value of `value_col` from `offset` rows earlier within the same partition.
+    ///
+    /// Rows are partitioned by `partition_by` and ordered within each partition by
+    /// `order_by`, a list of `(column, ascending)` pairs. Rows with fewer than `offset`
+    /// predecessors in their partition get null, so values never leak across partitions.
+    ///
+    /// # Arguments
+    ///
+    /// * `partition_by` - Column names identifying a partition
+    /// * `order_by` - `(column, ascending)` pairs defining the order within a partition
+    /// * `value_col` - Column whose values are shifted
+    /// * `offset` - Number of rows to look back
+    ///
+    /// # Returns
+    ///
+    /// A series named `"lag_{value_col}_{offset}"` with the same type as `value_col`,
+    /// aligned with the rows of the `DataFrame`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::series::Series;
+    /// use veloxx::types::Value;
+    /// use indexmap::IndexMap;
+    ///
+    /// let mut columns = IndexMap::new();
+    /// columns.insert("customer".to_string(), Series::new_string("customer", vec![Some("a".to_string()), Some("a".to_string()), Some("b".to_string())]));
+    /// columns.insert("day".to_string(), Series::new_i32("day", vec![Some(5), Some(2), Some(4)]));
+    /// let df = DataFrame::new(columns);
+    ///
+    /// let previous = df
+    ///     .window_lag(&["customer"], vec![("day".to_string(), true)], "day", 1)
+    ///     .unwrap();
+    /// assert_eq!(previous.get_value(0), Some(Value::I32(2)));
+    /// assert_eq!(previous.get_value(1), None);
+    /// assert_eq!(previous.get_value(2), None);
+    /// ```
+    pub fn window_lag(
+        &self,
+        partition_by: &[&str],
+        order_by: Vec<(String, bool)>,
+        value_col: &str,
+        offset: usize,
+    ) -> Result<Series, VeloxxError> {
+        let name = format!("lag_{}_{}", value_col, offset);
+        self.shift_within_partitions(partition_by, &order_by, value_col, &name, |pos, len| {
+            pos.checked_sub(offset).filter(|&target| target < len)
+        })
+    }
+
+    /// Returns the value of `value_col` from `offset` rows later within the same partition.
+    ///
+    /// This is the counterpart of [`DataFrame::window_lag`]: rows with fewer than `offset`
+    /// successors in their partition get null.
+    ///
+    /// # Arguments
+    ///
+    /// * `partition_by` - Column names identifying a partition
+    /// * `order_by` - `(column, ascending)` pairs defining the order within a partition
+    /// * `value_col` - Column whose values are shifted
+    /// * `offset` - Number of rows to look ahead
+    ///
+    /// # Returns
+    ///
+    /// A series named `"lead_{value_col}_{offset}"` with the same type as `value_col`,
+    /// aligned with the rows of the `DataFrame`
+    pub fn window_lead(
+        &self,
+        partition_by: &[&str],
+        order_by: Vec<(String, bool)>,
+        value_col: &str,
+        offset: usize,
+    ) -> Result<Series, VeloxxError> {
+        let name = format!("lead_{}_{}", value_col, offset);
+        self.shift_within_partitions(partition_by, &order_by, value_col, &name, |pos, len| {
+            pos.checked_add(offset).filter(|&target| target < len)
+        })
+    }
+
+    fn shift_within_partitions<F>(
+        &self,
+        partition_by: &[&str],
+        order_by: &[(String, bool)],
+        value_col: &str,
+        name: &str,
+        target: F,
+    ) -> Result<Series, VeloxxError>
+    where
+        F: Fn(usize, usize) -> Option<usize>,
+    {
+        let series = self
+            .get_column(value_col)
+            .ok_or_else(|| VeloxxError::ColumnNotFound(value_col.to_string()))?;
+        let partitions = self.sorted_group_indices(partition_by, order_by)?;
+
+        let mut values = vec![None; series.len()];
+        for indices in &partitions {
+            for (pos, &row) in indices.iter().enumerate() {
+                values[row] = target(pos, indices.len()).and_then(|t| series.get_value(indices[t]));
+            }
+        }
+
+        Series::from_values(name, values, series.data_type())
+    }
+}
+
+/// Running sums of the valid values within each group of row indices.
+///
+/// Once a group's sum overflows, it and every later row of that group are null.
+fn checked_group_cumsum<T: Copy + Default>(
+    data: &[T],
+    validity: &[bool],
+    groups: &[Vec<usize>],
+    checked_add: fn(T, T) -> Option<T>,
+) -> Vec<Option<T>> {
+    let mut result = vec![None; data.len()];
+    for indices in groups {
+        let mut running_sum = Some(T::default());
+        for &i in indices {
+            if validity[i] {
+                running_sum = running_sum.and_then(|sum| checked_add(sum, data[i]));
+                result[i] = running_sum;
+            }
+        }
+    }
+    result
+}
@@ -1,5 +1,35 @@
+use indexmap::IndexMap;
 use veloxx::conditions::Condition;
+use veloxx::dataframe::DataFrame;
+use veloxx::series::Series;
 use veloxx::types::Value;
+use veloxx::VeloxxError;
+
+fn membership_df() -> DataFrame {
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "id".to_string(),
+        Series::new_i32("id", vec![Some(1), Some(2), None, Some(4)]),
+    );
+    columns.insert(
+        "city".to_string(),
+        Series::new_string(
+            "city",
+            vec![
+                Some("London".to_string()),
+                Some("Paris".to_string()),
+                Some("Berlin".to_string()),
+                None,
+            ],
+        ),
+    );
+    DataFrame::new(columns)
+}
+
+fn ids(df: &DataFrame) -> Vec<Option<Value>> {
+    let series = df.get_column("id").unwrap();
+    (0..df.row_count()).map(|i| series.get_value(i)).collect()
+}
 
 #[test]
 fn test_conditions() {
@@ -30,3 +60,122 @@ fn test_conditions() {
         "Or(Lt(\"col2\", I32(42)), Gt(\"col2\", I32(42)))"
     );
 }
+
+#[test]
+fn test_in_condition_filters_i32_and_string_columns() {
+    let df = membership_df();
+
+    let by_id = df
+        .filter(&Condition::In(
+            "id".to_string(),
+            vec![Value::I32(2), Value::I32(4), Value::I32(7)],
+        ))
+        .unwrap();
+    assert_eq!(ids(&by_id), vec![Some(Value::I32(2)), Some(Value::I32(4))]);
+
+    let by_city = df
+        .filter(&Condition::In(
+            "city".to_string(),
+            vec![
+                Value::String("London".to_string()),
+                Value::String("Berlin".to_string()),
+            ],
+        ))
+        .unwrap();
+    assert_eq!(ids(&by_city), vec![Some(Value::I32(1)), None]);
+}
+
+#[test]
+fn test_in_condition_matches_nulls_only_when_listed() {
+    let df = membership_df();
+
+    let without_null = df
+        .filter(&Condition::In("id".to_string(), vec![Value::I32(1)]))
+        .unwrap();
+    assert_eq!(without_null.row_count(), 1);
+
+    let with_null = df
+        .filter(&Condition::In(
+            "id".to_string(),
+            vec![Value::I32(1), Value::Null],
+        ))
+        .unwrap();
+    assert_eq!(ids(&with_null), vec![Some(Value::I32(1)), None]);
+}
+
+#[test]
+fn test_not_in_condition_is_inverse_of_in() {
+    let df = membership_df();
+
+    let not_in = df
+        .filter(&Condition::NotIn(
+            "city".to_string(),
+            vec![Value::String("Paris".to_string())],
+        ))
+        .unwrap();
+    assert_eq!(
+        ids(&not_in),
+        vec![Some(Value::I32(1)), None, Some(Value::I32(4))]
+    );
+
+    let not_in_null = df
+        .filter(&Condition::NotIn("id".to_string(), vec![Value::Null]))
+        .unwrap();
+    assert_eq!(
+        ids(&not_in_null),
+        vec![
+            Some(Value::I32(1)),
+            Some(Value::I32(2)),
+            Some(Value::I32(4))
+        ]
+    );
+}
+
+#[test]
+fn test_in_condition_errors() {
+    let df = membership_df();
+
+    let missing = df.filter(&Condition::In("missing".to_string(), vec![Value::I32(1)]));
+    assert!(matches!(missing, Err(VeloxxError::ColumnNotFound(_))));
+
+    let mismatch = df.filter(&Condition::In(
+        "id".to_string(),
+        vec![Value::String("1".to_string())],
+    ));
+    assert!(matches!(mismatch, Err(VeloxxError::DataTypeMismatch(_))));
+}
+
+#[test]
+fn test_comparisons_coerce_numeric_types() {
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "x".to_string(),
+        Series::new_f64("x", vec![Some(2.5), Some(5.0), Some(7.5), None]),
+    );
+    let df = DataFrame::new(columns);
+    let xs = |df: &DataFrame| -> Vec<Option<Value>> {
+        let series = df.get_column("x").unwrap();
+        (0..df.row_count()).map(|i| series.get_value(i)).collect()
+    };
+
+    let gt = df
+        .filter(&Condition::Gt("x".to_string(), Value::I32(5)))
+        .unwrap();
+    assert_eq!(xs(&gt), vec![Some(Value::F64(7.5))]);
+
+    let lt = df
+        .filter(&Condition::Lt("x".to_string(), Value::I64(5)))
+        .unwrap();
+    assert_eq!(xs(&lt), vec![Some(Value::F64(2.5))]);
+
+    let eq = df
+        .filter(&Condition::Eq("x".to_string(), Value::I32(5)))
+        .unwrap();
+    assert_eq!(xs(&eq), vec![Some(Value::F64(5.0))]);
+
+    let mismatch = Condition::Gt("x".to_string(), Value::String("5".to_string()));
+    assert!(matches!(
+        mismatch.evaluate(&df, 0),
+        Err(VeloxxError::InvalidOperation(_))
+    ));
+}
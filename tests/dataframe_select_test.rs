@@ -31,3 +31,108 @@ fn test_select() {
     // assert_eq!(c_series.len(), 3);
     // assert_eq!(c_series.get_value(0), Some(veloxx::types::Value::I32(10)));
 }
+
+fn wide_df() -> DataFrame {
+    let mut columns = IndexMap::new();
+    for name in ["id", "feature_x", "label", "feature_y"] {
+        columns.insert(name.to_string(), Series::new_i32(name, vec![Some(1)]));
+    }
+    DataFrame::new(columns)
+}
+
+#[test]
+fn test_select_matching() {
+    let df = wide_df();
+    let features = df
+        .select_matching(|name| name.starts_with("feature_"))
+        .unwrap();
+    assert_eq!(features.column_names(), vec!["feature_x", "feature_y"]);
+
+    let none = df.select_matching(|name| name.is_empty()).unwrap();
+    assert_eq!(none.column_count(), 0);
+}
+
+#[cfg(feature = "data_quality")]
+#[test]
+fn test_select_regex() {
+    let df = wide_df();
+    let features = df.select_regex("^feature_[xy]$").unwrap();
+    assert_eq!(features.column_names(), vec!["feature_x", "feature_y"]);
+    assert!(df.select_regex("(").is_err());
+}
+
+#[test]
+fn test_column_order_is_preserved() {
+    use veloxx::expressions::Expr;
+    use veloxx::types::Value;
+
+    let mut columns = IndexMap::new();
+    for name in ["z", "b", "m", "a"] {
+        columns.insert(
+            name.to_string(),
+            Series::new_i32(name, vec![Some(3), Some(1), Some(2)]),
+        );
+    }
+    let df = DataFrame::new(columns);
+
+    let selected = df
+        .select_columns(vec!["m".to_string(), "z".to_string()])
+        .unwrap();
+    assert_eq!(selected.column_names(), vec!["m", "z"]);
+
+    let dropped = df.drop_columns(vec!["z".to_string()]).unwrap();
+    assert_eq!(dropped.column_names(), vec!["b", "m", "a"]);
+
+    let sorted = df.sort(vec!["a".to_string()], true).unwrap();
+    assert_eq!(sorted.column_names(), vec!["z", "b", "m", "a"]);
+
+    let extended = df.with_column("c", &Expr::Literal(Value::I32(0))).unwrap();
+    assert_eq!(extended.column_names(), vec!["z", "b", "m", "a", "c"]);
+
+    let taken = df.filter_by_indices(&[2, 0]).unwrap();
+    assert_eq!(taken.column_names(), vec!["z", "b", "m", "a"]);
+
+    let empty = df.filter_by_indices(&[]).unwrap();
+    assert_eq!(empty.row_count(), 0);
+    assert_eq!(empty.column_names(), vec!["z", "b", "m", "a"]);
+}
+
+#[test]
+fn test_head_and_tail() {
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "a".to_string(),
+        Series::new_i32("a", vec![Some(1), Some(2), Some(3), Some(4)]),
+    );
+    columns.insert(
+        "b".to_string(),
+        Series::new_f64("b", vec![Some(1.0), None, Some(3.0), Some(4.0)]),
+    );
+    let df = DataFrame::new(columns);
+
+    let head = df.head(2).unwrap();
+    assert_eq!(
+        head.get_column("a").unwrap().get_data_i32().unwrap(),
+        vec![Some(1), Some(2)]
+    );
+    let tail = df.tail(3).unwrap();
+    assert_eq!(
+        tail.get_column("a").unwrap().get_data_i32().unwrap(),
+        vec![Some(2), Some(3), Some(4)]
+    );
+    assert_eq!(
+        tail.get_column("b").unwrap().get_data_f64().unwrap(),
+        vec![None, Some(3.0), Some(4.0)]
+    );
+
+    // `n` beyond the row count is clamped and keeps the schema.
+    for all in [df.head(10).unwrap(), df.tail(10).unwrap()] {
+        assert_eq!(all.row_count(), 4);
+        assert_eq!(all.column_names(), vec!["a", "b"]);
+    }
+
+    for empty in [df.head(0).unwrap(), df.tail(0).unwrap()] {
+        assert_eq!(empty.row_count(), 0);
+        assert_eq!(empty.column_names(), vec!["a", "b"]);
+    }
+}
@@ -102,3 +102,1166 @@ fn test_get_nonexistent_column() {
     let df = DataFrame::new(columns);
     assert!(df.get_column("colX").is_none());
 }
+
+#[test]
+fn test_factorize_string_column() {
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "id".to_string(),
+        Series::new_i32("id", vec![Some(1), Some(2), Some(3), Some(4)]),
+    );
+    columns.insert(
+        "color".to_string(),
+        Series::new_string(
+            "color",
+            vec![
+                Some("red".to_string()),
+                Some("blue".to_string()),
+                None,
+                Some("red".to_string()),
+            ],
+        ),
+    );
+    let df = DataFrame::new(columns);
+
+    let (encoded, categories) = df.factorize("color").unwrap();
+    assert_eq!(categories, vec!["red".to_string(), "blue".to_string()]);
+    assert_eq!(
+        encoded.column_names(),
+        vec!["id".to_string(), "color".to_string()]
+    );
+
+    let codes = encoded.get_column("color").unwrap();
+    assert_eq!(codes.get_value(0), Some(Value::I32(0)));
+    assert_eq!(codes.get_value(1), Some(Value::I32(1)));
+    assert_eq!(codes.get_value(2), None);
+    assert_eq!(codes.get_value(3), Some(Value::I32(0)));
+
+    assert!(df.factorize("id").is_err());
+    assert!(df.factorize("missing").is_err());
+}
+
+#[test]
+fn test_row_hashes_are_stable() {
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "id".to_string(),
+        Series::new_i32("id", vec![Some(1), Some(1), Some(1)]),
+    );
+    columns.insert(
+        "tag".to_string(),
+        Series::new_string(
+            "tag",
+            vec![Some("a".to_string()), None, Some("a".to_string())],
+        ),
+    );
+    let df = DataFrame::new(columns);
+
+    let hashes = df.row_hashes(None).unwrap();
+    assert_eq!(hashes.name(), "row_hash");
+    // Fixed FNV-1a values: the hash must not change across runs or machines.
+    assert_eq!(
+        hashes.get_value(0),
+        Some(Value::String("183deb1bcb4ddcc1".to_string()))
+    );
+    assert_eq!(
+        hashes.get_value(1),
+        Some(Value::String("07fd7bf1b8fb1567".to_string()))
+    );
+    assert_eq!(hashes.get_value(0), hashes.get_value(2));
+
+    let id_only = df.row_hashes(Some(&["id"])).unwrap();
+    assert_eq!(id_only.get_value(0), id_only.get_value(1));
+    assert!(df.row_hashes(Some(&["missing"])).is_err());
+}
+
+#[test]
+fn test_shrink_to_fit_releases_capacity() {
+    let mut values = Vec::with_capacity(1024);
+    values.extend([1, 2, 3]);
+    let mut bitmap = Vec::with_capacity(1024);
+    bitmap.extend([true, true, true]);
+
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "a".to_string(),
+        Series::I32("a".to_string(), values, bitmap),
+    );
+    let mut df = DataFrame::new(columns);
+
+    let reclaimed = df.shrink_to_fit();
+    assert!(reclaimed >= (1024 - 3) * std::mem::size_of::<i32>());
+    assert_eq!(
+        df.get_column("a").unwrap().get_value(2),
+        Some(Value::I32(3))
+    );
+    // A second call has nothing left to release.
+    assert_eq!(df.shrink_to_fit(), 0);
+}
+
+#[test]
+fn test_correlation_and_covariance_matrix_subset() {
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "x".to_string(),
+        Series::new_i32("x", vec![Some(1), Some(2), Some(3), None]),
+    );
+    columns.insert(
+        "label".to_string(),
+        Series::new_string(
+            "label",
+            vec![
+                Some("a".to_string()),
+                Some("b".to_string()),
+                Some("c".to_string()),
+                Some("d".to_string()),
+            ],
+        ),
+    );
+    columns.insert(
+        "y".to_string(),
+        Series::new_f64("y", vec![Some(2.0), Some(4.0), Some(6.0), Some(8.0)]),
+    );
+    columns.insert(
+        "z".to_string(),
+        Series::new_f64("z", vec![Some(3.0), Some(2.0), Some(1.0), Some(0.0)]),
+    );
+    let df = DataFrame::new(columns);
+
+    let corr = df.correlation_matrix(Some(&["z", "x"])).unwrap();
+    assert_eq!(corr.column_names(), vec!["column", "z", "x"]);
+    let z = corr.get_column("z").unwrap().get_data_f64().unwrap();
+    assert!((z[0].unwrap() - 1.0).abs() < 1e-12);
+    assert!((z[1].unwrap() + 1.0).abs() < 1e-12);
+
+    let all = df.covariance_matrix(None).unwrap();
+    assert_eq!(all.column_names(), vec!["column", "x", "y", "z"]);
+    assert_eq!(all.row_count(), 3);
+    // Pairwise deletion drops the null in `x`: cov(x, y) over the first three rows is 2.
+    assert_eq!(all.get_column("y").unwrap().get_f64(0), Some(2.0));
+
+    assert!(df.correlation_matrix(Some(&["x", "label"])).is_err());
+    assert!(df.correlation_matrix(Some(&["missing"])).is_err());
+
+    let mut labelled = df.clone();
+    labelled.add_column(Series::new_f64(
+        "column",
+        vec![Some(1.0), Some(2.0), Some(3.0), Some(4.0)],
+    ));
+    assert!(matches!(
+        labelled.covariance_matrix(None),
+        Err(veloxx::VeloxxError::InvalidOperation(_))
+    ));
+}
+
+#[test]
+fn test_describe_numeric_outputs_and_percentiles() {
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "age".to_string(),
+        Series::new_i32("age", vec![Some(20), Some(30), Some(25), None, Some(35)]),
+    );
+    columns.insert(
+        "city".to_string(),
+        Series::new_string(
+            "city",
+            vec![
+                Some("NY".to_string()),
+                None,
+                None,
+                None,
+                Some("LA".to_string()),
+            ],
+        ),
+    );
+    let df = DataFrame::new(columns);
+
+    let summary = df.describe().unwrap();
+    let names = summary.column_names();
+    assert_eq!(
+        names,
+        vec!["column", "count", "mean", "std", "min", "25%", "50%", "75%", "max"]
+    );
+    assert_eq!(
+        summary.get_column("count").unwrap().get_value(0),
+        Some(Value::I32(4))
+    );
+    assert_eq!(
+        summary.get_column("count").unwrap().get_value(1),
+        Some(Value::I32(2))
+    );
+    assert_eq!(summary.get_column("min").unwrap().get_f64(0), Some(20.0));
+    assert_eq!(summary.get_column("25%").unwrap().get_f64(0), Some(23.75));
+    assert_eq!(summary.get_column("max").unwrap().get_f64(0), Some(35.0));
+    assert_eq!(summary.get_column("max").unwrap().get_value(1), None);
+
+    let subset = df.describe_with(Some(&["age"]), &[0.0, 1.0]).unwrap();
+    assert_eq!(subset.row_count(), 1);
+    assert_eq!(subset.get_column("0%").unwrap().get_f64(0), Some(20.0));
+    assert_eq!(subset.get_column("100%").unwrap().get_f64(0), Some(35.0));
+
+    assert!(df.describe_with(Some(&["missing"]), &[0.5]).is_err());
+    assert!(df.describe_with(None, &[1.5]).is_err());
+}
+
+#[test]
+fn test_describe_treats_i64_as_numeric() {
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "bytes".to_string(),
+        Series::new_i64(
+            "bytes",
+            vec![Some(4_000_000_000), None, Some(6_000_000_000)],
+        ),
+    );
+    let df = DataFrame::new(columns);
+
+    let summary = df.describe().unwrap();
+    let column = |name: &str| summary.get_column(name).unwrap().get_f64(0);
+    assert_eq!(
+        summary.get_column("count").unwrap().get_value(0),
+        Some(Value::I32(2))
+    );
+    assert_eq!(column("mean"), Some(5_000_000_000.0));
+    assert_eq!(column("min"), Some(4_000_000_000.0));
+    assert_eq!(column("50%"), Some(5_000_000_000.0));
+    assert_eq!(column("max"), Some(6_000_000_000.0));
+}
+
+#[test]
+fn test_describe_keeps_datetime_and_rejects_clashing_percentiles() {
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "seen".to_string(),
+        Series::new_datetime("seen", vec![Some(1_000), None, Some(3_000)]),
+    );
+    let df = DataFrame::new(columns);
+
+    let summary = df.describe().unwrap();
+    let column = |name: &str| summary.get_column(name).unwrap().get_f64(0);
+    assert_eq!(column("mean"), Some(2_000.0));
+    assert_eq!(column("min"), Some(1_000.0));
+    assert_eq!(column("50%"), Some(2_000.0));
+    assert_eq!(column("max"), Some(3_000.0));
+
+    assert!(matches!(
+        df.describe_with(None, &[0.5, 0.5]),
+        Err(veloxx::VeloxxError::InvalidOperation(_))
+    ));
+    assert!(matches!(
+        df.describe_with(None, &[0.1231, 0.1234]),
+        Err(veloxx::VeloxxError::InvalidOperation(_))
+    ));
+}
+
+#[test]
+fn test_rename_column_preserves_position() {
+    let mut columns = IndexMap::new();
+    columns.insert("a".to_string(), Series::new_i32("a", vec![Some(1)]));
+    columns.insert("b".to_string(), Series::new_i32("b", vec![Some(2)]));
+    columns.insert("c".to_string(), Series::new_i32("c", vec![Some(3)]));
+    let df = DataFrame::new(columns);
+
+    let renamed = df.rename_column("a", "alpha").unwrap();
+    assert_eq!(renamed.column_names(), vec!["alpha", "b", "c"]);
+    assert_eq!(renamed.get_column("alpha").unwrap().name(), "alpha");
+
+    let unchanged = df.rename_column("b", "b").unwrap();
+    assert_eq!(unchanged.column_names(), vec!["a", "b", "c"]);
+
+    assert!(df.rename_column("a", "c").is_err());
+    assert!(df.rename_column("missing", "z").is_err());
+}
+
+#[test]
+fn test_append_aligned_matches_columns_by_name() {
+    let mut first = IndexMap::new();
+    first.insert("id".to_string(), Series::new_i32("id", vec![Some(1)]));
+    first.insert(
+        "name".to_string(),
+        Series::new_string("name", vec![Some("a".to_string())]),
+    );
+    let first = DataFrame::new(first);
+
+    let mut second = IndexMap::new();
+    second.insert(
+        "name".to_string(),
+        Series::new_string("name", vec![Some("b".to_string()), None]),
+    );
+    second.insert(
+        "id".to_string(),
+        Series::new_i32("id", vec![Some(2), Some(3)]),
+    );
+    let second = DataFrame::new(second);
+
+    let stacked = first.append_aligned(&second).unwrap();
+    assert_eq!(first.append(&second).unwrap().row_count(), 3);
+    assert_eq!(stacked.column_names(), vec!["id", "name"]);
+    assert_eq!(stacked.row_count(), 3);
+    assert_eq!(
+        stacked.get_column("id").unwrap().get_value(2),
+        Some(Value::I32(3))
+    );
+    assert_eq!(
+        stacked.get_column("name").unwrap().get_value(1),
+        Some(Value::String("b".to_string()))
+    );
+
+    let mut mismatched = IndexMap::new();
+    mismatched.insert("name".to_string(), Series::new_i32("name", vec![Some(1)]));
+    mismatched.insert("id".to_string(), Series::new_i32("id", vec![Some(4)]));
+    assert!(first.append_aligned(&DataFrame::new(mismatched)).is_err());
+
+    let mut missing = IndexMap::new();
+    missing.insert("id".to_string(), Series::new_i32("id", vec![Some(4)]));
+    missing.insert("other".to_string(), Series::new_i32("other", vec![Some(4)]));
+    assert!(first.append_aligned(&DataFrame::new(missing)).is_err());
+}
+
+#[test]
+fn test_to_records_row_maps_in_column_order() {
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "name".to_string(),
+        Series::new_string("name", vec![Some("a".to_string()), None]),
+    );
+    columns.insert(
+        "score".to_string(),
+        Series::new_f64("score", vec![None, Some(2.5)]),
+    );
+    let df = DataFrame::new(columns);
+
+    let records = df.to_records();
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].keys().collect::<Vec<_>>(), vec!["name", "score"]);
+    assert_eq!(records[0]["name"], Value::String("a".to_string()));
+    assert_eq!(records[0]["score"], Value::Null);
+    assert_eq!(records[1]["name"], Value::Null);
+    assert_eq!(records[1]["score"], Value::F64(2.5));
+
+    assert!(DataFrame::new(IndexMap::new()).to_records().is_empty());
+}
+
+#[test]
+fn test_with_thread_pool_bounds_parallelism() {
+    use veloxx::dataframe::join::JoinType;
+
+    let threads = DataFrame::with_thread_pool(2, rayon::current_num_threads).unwrap();
+    assert_eq!(threads, 2);
+
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "id".to_string(),
+        Series::new_i32("id", (0..1_000).map(Some).collect()),
+    );
+    let left = DataFrame::new(columns.clone());
+    let right = DataFrame::new(columns);
+    let joined = DataFrame::with_thread_pool(1, || left.join(&right, "id", JoinType::Inner))
+        .unwrap()
+        .unwrap();
+    assert_eq!(joined.row_count(), 1_000);
+
+    assert!(DataFrame::with_thread_pool(0, || ()).is_err());
+}
+
+#[test]
+fn test_from_csv_chunked_streams_all_rows() {
+    use std::io::Write;
+    use veloxx::types::DataType;
+
+    let mut file = tempfile::NamedTempFile::new().unwrap();
+    writeln!(file, "id,score,label").unwrap();
+    for i in 0..10_000 {
+        writeln!(file, "{},{},\"row, {}\"", i, i as f64 / 2.0, i).unwrap();
+    }
+    file.flush().unwrap();
+    let path = file.path().to_str().unwrap();
+
+    let mut total_rows = 0;
+    let mut chunks = 0;
+    DataFrame::from_csv_chunked(path, 1_000, |chunk| {
+        assert!(chunk.row_count() <= 1_000);
+        assert_eq!(chunk.get_column("id").unwrap().data_type(), DataType::I32);
+        // "0", "0.5", "1", ... infers F64 from the first chunk and keeps it for later ones.
+        assert_eq!(
+            chunk.get_column("score").unwrap().data_type(),
+            DataType::F64
+        );
+        let first_id = chunk.get_column("id").unwrap().get_i32(0).unwrap();
+        assert_eq!(first_id, total_rows as i32);
+        total_rows += chunk.row_count();
+        chunks += 1;
+        Ok(())
+    })
+    .unwrap();
+    assert_eq!(total_rows, 10_000);
+    assert_eq!(chunks, 10);
+
+    assert!(DataFrame::from_csv_chunked(path, 0, |_| Ok(())).is_err());
+}
+
+#[test]
+fn test_from_csv_chunked_rejects_values_outside_first_chunk_schema() {
+    let path = "test_chunked_schema.csv";
+    std::fs::write(path, "n\n1\n2\nthree\n").unwrap();
+
+    let mut seen = 0;
+    let result = DataFrame::from_csv_chunked(path, 2, |chunk| {
+        seen += chunk.row_count();
+        Ok(())
+    });
+    std::fs::remove_file(path).unwrap();
+
+    assert!(matches!(result, Err(veloxx::VeloxxError::Parsing(_))));
+    assert_eq!(seen, 2);
+}
+
+#[test]
+fn test_write_csv_to_buffer_matches_to_csv() {
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "name".to_string(),
+        Series::new_string("name", vec![Some("Smith, John".to_string()), None]),
+    );
+    columns.insert(
+        "score".to_string(),
+        Series::new_f64("score", vec![Some(1.5), Some(2.0)]),
+    );
+    let df = DataFrame::new(columns);
+
+    let mut buffer = Vec::new();
+    df.write_csv(&mut buffer).unwrap();
+    let written = String::from_utf8(buffer).unwrap();
+    assert_eq!(written, "name,score\n\"Smith, John\",1.5\n,2\n");
+
+    let path = "test_write_csv_buffer.csv";
+    df.to_csv(path).unwrap();
+    let from_file = std::fs::read_to_string(path).unwrap();
+    std::fs::remove_file(path).unwrap();
+    assert_eq!(from_file, written);
+}
+
+#[test]
+fn test_read_csv_from_in_memory_reader_round_trips_write_csv() {
+    let data = "id,name\n1,\"Smith, John\"\n2,\n";
+    let df = DataFrame::read_csv(data.as_bytes()).unwrap();
+    assert_eq!(df.row_count(), 2);
+    assert_eq!(
+        df.get_column("name").unwrap().get_value(0),
+        Some(Value::String("Smith, John".to_string()))
+    );
+    assert_eq!(df.get_column("name").unwrap().get_value(1), None);
+
+    let mut buffer = Vec::new();
+    df.write_csv(&mut buffer).unwrap();
+    let reread = DataFrame::read_csv(buffer.as_slice()).unwrap();
+    assert_eq!(reread.row_count(), 2);
+    assert_eq!(
+        reread.get_column("id").unwrap().get_value(1),
+        df.get_column("id").unwrap().get_value(1)
+    );
+    assert_eq!(
+        reread.get_column("name").unwrap().get_value(0),
+        Some(Value::String("Smith, John".to_string()))
+    );
+}
+
+#[test]
+fn test_coalesce_columns_takes_first_non_null() {
+    let phone = |name: &str, values: [Option<&str>; 3]| {
+        Series::new_string(
+            name,
+            values.iter().map(|v| v.map(|s| s.to_string())).collect(),
+        )
+    };
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "id".to_string(),
+        Series::new_i32("id", vec![Some(1), Some(2), Some(3)]),
+    );
+    columns.insert(
+        "phone_home".to_string(),
+        phone("phone_home", [Some("h1"), None, None]),
+    );
+    columns.insert(
+        "phone_work".to_string(),
+        phone("phone_work", [Some("w1"), None, Some("w3")]),
+    );
+    columns.insert(
+        "phone_mobile".to_string(),
+        phone("phone_mobile", [None, None, Some("m3")]),
+    );
+    let df = DataFrame::new(columns);
+    let sources = ["phone_home", "phone_work", "phone_mobile"];
+
+    let merged = df.coalesce_columns(&sources, "phone", true).unwrap();
+    assert_eq!(merged.column_names(), vec!["id", "phone"]);
+    let values: Vec<Option<Value>> = (0..3)
+        .map(|i| merged.get_column("phone").unwrap().get_value(i))
+        .collect();
+    assert_eq!(
+        values,
+        vec![
+            Some(Value::String("h1".to_string())),
+            None,
+            Some(Value::String("w3".to_string())),
+        ]
+    );
+
+    let kept = df.coalesce_columns(&sources, "phone", false).unwrap();
+    assert_eq!(kept.column_count(), 5);
+    assert_eq!(kept.column_names()[4], "phone");
+    assert!(df.coalesce_columns(&sources, "id", false).is_err());
+    assert!(df
+        .coalesce_columns(&["id", "phone_home"], "x", false)
+        .is_err());
+}
+
+#[test]
+fn test_coalesce_columns_widens_numeric_types() {
+    let mut columns = IndexMap::new();
+    columns.insert("a".to_string(), Series::new_i32("a", vec![Some(1), None]));
+    columns.insert(
+        "b".to_string(),
+        Series::new_f64("b", vec![Some(9.5), Some(2.5)]),
+    );
+    let df = DataFrame::new(columns);
+
+    let merged = df.coalesce_columns(&["a", "b"], "a", true).unwrap();
+    let a = merged.get_column("a").unwrap();
+    assert_eq!(a.get_value(0), Some(Value::F64(1.0)));
+    assert_eq!(a.get_value(1), Some(Value::F64(2.5)));
+}
+
+#[test]
+fn test_sample_is_reproducible_with_seed() {
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "id".to_string(),
+        Series::new_i32("id", (0..50).map(Some).collect()),
+    );
+    let df = DataFrame::new(columns);
+    let ids = |sample: &DataFrame| -> Vec<Option<Value>> {
+        let id = sample.get_column("id").unwrap();
+        (0..sample.row_count()).map(|i| id.get_value(i)).collect()
+    };
+
+    let first = df.sample(20, Some(1234)).unwrap();
+    let second = df.sample(20, Some(1234)).unwrap();
+    assert_eq!(first.row_count(), 20);
+    assert_eq!(ids(&first), ids(&second));
+
+    let mut unique = ids(&first);
+    unique.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    unique.dedup();
+    assert_eq!(unique.len(), 20);
+
+    assert_eq!(df.sample(500, Some(1)).unwrap().row_count(), 50);
+    assert_eq!(df.sample_frac(0.1, Some(1)).unwrap().row_count(), 5);
+    assert!(df.sample_frac(-0.1, None).is_err());
+    assert!(df.sample_frac(f64::NAN, None).is_err());
+}
+
+#[test]
+fn test_sort_by_mixed_directions_breaks_ties_descending() {
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "date".to_string(),
+        Series::new_string(
+            "date",
+            ["2024-01-02", "2024-01-01", "2024-01-02", "2024-01-01"]
+                .iter()
+                .map(|s| Some(s.to_string()))
+                .collect(),
+        ),
+    );
+    columns.insert(
+        "score".to_string(),
+        Series::new_i32("score", vec![Some(7), Some(3), Some(9), None]),
+    );
+    let df = DataFrame::new(columns);
+
+    let sorted = df
+        .sort_by(&[("date".to_string(), true), ("score".to_string(), false)])
+        .unwrap();
+    let score = sorted.get_column("score").unwrap();
+    let scores: Vec<Option<Value>> = (0..4).map(|i| score.get_value(i)).collect();
+    // Within each date, scores descend and the null score sorts last.
+    assert_eq!(
+        scores,
+        vec![
+            Some(Value::I32(3)),
+            None,
+            Some(Value::I32(9)),
+            Some(Value::I32(7)),
+        ]
+    );
+    assert_eq!(
+        sorted.get_column("date").unwrap().get_value(0),
+        Some(Value::String("2024-01-01".to_string()))
+    );
+    assert!(df.sort_by(&[("missing".to_string(), true)]).is_err());
+}
+
+#[test]
+fn test_assert_sorted_reports_first_out_of_order_row() {
+    use veloxx::VeloxxError;
+
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "ts".to_string(),
+        Series::new_i32(
+            "ts",
+            vec![None, Some(1), Some(4), Some(4), Some(2), Some(0)],
+        ),
+    );
+    let df = DataFrame::new(columns);
+
+    match df.assert_sorted("ts", true) {
+        Err(VeloxxError::InvalidOperation(message)) => assert!(message.contains("row 4")),
+        other => panic!("expected an out-of-order error, got {other:?}"),
+    }
+    assert!(df.head(4).unwrap().assert_sorted("ts", true).is_ok());
+    assert!(df.assert_sorted("ts", false).is_err());
+
+    // The output of sort always passes, with nulls last when descending.
+    let descending = df.sort(vec!["ts".to_string()], false).unwrap();
+    assert!(descending.assert_sorted("ts", false).is_ok());
+    assert!(matches!(
+        df.assert_sorted("missing", true),
+        Err(VeloxxError::ColumnNotFound(_))
+    ));
+
+    // NaN cannot be ordered, even though `Value` sorts it after every other number.
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "x".to_string(),
+        Series::new_f64("x", vec![Some(1.0), Some(f64::NAN)]),
+    );
+    let with_nan = DataFrame::new(columns);
+    assert!(with_nan.assert_sorted("x", true).is_err());
+    assert!(with_nan.assert_sorted("x", false).is_err());
+}
+
+#[test]
+fn test_sort_with_nulls_controls_null_placement() {
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "score".to_string(),
+        Series::new_f64("score", vec![Some(2.0), None, Some(5.0), None, Some(1.0)]),
+    );
+    let df = DataFrame::new(columns);
+    let scores = |sorted: DataFrame| -> Vec<Option<Value>> {
+        let score = sorted.get_column("score").unwrap();
+        (0..score.len()).map(|i| score.get_value(i)).collect()
+    };
+    let by = || vec!["score".to_string()];
+
+    assert_eq!(
+        scores(df.sort_with_nulls(by(), false, true).unwrap()),
+        vec![
+            Some(Value::F64(5.0)),
+            Some(Value::F64(2.0)),
+            Some(Value::F64(1.0)),
+            None,
+            None,
+        ]
+    );
+    assert_eq!(
+        scores(df.sort_with_nulls(by(), true, true).unwrap()),
+        vec![
+            Some(Value::F64(1.0)),
+            Some(Value::F64(2.0)),
+            Some(Value::F64(5.0)),
+            None,
+            None,
+        ]
+    );
+    assert_eq!(
+        scores(df.sort_with_nulls(by(), false, false).unwrap()),
+        vec![
+            None,
+            None,
+            Some(Value::F64(5.0)),
+            Some(Value::F64(2.0)),
+            Some(Value::F64(1.0)),
+        ]
+    );
+    // `sort` keeps nulls first when ascending.
+    assert_eq!(scores(df.sort(by(), true).unwrap())[0], None);
+
+    // The single-I32 fast path honours the flag too.
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "n".to_string(),
+        Series::new_i32("n", vec![None, Some(3), Some(1)]),
+    );
+    let ints = DataFrame::new(columns)
+        .sort_with_nulls(vec!["n".to_string()], true, true)
+        .unwrap();
+    assert_eq!(
+        ints.get_column("n").unwrap().get_value(0),
+        Some(Value::I32(1))
+    );
+    assert_eq!(ints.get_column("n").unwrap().get_value(2), None);
+}
+
+#[test]
+fn test_clip_numeric_bounds_numeric_columns_only() {
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "qty".to_string(),
+        Series::new_i32("qty", vec![Some(-4), Some(3), None, Some(12)]),
+    );
+    columns.insert(
+        "price".to_string(),
+        Series::new_f64("price", vec![Some(-0.5), Some(9.75), Some(10.5), None]),
+    );
+    columns.insert(
+        "sku".to_string(),
+        Series::new_string(
+            "sku",
+            vec![Some("a".to_string()), None, Some("c".to_string()), None],
+        ),
+    );
+    let df = DataFrame::new(columns);
+
+    let clipped = df.clip_numeric(Some(0.0), Some(10.0)).unwrap();
+    assert_eq!(
+        clipped.get_column("qty").unwrap(),
+        &Series::new_i32("qty", vec![Some(0), Some(3), None, Some(10)])
+    );
+    assert_eq!(
+        clipped.get_column("price").unwrap(),
+        &Series::new_f64("price", vec![Some(0.0), Some(9.75), Some(10.0), None])
+    );
+    assert_eq!(clipped.get_column("sku"), df.get_column("sku"));
+
+    let upper_only = df.clip_numeric(None, Some(1.0)).unwrap();
+    assert_eq!(
+        upper_only.get_column("qty").unwrap().get_value(0),
+        Some(Value::I32(-4))
+    );
+
+    assert!(matches!(
+        df.clip_numeric(Some(5.0), Some(1.0)),
+        Err(veloxx::VeloxxError::InvalidOperation(_))
+    ));
+}
+
+#[test]
+fn test_drop_duplicates_over_subset_keeps_first() {
+    use veloxx::dataframe::cleaning::Keep;
+
+    let strings = |values: &[&str]| -> Vec<Option<String>> {
+        values.iter().map(|v| Some(v.to_string())).collect()
+    };
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "name".to_string(),
+        Series::new_string("name", strings(&["ann", "bob", "ann", "ann", "bob"])),
+    );
+    columns.insert(
+        "city".to_string(),
+        Series::new_string("city", strings(&["rome", "oslo", "rome", "oslo", "oslo"])),
+    );
+    columns.insert(
+        "visit".to_string(),
+        Series::new_i32("visit", vec![Some(1), Some(2), Some(3), Some(4), Some(5)]),
+    );
+    let df = DataFrame::new(columns);
+    let subset = ["name".to_string(), "city".to_string()];
+    let visits = |df: &DataFrame| -> Vec<Option<Value>> {
+        let series = df.get_column("visit").unwrap();
+        (0..df.row_count()).map(|i| series.get_value(i)).collect()
+    };
+
+    let first = df.drop_duplicates(Some(&subset), Keep::First).unwrap();
+    assert_eq!(
+        visits(&first),
+        vec![
+            Some(Value::I32(1)),
+            Some(Value::I32(2)),
+            Some(Value::I32(4))
+        ]
+    );
+
+    let last = df.drop_duplicates(Some(&subset), Keep::Last).unwrap();
+    assert_eq!(
+        visits(&last),
+        vec![
+            Some(Value::I32(3)),
+            Some(Value::I32(4)),
+            Some(Value::I32(5))
+        ]
+    );
+
+    assert_eq!(
+        df.drop_duplicates(None, Keep::First).unwrap().row_count(),
+        5
+    );
+    assert!(matches!(
+        df.drop_duplicates(Some(&["zip".to_string()]), Keep::First),
+        Err(veloxx::VeloxxError::ColumnNotFound(_))
+    ));
+}
+
+#[test]
+fn test_sort_places_nan_after_numbers() {
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "x".to_string(),
+        Series::new_f64("x", vec![Some(f64::NAN), Some(2.0), None, Some(-1.0)]),
+    );
+    let df = DataFrame::new(columns);
+
+    let sorted = df.sort(vec!["x".to_string()], true).unwrap();
+    let x = sorted.get_column("x").unwrap();
+    assert_eq!(x.get_value(0), None);
+    assert_eq!(x.get_value(1), Some(Value::F64(-1.0)));
+    assert_eq!(x.get_value(2), Some(Value::F64(2.0)));
+    assert!(matches!(x.get_value(3), Some(Value::F64(v)) if v.is_nan()));
+}
+
+#[test]
+fn test_weighted_sample_respects_weights() {
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "id".to_string(),
+        Series::new_i32("id", (0..40).map(Some).collect()),
+    );
+    // Only even rows carry weight; odd rows are zero or null and must never be picked.
+    columns.insert(
+        "weight".to_string(),
+        Series::new_i32(
+            "weight",
+            (0..40)
+                .map(|i| match i % 4 {
+                    0 => Some(1),
+                    2 => Some(100),
+                    1 => Some(0),
+                    _ => None,
+                })
+                .collect(),
+        ),
+    );
+    let df = DataFrame::new(columns);
+    let ids = |sample: &DataFrame| -> Vec<i32> {
+        let id = sample.get_column("id").unwrap();
+        (0..sample.row_count())
+            .map(|i| match id.get_value(i) {
+                Some(Value::I32(v)) => v,
+                other => panic!("unexpected id {other:?}"),
+            })
+            .collect()
+    };
+
+    let first = df.weighted_sample(10, "weight", 99).unwrap();
+    assert_eq!(
+        ids(&first),
+        ids(&df.weighted_sample(10, "weight", 99).unwrap())
+    );
+    let picked = ids(&first);
+    assert_eq!(picked.len(), 10);
+    assert!(picked.iter().all(|id| id % 2 == 0));
+    // Heavily weighted rows dominate a small sample.
+    assert!(picked.iter().filter(|id| *id % 4 == 2).count() >= 8);
+
+    // Only 20 rows have a positive weight, each picked at most once.
+    let mut all = ids(&df.weighted_sample(100, "weight", 1).unwrap());
+    all.sort();
+    all.dedup();
+    assert_eq!(all.len(), 20);
+
+    let mut negative = IndexMap::new();
+    negative.insert(
+        "weight".to_string(),
+        Series::new_f64("weight", vec![Some(1.0), Some(-2.0)]),
+    );
+    assert!(matches!(
+        DataFrame::new(negative).weighted_sample(1, "weight", 0),
+        Err(veloxx::VeloxxError::InvalidOperation(_))
+    ));
+    assert!(matches!(
+        df.weighted_sample(1, "missing", 0),
+        Err(veloxx::VeloxxError::ColumnNotFound(_))
+    ));
+}
+
+#[test]
+fn test_fill_nulls_forward_and_backward() {
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "a".to_string(),
+        Series::new_i32("a", vec![Some(1), None, None, Some(4)]),
+    );
+    columns.insert(
+        "label".to_string(),
+        Series::new_string("label", vec![None, Some("x".to_string()), None, None]),
+    );
+    let df = DataFrame::new(columns);
+    let column = |df: &DataFrame, name: &str| -> Vec<Option<Value>> {
+        let series = df.get_column(name).unwrap();
+        (0..df.row_count()).map(|i| series.get_value(i)).collect()
+    };
+    let x = || Some(Value::String("x".to_string()));
+
+    let forward = df.fill_nulls_forward(None).unwrap();
+    assert_eq!(
+        column(&forward, "a"),
+        vec![
+            Some(Value::I32(1)),
+            Some(Value::I32(1)),
+            Some(Value::I32(1)),
+            Some(Value::I32(4))
+        ]
+    );
+    assert_eq!(column(&forward, "label"), vec![None, x(), x(), x()]);
+
+    let backward = df.fill_nulls_backward(None).unwrap();
+    assert_eq!(
+        column(&backward, "a"),
+        vec![
+            Some(Value::I32(1)),
+            Some(Value::I32(4)),
+            Some(Value::I32(4)),
+            Some(Value::I32(4))
+        ]
+    );
+    assert_eq!(column(&backward, "label"), vec![x(), x(), None, None]);
+
+    let only_a = df.fill_nulls_forward(Some(&["a".to_string()])).unwrap();
+    assert_eq!(only_a.get_column("label"), df.get_column("label"));
+    assert!(matches!(
+        df.fill_nulls_backward(Some(&["b".to_string()])),
+        Err(veloxx::VeloxxError::ColumnNotFound(_))
+    ));
+}
+
+#[test]
+fn test_get_value_by_row_and_column() {
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "name".to_string(),
+        Series::new_string("name", vec![Some("ada".to_string()), None]),
+    );
+    columns.insert(
+        "score".to_string(),
+        Series::new_f64("score", vec![Some(9.5), Some(7.0)]),
+    );
+    let df = DataFrame::new(columns);
+
+    assert_eq!(df.get_value(1, "score").unwrap(), Some(Value::F64(7.0)));
+    assert_eq!(
+        df.get_value(0, "name").unwrap(),
+        Some(Value::String("ada".to_string()))
+    );
+    assert_eq!(df.get_value(1, "name").unwrap(), None);
+    assert!(matches!(
+        df.get_value(0, "age"),
+        Err(veloxx::VeloxxError::ColumnNotFound(name)) if name == "age"
+    ));
+    assert!(matches!(
+        df.get_value(2, "score"),
+        Err(veloxx::VeloxxError::InvalidOperation(_))
+    ));
+}
+
+#[test]
+fn test_duplicate_rows_marks_later_occurrences() {
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "name".to_string(),
+        Series::new_string(
+            "name",
+            vec![Some("ann".to_string()), None, Some("ann".to_string()), None],
+        ),
+    );
+    columns.insert(
+        "visit".to_string(),
+        Series::new_i32("visit", vec![Some(1), Some(2), Some(3), Some(4)]),
+    );
+    let df = DataFrame::new(columns);
+
+    let mask = df.duplicate_rows(Some(&["name".to_string()])).unwrap();
+    assert_eq!(mask.name(), "is_duplicate");
+    let flags: Vec<Option<Value>> = (0..mask.len()).map(|i| mask.get_value(i)).collect();
+    assert_eq!(
+        flags,
+        vec![
+            Some(Value::Bool(false)),
+            Some(Value::Bool(false)),
+            Some(Value::Bool(true)),
+            Some(Value::Bool(true)),
+        ]
+    );
+
+    let duplicates = df.filter_by_mask(&mask).unwrap();
+    let visits = duplicates.get_column("visit").unwrap();
+    assert_eq!(visits.get_value(0), Some(Value::I32(3)));
+    assert_eq!(visits.get_value(1), Some(Value::I32(4)));
+
+    let all_columns = df.duplicate_rows(None).unwrap();
+    assert!((0..all_columns.len()).all(|i| all_columns.get_value(i) == Some(Value::Bool(false))));
+    assert!(matches!(
+        df.duplicate_rows(Some(&["city".to_string()])),
+        Err(veloxx::VeloxxError::ColumnNotFound(_))
+    ));
+}
+
+#[test]
+fn test_standardize_fits_and_transforms_columns() {
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "age".to_string(),
+        Series::new_i32("age", vec![Some(20), Some(30), None, Some(40)]),
+    );
+    columns.insert(
+        "name".to_string(),
+        Series::new_string(
+            "name",
+            vec![
+                Some("a".to_string()),
+                Some("b".to_string()),
+                Some("c".to_string()),
+                Some("d".to_string()),
+            ],
+        ),
+    );
+    columns.insert(
+        "flat".to_string(),
+        Series::new_f64("flat", vec![Some(1.0), Some(1.0), Some(1.0), None]),
+    );
+    let train = DataFrame::new(columns);
+
+    let (scaled, fitted) = train.fit_standardize(&["age"]).unwrap();
+    assert_eq!(scaled.column_names(), vec!["age", "name", "flat"]);
+    assert_eq!(
+        scaled.get_column("age").unwrap().get_data_f64().unwrap(),
+        vec![Some(-1.0), Some(0.0), None, Some(1.0)]
+    );
+    assert_eq!(scaled.get_column("name"), train.get_column("name"));
+    assert_eq!(fitted.params.get("age"), Some(&(30.0, 10.0)));
+    assert_eq!(
+        train.standardize(&["age"]).unwrap().get_column("age"),
+        scaled.get_column("age")
+    );
+
+    let mut test_columns = IndexMap::new();
+    test_columns.insert(
+        "age".to_string(),
+        Series::new_i32("age", vec![Some(55), Some(25)]),
+    );
+    let test = fitted.transform(&DataFrame::new(test_columns)).unwrap();
+    assert_eq!(
+        test.get_column("age").unwrap().get_data_f64().unwrap(),
+        vec![Some(2.5), Some(-0.5)]
+    );
+
+    assert!(matches!(
+        train.standardize(&["name"]),
+        Err(veloxx::VeloxxError::Unsupported(_))
+    ));
+    assert!(matches!(
+        train.standardize(&["flat"]),
+        Err(veloxx::VeloxxError::InvalidOperation(_))
+    ));
+    assert!(matches!(
+        train.standardize(&["height"]),
+        Err(veloxx::VeloxxError::ColumnNotFound(_))
+    ));
+}
+
+#[test]
+fn test_get_column_mut_and_set_column() {
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "id".to_string(),
+        Series::new_i32("id", vec![Some(1), Some(2)]),
+    );
+    columns.insert(
+        "score".to_string(),
+        Series::new_f64("score", vec![Some(0.5), None]),
+    );
+    let mut df = DataFrame::new(columns);
+
+    let score = df.get_column_mut("score").unwrap();
+    *score = score.fill_nulls(&Value::F64(0.0)).unwrap();
+    assert_eq!(df.get_value(1, "score").unwrap(), Some(Value::F64(0.0)));
+    assert!(df.get_column_mut("missing").is_none());
+
+    // Replacing keeps the column's position; a new name is appended.
+    df.set_column(Series::new_i32("id", vec![Some(10), Some(20)]));
+    df.set_column(Series::new_bool("flag", vec![Some(true), Some(false)]));
+    assert_eq!(df.column_names(), vec!["id", "score", "flag"]);
+    assert_eq!(df.get_value(0, "id").unwrap(), Some(Value::I32(10)));
+}
+
+#[test]
+fn test_insert_and_replace_column_check_the_frame() {
+    let mut df = DataFrame::new(IndexMap::new());
+    df.insert_column(Series::new_i32("id", vec![Some(1), Some(2), Some(3)]))
+        .unwrap();
+    df.insert_column(Series::new_string(
+        "name",
+        vec![Some("a".to_string()), None, Some("c".to_string())],
+    ))
+    .unwrap();
+    df.insert_column(Series::new_f64("score", vec![Some(1.0), Some(2.0), None]))
+        .unwrap();
+
+    assert!(matches!(
+        df.insert_column(Series::new_i32("id", vec![Some(7), Some(8), Some(9)])),
+        Err(veloxx::VeloxxError::InvalidOperation(_))
+    ));
+    assert!(matches!(
+        df.insert_column(Series::new_i32("extra", vec![Some(1)])),
+        Err(veloxx::VeloxxError::InvalidOperation(_))
+    ));
+    assert!(matches!(
+        df.replace_column(Series::new_i32("missing", vec![Some(1), Some(2), Some(3)])),
+        Err(veloxx::VeloxxError::ColumnNotFound(_))
+    ));
+    assert!(matches!(
+        df.replace_column(Series::new_string("name", vec![None, None])),
+        Err(veloxx::VeloxxError::InvalidOperation(_))
+    ));
+
+    df.replace_column(Series::new_bool(
+        "name",
+        vec![Some(true), Some(false), None],
+    ))
+    .unwrap();
+    assert_eq!(df.column_names(), vec!["id", "name", "score"]);
+    assert_eq!(df.get_value(0, "name").unwrap(), Some(Value::Bool(true)));
+    assert_eq!(df.row_count(), 3);
+}
+
+#[test]
+fn test_from_series_keeps_order_and_validates() {
+    let df = DataFrame::from_series(vec![
+        Series::new_f64("z", vec![Some(1.5), None]),
+        Series::new_i32("a", vec![Some(1), Some(2)]),
+        Series::new_bool("m", vec![None, Some(true)]),
+    ])
+    .unwrap();
+    assert_eq!(df.column_names(), vec!["z", "a", "m"]);
+    assert_eq!(df.row_count(), 2);
+    assert_eq!(df.get_value(1, "m").unwrap(), Some(Value::Bool(true)));
+
+    assert_eq!(DataFrame::from_series(vec![]).unwrap().column_count(), 0);
+    assert!(matches!(
+        DataFrame::from_series(vec![
+            Series::new_i32("a", vec![Some(1)]),
+            Series::new_i32("a", vec![Some(2)]),
+        ]),
+        Err(veloxx::VeloxxError::InvalidOperation(_))
+    ));
+    assert!(matches!(
+        DataFrame::from_series(vec![
+            Series::new_i32("a", vec![Some(1)]),
+            Series::new_i32("b", vec![Some(2), Some(3)]),
+        ]),
+        Err(veloxx::VeloxxError::InvalidOperation(_))
+    ));
+}
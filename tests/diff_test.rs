@@ -0,0 +1,72 @@
+use indexmap::IndexMap;
+use veloxx::dataframe::DataFrame;
+use veloxx::series::Series;
+use veloxx::types::Value;
+
+fn snapshot(ids: Vec<i32>, names: Vec<&str>, prices: Vec<f64>) -> DataFrame {
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "id".to_string(),
+        Series::new_i32("id", ids.into_iter().map(Some).collect()),
+    );
+    columns.insert(
+        "name".to_string(),
+        Series::new_string(
+            "name",
+            names.into_iter().map(|n| Some(n.to_string())).collect(),
+        ),
+    );
+    columns.insert(
+        "price".to_string(),
+        Series::new_f64("price", prices.into_iter().map(Some).collect()),
+    );
+    DataFrame::new(columns)
+}
+
+#[test]
+fn test_diff_against_classifies_keys() {
+    let old = snapshot(vec![1, 2, 3], vec!["a", "b", "c"], vec![1.0, 2.0, 3.0]);
+    let new = snapshot(vec![2, 3, 4], vec!["b", "C", "d"], vec![2.5, 3.0, 4.0]);
+
+    let changes = new.diff_against(&old, &["id"]).unwrap();
+
+    assert_eq!(changes.added.row_count(), 1);
+    assert_eq!(
+        changes.added.get_column("id").unwrap().get_value(0),
+        Some(Value::I32(4))
+    );
+
+    assert_eq!(changes.removed.row_count(), 1);
+    assert_eq!(
+        changes.removed.get_column("id").unwrap().get_value(0),
+        Some(Value::I32(1))
+    );
+
+    assert_eq!(changes.changed.row_count(), 2);
+    let changed_ids = changes.changed.get_column("id").unwrap();
+    assert_eq!(changed_ids.get_value(0), Some(Value::I32(2)));
+    assert_eq!(changed_ids.get_value(1), Some(Value::I32(3)));
+    assert_eq!(
+        changes.changed_columns,
+        vec![vec!["price".to_string()], vec!["name".to_string()]]
+    );
+}
+
+#[test]
+fn test_diff_against_identical_frames() {
+    let old = snapshot(vec![1, 2], vec!["a", "b"], vec![1.0, 2.0]);
+    let changes = old.diff_against(&old.clone(), &["id"]).unwrap();
+    assert_eq!(changes.added.row_count(), 0);
+    assert_eq!(changes.removed.row_count(), 0);
+    assert_eq!(changes.changed.row_count(), 0);
+    // Empty results keep the schema of the compared frames.
+    assert_eq!(changes.added.column_count(), 3);
+}
+
+#[test]
+fn test_diff_against_duplicate_key() {
+    let old = snapshot(vec![1, 1], vec!["a", "b"], vec![1.0, 2.0]);
+    let new = snapshot(vec![1], vec!["a"], vec![1.0]);
+    assert!(new.diff_against(&old, &["id"]).is_err());
+    assert!(new.diff_against(&old, &["missing"]).is_err());
+}
@@ -148,3 +148,47 @@ fn test_arithmetic_expression_evaluation() {
     let result = mul_expr.evaluate(&df, 0).unwrap();
     assert_eq!(result, Value::I32(50));
 }
+
+#[test]
+fn test_cast_expression_in_with_column() {
+    use veloxx::types::DataType;
+
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "total".to_string(),
+        Series::new_i32("total", vec![Some(7), Some(9), Some(5)]),
+    );
+    columns.insert(
+        "count".to_string(),
+        Series::new_f64("count", vec![Some(2.0), Some(4.0), Some(1.0)]),
+    );
+    columns.insert(
+        "code".to_string(),
+        Series::new_string(
+            "code",
+            vec![Some("1.5".to_string()), Some("n/a".to_string()), None],
+        ),
+    );
+    let df = DataFrame::new(columns);
+
+    let average = Expr::Divide(
+        Box::new(Expr::Cast(
+            Box::new(Expr::Column("total".to_string())),
+            DataType::F64,
+        )),
+        Box::new(Expr::Column("count".to_string())),
+    );
+    let with_average = df.with_column("average", &average).unwrap();
+    let column = with_average.get_column("average").unwrap();
+    assert_eq!(column.data_type(), DataType::F64);
+    assert_eq!(column.get_value(0), Some(Value::F64(3.5)));
+    assert_eq!(column.get_value(1), Some(Value::F64(2.25)));
+
+    // Unparseable strings become null; unsupported conversions are errors.
+    let parsed = Expr::Cast(Box::new(Expr::Column("code".to_string())), DataType::F64);
+    assert_eq!(parsed.evaluate(&df, 0).unwrap(), Value::F64(1.5));
+    assert_eq!(parsed.evaluate(&df, 1).unwrap(), Value::Null);
+    assert_eq!(parsed.evaluate(&df, 2).unwrap(), Value::Null);
+    let unsupported = Expr::Cast(Box::new(Expr::Literal(Value::DateTime(0))), DataType::Bool);
+    assert!(unsupported.evaluate(&df, 0).is_err());
+}
@@ -41,3 +41,517 @@ fn test_complex_expression() {
 
     // assert_eq!(result, Series::new_bool("a", vec![Some(true), Some(true)]));
 }
+
+#[test]
+fn test_insert_column_at_position() {
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "a".to_string(),
+        Series::new_i32("a", vec![Some(1), Some(2)]),
+    );
+    columns.insert(
+        "b".to_string(),
+        Series::new_i32("b", vec![Some(3), Some(4)]),
+    );
+    let df = DataFrame::new(columns);
+    let expr = Expr::Multiply(
+        Box::new(Expr::Column("a".to_string())),
+        Box::new(Expr::Column("b".to_string())),
+    );
+
+    let first = df.insert_column_at(0, "ab", &expr).unwrap();
+    assert_eq!(
+        first.column_names(),
+        vec!["ab".to_string(), "a".to_string(), "b".to_string()]
+    );
+    assert_eq!(
+        first.get_column("ab").unwrap(),
+        &Series::new_i32("ab", vec![Some(3), Some(8)])
+    );
+
+    let middle = df.insert_column_at(1, "ab", &expr).unwrap();
+    assert_eq!(
+        middle.column_names(),
+        vec!["a".to_string(), "ab".to_string(), "b".to_string()]
+    );
+
+    let clamped = df.insert_column_at(99, "ab", &expr).unwrap();
+    assert_eq!(
+        clamped.column_names(),
+        vec!["a".to_string(), "b".to_string(), "ab".to_string()]
+    );
+
+    assert!(df.insert_column_at(0, "a", &expr).is_err());
+}
+
+#[test]
+fn test_sort_by_expr() {
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "name".to_string(),
+        Series::new_string(
+            "name",
+            vec![
+                Some("a".to_string()),
+                Some("b".to_string()),
+                Some("c".to_string()),
+                Some("d".to_string()),
+            ],
+        ),
+    );
+    columns.insert(
+        "open".to_string(),
+        Series::new_i32("open", vec![Some(10), Some(20), Some(30), Some(5)]),
+    );
+    columns.insert(
+        "close".to_string(),
+        Series::new_i32("close", vec![Some(12), Some(11), Some(29), Some(3)]),
+    );
+    let df = DataFrame::new(columns);
+
+    let delta = Expr::Subtract(
+        Box::new(Expr::Column("open".to_string())),
+        Box::new(Expr::Column("close".to_string())),
+    );
+    // Deltas are -2, 9, 1, 2: losers first, then gains from largest to smallest.
+    let sorted = df
+        .sort_by_expr(vec![
+            (
+                Expr::GreaterThan(
+                    Box::new(delta.clone()),
+                    Box::new(Expr::Literal(veloxx::types::Value::I32(0))),
+                ),
+                true,
+            ),
+            (delta, false),
+        ])
+        .unwrap();
+    let names = sorted
+        .get_column("name")
+        .unwrap()
+        .get_data_string()
+        .unwrap();
+    assert_eq!(
+        names,
+        vec![
+            Some("a".to_string()),
+            Some("b".to_string()),
+            Some("d".to_string()),
+            Some("c".to_string()),
+        ]
+    );
+    // The helper keys are not materialized.
+    assert_eq!(sorted.column_count(), 3);
+
+    assert!(df
+        .sort_by_expr(vec![(Expr::Column("missing".to_string()), true)])
+        .is_err());
+}
+
+#[test]
+fn test_sort_by_expr_null_keys() {
+    use veloxx::types::Value;
+
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "x".to_string(),
+        Series::new_i32("x", vec![Some(3), None, Some(1)]),
+    );
+    let df = DataFrame::new(columns);
+    let x = || Box::new(Expr::Column("x".to_string()));
+
+    let ascending = df.sort_by_expr(vec![(Expr::Column("x".to_string()), true)]);
+    assert_eq!(
+        ascending
+            .unwrap()
+            .get_column("x")
+            .unwrap()
+            .get_data_i32()
+            .unwrap(),
+        vec![None, Some(1), Some(3)]
+    );
+    let descending = df.sort_by_expr(vec![(Expr::Column("x".to_string()), false)]);
+    assert_eq!(
+        descending
+            .unwrap()
+            .get_column("x")
+            .unwrap()
+            .get_data_i32()
+            .unwrap(),
+        vec![Some(3), Some(1), None]
+    );
+
+    // Arithmetic does not accept a null operand unless it is given a value first.
+    let shifted = Expr::Subtract(x(), Box::new(Expr::Literal(Value::I32(1))));
+    assert!(df.sort_by_expr(vec![(shifted, true)]).is_err());
+    let filled = Expr::Subtract(
+        Box::new(Expr::IfNull(x(), Box::new(Expr::Literal(Value::I32(2))))),
+        Box::new(Expr::Literal(Value::I32(1))),
+    );
+    assert_eq!(
+        df.sort_by_expr(vec![(filled, true)])
+            .unwrap()
+            .get_column("x")
+            .unwrap()
+            .get_data_i32()
+            .unwrap(),
+        vec![Some(1), None, Some(3)]
+    );
+}
+
+#[test]
+fn test_null_aware_expressions() {
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "x".to_string(),
+        Series::new_i32("x", vec![Some(5), None, Some(7)]),
+    );
+    let df = DataFrame::new(columns);
+    let x = || Box::new(Expr::Column("x".to_string()));
+
+    let df = df
+        .with_column(
+            "safe",
+            &Expr::IfNull(x(), Box::new(Expr::Literal(veloxx::types::Value::I32(0)))),
+        )
+        .unwrap()
+        .with_column("missing", &Expr::IsNull(x()))
+        .unwrap()
+        .with_column("present", &Expr::IsNotNull(x()))
+        .unwrap();
+
+    assert_eq!(
+        df.get_column("safe").unwrap().get_data_i32().unwrap(),
+        vec![Some(5), Some(0), Some(7)]
+    );
+    assert_eq!(
+        df.get_column("missing").unwrap().get_data_bool().unwrap(),
+        vec![Some(false), Some(true), Some(false)]
+    );
+    assert_eq!(
+        df.get_column("present").unwrap().get_data_bool().unwrap(),
+        vec![Some(true), Some(false), Some(true)]
+    );
+}
+
+#[test]
+fn test_string_expressions_build_full_name() {
+    use veloxx::types::{DataType, Value};
+
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "first".to_string(),
+        Series::new_string(
+            "first",
+            vec![Some("Ada".to_string()), Some("Émile".to_string()), None],
+        ),
+    );
+    columns.insert(
+        "last".to_string(),
+        Series::new_string(
+            "last",
+            vec![
+                Some("Lovelace".to_string()),
+                Some("Zola".to_string()),
+                Some("Hopper".to_string()),
+            ],
+        ),
+    );
+    columns.insert(
+        "age".to_string(),
+        Series::new_i32("age", vec![Some(36), Some(62), Some(85)]),
+    );
+    let df = DataFrame::new(columns);
+    let col = |name: &str| Expr::Column(name.to_string());
+    let full_name = Expr::Concat(vec![
+        col("first"),
+        Expr::Literal(Value::String(" ".to_string())),
+        col("last"),
+    ]);
+
+    let df = df
+        .with_column("full_name", &full_name)
+        .unwrap()
+        .with_column("shout", &Expr::Upper(Box::new(full_name.clone())))
+        .unwrap()
+        .with_column("quiet", &Expr::Lower(Box::new(col("last"))))
+        .unwrap()
+        .with_column("name_length", &Expr::StrLength(Box::new(full_name)))
+        .unwrap()
+        .with_column(
+            "label",
+            &Expr::Concat(vec![
+                col("last"),
+                Expr::Literal(Value::String(":".to_string())),
+                col("age"),
+            ]),
+        )
+        .unwrap();
+
+    assert_eq!(
+        df.get_column("full_name")
+            .unwrap()
+            .get_data_string()
+            .unwrap(),
+        vec![
+            Some("Ada Lovelace".to_string()),
+            Some("Émile Zola".to_string()),
+            None
+        ]
+    );
+    assert_eq!(
+        df.get_column("shout").unwrap().get_data_string().unwrap(),
+        vec![
+            Some("ADA LOVELACE".to_string()),
+            Some("ÉMILE ZOLA".to_string()),
+            None
+        ]
+    );
+    assert_eq!(
+        df.get_column("quiet").unwrap().get_string(2).unwrap(),
+        "hopper"
+    );
+    assert_eq!(
+        df.get_column("name_length")
+            .unwrap()
+            .get_data_i32()
+            .unwrap(),
+        vec![Some(12), Some(10), None]
+    );
+    assert_eq!(
+        df.get_column("label").unwrap().get_string(0).unwrap(),
+        "Lovelace:36"
+    );
+
+    // The output type does not depend on the data, even when every row is null.
+    let blanks = df
+        .with_column(
+            "blank_length",
+            &Expr::StrLength(Box::new(Expr::Literal(Value::Null))),
+        )
+        .unwrap();
+    assert_eq!(
+        blanks.get_column("blank_length").unwrap().data_type(),
+        DataType::I32
+    );
+
+    assert!(matches!(
+        df.with_column("bad", &Expr::Upper(Box::new(col("age")))),
+        Err(veloxx::VeloxxError::DataTypeMismatch(_))
+    ));
+}
+
+#[test]
+fn test_coalesce_falls_back_through_columns_to_default() {
+    use veloxx::types::Value;
+
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "preferred".to_string(),
+        Series::new_i32("preferred", vec![Some(1), None, None]),
+    );
+    columns.insert(
+        "fallback".to_string(),
+        Series::new_i32("fallback", vec![Some(10), Some(20), None]),
+    );
+    columns.insert(
+        "label".to_string(),
+        Series::new_string("label", vec![None, Some("x".to_string()), None]),
+    );
+    let df = DataFrame::new(columns);
+    let col = |name: &str| Expr::Column(name.to_string());
+
+    let df = df
+        .with_column(
+            "amount",
+            &Expr::Coalesce(vec![
+                col("preferred"),
+                col("fallback"),
+                Expr::Literal(Value::I32(0)),
+            ]),
+        )
+        .unwrap()
+        .with_column(
+            "all_null",
+            &Expr::Coalesce(vec![col("preferred"), Expr::Literal(Value::Null)]),
+        )
+        .unwrap();
+    assert_eq!(
+        df.get_column("amount").unwrap().get_data_i32().unwrap(),
+        vec![Some(1), Some(20), Some(0)]
+    );
+    assert_eq!(
+        df.get_column("all_null").unwrap().get_data_i32().unwrap(),
+        vec![Some(1), None, None]
+    );
+
+    // The first row yields an I32 and the second a String.
+    assert!(matches!(
+        df.with_column(
+            "mixed",
+            &Expr::Coalesce(vec![col("preferred"), col("label")])
+        ),
+        Err(veloxx::VeloxxError::DataTypeMismatch(_))
+    ));
+}
+
+#[test]
+fn test_case_buckets_ages() {
+    use veloxx::conditions::Condition;
+    use veloxx::types::Value;
+
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "age".to_string(),
+        Series::new_i32("age", vec![Some(12), Some(40), None, Some(70), Some(18)]),
+    );
+    let df = DataFrame::new(columns);
+    let label = |s: &str| Expr::Literal(Value::String(s.to_string()));
+    let under = |age: i32| Condition::Lt("age".to_string(), Value::I32(age));
+    let buckets = Expr::Case {
+        branches: vec![
+            (under(18), label("minor")),
+            (under(65), label("adult")),
+            (
+                Condition::Gt("age".to_string(), Value::I32(64)),
+                label("senior"),
+            ),
+        ],
+        otherwise: Box::new(label("unknown")),
+    };
+
+    let df = df.with_column("bucket", &buckets).unwrap();
+    assert_eq!(
+        df.get_column("bucket").unwrap().get_data_string().unwrap(),
+        vec![
+            Some("minor".to_string()),
+            Some("adult".to_string()),
+            Some("unknown".to_string()),
+            Some("senior".to_string()),
+            Some("adult".to_string()),
+        ]
+    );
+
+    // A branch yielding an I32 cannot share a column with String buckets.
+    let mixed = Expr::Case {
+        branches: vec![(under(18), Expr::Literal(Value::I32(0)))],
+        otherwise: Box::new(label("grown")),
+    };
+    assert!(matches!(
+        df.with_column("mixed", &mixed),
+        Err(veloxx::VeloxxError::DataTypeMismatch(_))
+    ));
+}
+
+#[test]
+fn test_with_columns_matches_chained_with_column() {
+    use veloxx::types::Value;
+
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "price".to_string(),
+        Series::new_f64("price", vec![Some(2.0), Some(0.5), Some(5.0)]),
+    );
+    columns.insert(
+        "qty".to_string(),
+        Series::new_f64("qty", vec![Some(3.0), Some(4.0), Some(1.0)]),
+    );
+    columns.insert(
+        "discount".to_string(),
+        Series::new_f64("discount", vec![None, Some(0.1), None]),
+    );
+    columns.insert(
+        "sku".to_string(),
+        Series::new_string(
+            "sku",
+            vec![
+                Some("a1".to_string()),
+                Some("b2".to_string()),
+                Some("c3".to_string()),
+            ],
+        ),
+    );
+    let df = DataFrame::new(columns);
+    let col = |name: &str| Box::new(Expr::Column(name.to_string()));
+    let exprs = [
+        ("total", Expr::Multiply(col("price"), col("qty"))),
+        (
+            "discount_or_zero",
+            Expr::IfNull(col("discount"), Box::new(Expr::Literal(Value::F64(0.0)))),
+        ),
+        ("code", Expr::Upper(col("sku"))),
+    ];
+
+    let batched = df.with_columns(&exprs).unwrap();
+    let mut chained = df.clone();
+    for (name, expr) in &exprs {
+        chained = chained.with_column(name, expr).unwrap();
+    }
+    assert_eq!(
+        batched.column_names(),
+        vec![
+            "price",
+            "qty",
+            "discount",
+            "sku",
+            "total",
+            "discount_or_zero",
+            "code"
+        ]
+    );
+    assert_eq!(batched.column_names(), chained.column_names());
+    for name in batched.column_names() {
+        assert_eq!(batched.get_column(&name), chained.get_column(&name));
+    }
+
+    assert!(matches!(
+        df.with_columns(&[("qty", Expr::Upper(col("sku")))]),
+        Err(veloxx::VeloxxError::InvalidOperation(_))
+    ));
+    assert!(matches!(
+        df.with_columns(&[
+            ("code", Expr::Upper(col("sku"))),
+            ("code", Expr::Lower(col("sku"))),
+        ]),
+        Err(veloxx::VeloxxError::InvalidOperation(_))
+    ));
+}
+
+#[test]
+fn test_i64_arithmetic_expressions() {
+    use veloxx::types::Value;
+
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "bytes".to_string(),
+        Series::new_i64("bytes", vec![Some(5_000_000_000), Some(i64::MAX)]),
+    );
+    let df = DataFrame::new(columns);
+    let bytes = || Box::new(Expr::Column("bytes".to_string()));
+    let two = || Box::new(Expr::Literal(Value::I64(2)));
+
+    let head = df.head(1).unwrap();
+    let doubled = head
+        .with_column("doubled", &Expr::Multiply(bytes(), two()))
+        .unwrap();
+    assert_eq!(
+        doubled.get_column("doubled").unwrap(),
+        &Series::new_i64("doubled", vec![Some(10_000_000_000)])
+    );
+    let halved = head
+        .with_column("halved", &Expr::Divide(bytes(), two()))
+        .unwrap();
+    assert_eq!(
+        halved.get_column("halved").unwrap().get_value(0),
+        Some(Value::I64(2_500_000_000))
+    );
+    let less = head
+        .with_column("less", &Expr::Subtract(bytes(), two()))
+        .unwrap();
+    assert_eq!(
+        less.get_column("less").unwrap().get_value(0),
+        Some(Value::I64(4_999_999_998))
+    );
+
+    assert!(df.with_column("over", &Expr::Add(bytes(), two())).is_err());
+}
@@ -91,5 +91,26 @@ mod tests {
                 prop_assert!((s - expected).abs() < 1e-9);
             }
         }
+
+        #[test]
+        fn fuzz_sort_i32_fast_path_matches_generic(
+            keys in prop::collection::vec(prop::option::weighted(0.9, any::<i32>()), 0..200),
+            ascending in any::<bool>(),
+        ) {
+            let len = keys.len() as i32;
+            let mut cols = IndexMap::new();
+            cols.insert("key".to_string(), Series::new_i32("key", keys));
+            cols.insert("row".to_string(), Series::new_i32("row", (0..len).map(Some).collect()));
+            let df = DataFrame::new(cols);
+
+            // A single I32 key takes the radix path; repeating the key forces the generic sort.
+            let fast = df.sort(vec!["key".to_string()], ascending).unwrap();
+            let generic = df
+                .sort(vec!["key".to_string(), "key".to_string()], ascending)
+                .unwrap();
+
+            prop_assert_eq!(fast.get_column("key"), generic.get_column("key"));
+            prop_assert_eq!(fast.get_column("row"), generic.get_column("row"));
+        }
     }
 }
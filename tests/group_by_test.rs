@@ -0,0 +1,346 @@
+use indexmap::IndexMap;
+use veloxx::dataframe::DataFrame;
+use veloxx::series::Series;
+use veloxx::types::Value;
+
+fn city_sales_df() -> DataFrame {
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "city".to_string(),
+        Series::new_string(
+            "city",
+            vec![
+                Some("Paris".to_string()),
+                Some("Oslo".to_string()),
+                Some("Paris".to_string()),
+                None,
+            ],
+        ),
+    );
+    columns.insert(
+        "sales".to_string(),
+        Series::new_f64(
+            "sales",
+            vec![Some(100.0), Some(150.0), Some(200.0), Some(50.0)],
+        ),
+    );
+    DataFrame::new(columns)
+}
+
+#[test]
+fn test_iter_groups_yields_keys_and_sub_frames() {
+    let df = city_sales_df();
+    let grouped = df.group_by(vec!["city".to_string()]).unwrap();
+
+    let mut groups: Vec<(Vec<Value>, DataFrame)> = grouped.iter_groups().collect();
+    groups.sort_by(|a, b| a.0.cmp(&b.0));
+    assert_eq!(groups.len(), 3);
+
+    assert_eq!(groups[0].0, vec![Value::Null]);
+    assert_eq!(groups[0].1.row_count(), 1);
+
+    assert_eq!(groups[1].0, vec![Value::String("Oslo".to_string())]);
+    assert_eq!(groups[2].0, vec![Value::String("Paris".to_string())]);
+    assert_eq!(
+        groups[2]
+            .1
+            .get_column("sales")
+            .unwrap()
+            .get_data_f64()
+            .unwrap(),
+        vec![Some(100.0), Some(200.0)]
+    );
+}
+
+#[test]
+fn test_group_apply_normalizes_within_groups() {
+    let df = city_sales_df();
+    let shares = df
+        .group_apply(&["city"], |group| {
+            let sales = group.get_column("sales").unwrap();
+            let total = sales.sum()?.as_f64().unwrap();
+            let share: Vec<Option<f64>> = sales
+                .get_data_f64()?
+                .into_iter()
+                .map(|v| v.map(|v| v / total))
+                .collect();
+            let mut columns = group.columns.clone();
+            columns.insert("share".to_string(), Series::new_f64("share", share));
+            Ok(DataFrame::new(columns))
+        })
+        .unwrap();
+
+    assert_eq!(shares.column_names(), vec!["city", "sales", "share"]);
+    // Groups appear in order of first appearance: Paris, Oslo, then the null key.
+    assert_eq!(
+        shares.get_column("sales").unwrap().get_data_f64().unwrap(),
+        vec![Some(100.0), Some(200.0), Some(150.0), Some(50.0)]
+    );
+    let share = shares.get_column("share").unwrap().get_data_f64().unwrap();
+    assert!((share[0].unwrap() - 1.0 / 3.0).abs() < 1e-12);
+    assert_eq!(share[2], Some(1.0));
+    assert_eq!(share[3], Some(1.0));
+}
+
+#[test]
+fn test_group_apply_rejects_mismatched_results() {
+    let df = city_sales_df();
+    let result = df.group_apply(&["city"], |group| {
+        if group.row_count() > 1 {
+            Ok(group.clone())
+        } else {
+            group.select_columns(vec!["sales".to_string()])
+        }
+    });
+    assert!(result.is_err());
+    assert!(df.group_apply(&["missing"], |g| Ok(g.clone())).is_err());
+}
+
+#[test]
+fn test_agg_keeps_typed_integer_keys() {
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "store".to_string(),
+        Series::new_i32("store", vec![Some(7), Some(3), Some(7), None]),
+    );
+    columns.insert(
+        "sales".to_string(),
+        Series::new_f64("sales", vec![Some(1.0), Some(2.0), Some(4.0), Some(8.0)]),
+    );
+    let df = DataFrame::new(columns);
+
+    let result = df
+        .group_by(vec!["store".to_string()])
+        .unwrap()
+        .agg(vec![("sales", "max")])
+        .unwrap();
+    let store = result.get_column("store").unwrap();
+    let max = result.get_column("sales_max").unwrap();
+
+    let mut rows: Vec<(Option<Value>, Option<Value>)> = (0..result.row_count())
+        .map(|i| (store.get_value(i), max.get_value(i)))
+        .collect();
+    rows.sort();
+    assert_eq!(
+        rows,
+        vec![
+            (None, Some(Value::F64(8.0))),
+            (Some(Value::I32(3)), Some(Value::F64(2.0))),
+            (Some(Value::I32(7)), Some(Value::F64(4.0))),
+        ]
+    );
+}
+
+#[test]
+fn test_agg_multiple_functions_per_column() {
+    let mut df = city_sales_df();
+    df.columns.insert(
+        "units".to_string(),
+        Series::new_i32("units", vec![Some(1), Some(2), Some(3), Some(4)]),
+    );
+    let grouped = df.group_by(vec!["city".to_string()]).unwrap();
+
+    let result = grouped
+        .agg(vec![
+            ("sales", "sum"),
+            ("sales", "mean"),
+            ("units", "count"),
+        ])
+        .unwrap();
+    assert_eq!(
+        result.column_names(),
+        vec!["city", "sales_sum", "sales_mean", "units_count"]
+    );
+    let city = result.get_column("city").unwrap();
+    let paris = (0..result.row_count())
+        .find(|&i| city.get_value(i) == Some(Value::String("Paris".to_string())))
+        .unwrap();
+    assert_eq!(
+        result.get_column("sales_sum").unwrap().get_value(paris),
+        Some(Value::F64(300.0))
+    );
+    assert_eq!(
+        result.get_column("sales_mean").unwrap().get_value(paris),
+        Some(Value::F64(150.0))
+    );
+    assert_eq!(
+        result.get_column("units_count").unwrap().get_value(paris),
+        Some(Value::I32(2))
+    );
+
+    assert!(matches!(
+        grouped.agg(vec![("sales", "sum"), ("sales", "sum")]),
+        Err(veloxx::VeloxxError::InvalidOperation(_))
+    ));
+}
+
+#[test]
+fn test_agg_dispersion_statistics() {
+    let df = city_sales_df();
+    let grouped = df.group_by(vec!["city".to_string()]).unwrap();
+    let result = grouped
+        .agg(vec![
+            ("sales", "median"),
+            ("sales", "std"),
+            ("sales", "var"),
+        ])
+        .unwrap();
+
+    let city = result.get_column("city").unwrap();
+    let row_of = |name: &str| {
+        (0..result.row_count())
+            .find(|&i| city.get_value(i) == Some(Value::String(name.to_string())))
+            .unwrap()
+    };
+    let stat = |column: &str, row: usize| result.get_column(column).unwrap().get_value(row);
+
+    let paris = row_of("Paris");
+    assert_eq!(stat("sales_median", paris), Some(Value::F64(150.0)));
+    assert_eq!(stat("sales_var", paris), Some(Value::F64(5000.0)));
+    match stat("sales_std", paris) {
+        Some(Value::F64(std)) => assert!((std - 5000f64.sqrt()).abs() < 1e-9),
+        other => panic!("unexpected std: {other:?}"),
+    }
+
+    let oslo = row_of("Oslo");
+    assert_eq!(stat("sales_median", oslo), Some(Value::F64(150.0)));
+    assert_eq!(stat("sales_std", oslo), None);
+    assert_eq!(stat("sales_var", oslo), None);
+}
+
+#[test]
+fn test_agg_nunique_counts_distinct_values() {
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "customer".to_string(),
+        Series::new_i32(
+            "customer",
+            vec![Some(1), Some(1), Some(1), Some(2), Some(2)],
+        ),
+    );
+    columns.insert(
+        "product".to_string(),
+        Series::new_string(
+            "product",
+            vec![
+                Some("tea".to_string()),
+                Some("jam".to_string()),
+                Some("tea".to_string()),
+                Some("tea".to_string()),
+                None,
+            ],
+        ),
+    );
+    columns.insert(
+        "price".to_string(),
+        Series::new_f64(
+            "price",
+            vec![Some(2.5), Some(4.0), Some(2.5), Some(0.1), Some(0.2)],
+        ),
+    );
+    let df = DataFrame::new(columns);
+
+    let result = df
+        .group_by(vec!["customer".to_string()])
+        .unwrap()
+        .agg(vec![("product", "nunique"), ("price", "nunique")])
+        .unwrap();
+    let customer = result.get_column("customer").unwrap();
+    let mut counts: Vec<(Option<Value>, Option<Value>, Option<Value>)> = (0..result.row_count())
+        .map(|i| {
+            (
+                customer.get_value(i),
+                result.get_column("product_nunique").unwrap().get_value(i),
+                result.get_column("price_nunique").unwrap().get_value(i),
+            )
+        })
+        .collect();
+    counts.sort();
+    assert_eq!(
+        counts,
+        vec![
+            (
+                Some(Value::I32(1)),
+                Some(Value::I32(2)),
+                Some(Value::I32(2))
+            ),
+            (
+                Some(Value::I32(2)),
+                Some(Value::I32(1)),
+                Some(Value::I32(2))
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_agg_first_and_last_follow_row_order() {
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "user".to_string(),
+        Series::new_string(
+            "user",
+            ["b", "a", "b", "a", "b", "c"]
+                .iter()
+                .map(|s| Some(s.to_string()))
+                .collect(),
+        ),
+    );
+    columns.insert(
+        "seen_at".to_string(),
+        Series::new_i64(
+            "seen_at",
+            vec![Some(50), None, Some(10), Some(40), None, None],
+        ),
+    );
+    let df = DataFrame::new(columns);
+
+    let result = df
+        .group_by(vec!["user".to_string()])
+        .unwrap()
+        .agg(vec![("seen_at", "first"), ("seen_at", "last")])
+        .unwrap();
+    let user = result.get_column("user").unwrap();
+    let mut rows: Vec<(Option<Value>, Option<Value>, Option<Value>)> = (0..result.row_count())
+        .map(|i| {
+            (
+                user.get_value(i),
+                result.get_column("seen_at_first").unwrap().get_value(i),
+                result.get_column("seen_at_last").unwrap().get_value(i),
+            )
+        })
+        .collect();
+    rows.sort();
+    let user_value = |name: &str| Some(Value::String(name.to_string()));
+    assert_eq!(
+        rows,
+        vec![
+            (user_value("a"), Some(Value::I64(40)), Some(Value::I64(40))),
+            (user_value("b"), Some(Value::I64(50)), Some(Value::I64(10))),
+            (user_value("c"), None, None),
+        ]
+    );
+}
+
+#[test]
+fn test_i64_group_sum_overflow_is_an_error() {
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "k".to_string(),
+        Series::new_string("k", vec![Some("a".to_string()), Some("a".to_string())]),
+    );
+    columns.insert(
+        "n".to_string(),
+        Series::new_i64("n", vec![Some(i64::MAX), Some(1)]),
+    );
+    let df = DataFrame::new(columns);
+
+    let result = df
+        .group_by(vec!["k".to_string()])
+        .unwrap()
+        .agg(vec![("n", "sum"), ("n", "count")]);
+    assert!(matches!(
+        result,
+        Err(veloxx::error::VeloxxError::InvalidOperation(_))
+    ));
+}
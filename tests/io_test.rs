@@ -134,13 +134,21 @@ fn test_to_csv() {
 
 #[test]
 fn test_from_csv_nonexistent_file() {
-    let result = DataFrame::from_csv("nonexistent.csv");
-    assert!(result.is_err());
-    if let Err(VeloxxError::FileIO(_)) = result {
-        // Test passes if it's a FileIO error
-    } else {
-        panic!("Expected a FileIO error");
+    use std::error::Error;
+
+    let err = DataFrame::from_csv("nonexistent.csv").unwrap_err();
+    match &err {
+        VeloxxError::Io(io_err) => assert_eq!(io_err.kind(), std::io::ErrorKind::NotFound),
+        other => panic!("Expected an Io error, got {:?}", other),
     }
+    assert!(err.to_string().starts_with("I/O error: "));
+    let source = err.source().expect("Io errors expose their cause");
+    assert!(source.downcast_ref::<std::io::Error>().is_some());
+
+    assert!(matches!(
+        DataFrame::from_json("nonexistent.json"),
+        Err(VeloxxError::Io(_))
+    ));
 }
 
 #[test]
@@ -166,3 +174,235 @@ fn test_from_csv_malformed() {
         )
     );
 }
+
+#[test]
+fn test_unnest_json_column() {
+    use veloxx::types::Value;
+
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "id".to_string(),
+        Series::new_i32("id", vec![Some(1), Some(2), Some(3), Some(4)]),
+    );
+    columns.insert(
+        "payload".to_string(),
+        Series::new_string(
+            "payload",
+            vec![
+                Some(r#"{"score": 10, "label": "a", "ok": true}"#.to_string()),
+                Some(r#"{"score": 2.5, "extra": "x"}"#.to_string()),
+                Some("not json".to_string()),
+                None,
+            ],
+        ),
+    );
+    let df = DataFrame::new(columns);
+
+    let unnested = df.unnest_json("payload").unwrap();
+    assert_eq!(
+        unnested.column_names(),
+        vec![
+            "id".to_string(),
+            "payload_score".to_string(),
+            "payload_label".to_string(),
+            "payload_ok".to_string(),
+            "payload_extra".to_string(),
+        ]
+    );
+    assert_eq!(unnested.row_count(), 4);
+
+    let score = unnested.get_column("payload_score").unwrap();
+    assert_eq!(score.get_value(0), Some(Value::F64(10.0)));
+    assert_eq!(score.get_value(1), Some(Value::F64(2.5)));
+    assert_eq!(score.get_value(2), None);
+    assert_eq!(score.get_value(3), None);
+
+    let ok = unnested.get_column("payload_ok").unwrap();
+    assert_eq!(ok.get_value(0), Some(Value::Bool(true)));
+    assert_eq!(ok.get_value(1), None);
+
+    let extra = unnested.get_column("payload_extra").unwrap();
+    assert_eq!(extra.get_value(0), None);
+    assert_eq!(extra.get_value(1), Some(Value::String("x".to_string())));
+
+    assert!(df.unnest_json("id").is_err());
+    assert!(df.unnest_json("missing").is_err());
+
+    let mut clashing = df.clone();
+    clashing.add_column(Series::new_i32(
+        "payload_extra",
+        vec![Some(0), Some(0), Some(0), Some(0)],
+    ));
+    match clashing.unnest_json("payload") {
+        Err(VeloxxError::InvalidOperation(message)) => {
+            assert!(message.contains("payload_extra"))
+        }
+        other => panic!("expected InvalidOperation, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_to_csv_quotes_special_characters() {
+    let tricky = vec![
+        Some("Smith, John".to_string()),
+        Some("He said \"hi\"".to_string()),
+        Some("line one\nline two".to_string()),
+        Some("plain".to_string()),
+    ];
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "note".to_string(),
+        Series::new_string("note", tricky.clone()),
+    );
+    columns.insert(
+        "id".to_string(),
+        Series::new_i32("id", vec![Some(1), Some(2), Some(3), Some(4)]),
+    );
+    let df = DataFrame::new(columns);
+
+    let path = "test_quoted_output.csv";
+    df.to_csv(path).unwrap();
+    let content = std::fs::read_to_string(path).unwrap();
+    assert_eq!(
+        content,
+        "id,note\n1,\"Smith, John\"\n2,\"He said \"\"hi\"\"\"\n3,\"line one\nline two\"\n4,plain\n"
+    );
+
+    let read_df = DataFrame::from_csv(path).unwrap();
+    std::fs::remove_file(path).unwrap();
+    assert_eq!(read_df.row_count(), 4);
+    assert_eq!(
+        read_df
+            .get_column("note")
+            .unwrap()
+            .get_data_string()
+            .unwrap(),
+        tricky
+    );
+}
+
+#[test]
+fn test_to_csv_with_custom_delimiter_and_quote() {
+    use veloxx::dataframe::io::CsvWriteOptions;
+
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "name".to_string(),
+        Series::new_string(
+            "name",
+            vec![Some("a;b".to_string()), Some("it's".to_string())],
+        ),
+    );
+    let df = DataFrame::new(columns);
+
+    let path = "test_custom_delimiter.csv";
+    let options = CsvWriteOptions::new().delimiter(b';').quote(b'\'');
+    df.to_csv_with_options(path, &options).unwrap();
+    let content = std::fs::read_to_string(path).unwrap();
+    std::fs::remove_file(path).unwrap();
+    assert_eq!(content, "name\n'a;b'\n'it''s'\n");
+}
+
+#[test]
+fn test_from_csv_quoted_fields() {
+    let long_note = "x".repeat(10_000);
+    let csv_data = format!(
+        "name,address,score\n\"Smith, John\",\"1 Main St\nSpringfield\",7\n\"Doe, Jane\",\"She said \"\"hi\"\"\",9\nLong,\"{}\",1\n",
+        long_note
+    );
+    let path = "test_quoted_input.csv";
+    std::fs::write(path, csv_data).unwrap();
+    let df = DataFrame::from_csv(path).unwrap();
+    std::fs::remove_file(path).unwrap();
+
+    assert_eq!(df.row_count(), 3);
+    assert_eq!(
+        df.get_column("name").unwrap().get_data_string().unwrap(),
+        vec![
+            Some("Smith, John".to_string()),
+            Some("Doe, Jane".to_string()),
+            Some("Long".to_string()),
+        ]
+    );
+    assert_eq!(
+        df.get_column("address").unwrap().get_data_string().unwrap(),
+        vec![
+            Some("1 Main St\nSpringfield".to_string()),
+            Some("She said \"hi\"".to_string()),
+            Some(long_note),
+        ]
+    );
+    assert_eq!(
+        df.get_column("score").unwrap().get_data_i32().unwrap(),
+        vec![Some(7), Some(9), Some(1)]
+    );
+}
+
+#[test]
+fn test_ndjson_round_trip_with_missing_field() {
+    use veloxx::types::Value;
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("records.ndjson");
+    let path = path.to_str().unwrap();
+    std::fs::write(
+        path,
+        "{\"id\": 1, \"name\": \"ada\", \"score\": 1.5, \"active\": true, \"visits\": 3, \"ratio\": 0.1}\n\
+         {\"id\": 2, \"name\": \"bo\", \"active\": false, \"visits\": 5000000000, \"ratio\": 2.0}\n\
+         \n\
+         {\"id\": 3, \"name\": \"cy\", \"score\": 2.25, \"active\": true, \"visits\": 7, \"ratio\": 1.2345678901234}\n",
+    )
+    .unwrap();
+    let df = DataFrame::from_ndjson(path).unwrap();
+
+    assert_eq!(
+        df.column_names(),
+        vec!["id", "name", "score", "active", "visits", "ratio"]
+    );
+    assert_eq!(df.row_count(), 3);
+    let score = df.get_column("score").unwrap();
+    assert_eq!(score.get_value(0), Some(Value::F64(1.5)));
+    assert_eq!(score.get_value(1), None);
+    assert_eq!(
+        df.get_column("active").unwrap().get_data_bool().unwrap(),
+        vec![Some(true), Some(false), Some(true)]
+    );
+    assert_eq!(
+        df.get_column("id").unwrap().get_data_i32().unwrap(),
+        vec![Some(1), Some(2), Some(3)]
+    );
+    let visits = df.get_column("visits").unwrap();
+    assert_eq!(visits.get_value(0), Some(Value::I64(3)));
+    assert_eq!(visits.get_value(1), Some(Value::I64(5_000_000_000)));
+    let ratio = df.get_column("ratio").unwrap();
+    assert_eq!(ratio.get_value(0), Some(Value::F64(0.1)));
+    assert_eq!(ratio.get_value(1), Some(Value::F64(2.0)));
+    assert_eq!(ratio.get_value(2), Some(Value::F64(1.2345678901234)));
+
+    let out_path = dir.path().join("records_out.ndjson");
+    let out_path = out_path.to_str().unwrap();
+    df.to_ndjson(out_path).unwrap();
+    let content = std::fs::read_to_string(out_path).unwrap();
+    let round_tripped = DataFrame::from_ndjson(out_path).unwrap();
+    assert_eq!(
+        content.lines().nth(1),
+        Some(
+            "{\"id\":2,\"name\":\"bo\",\"score\":null,\"active\":false,\"visits\":5000000000,\"ratio\":2.0}"
+        )
+    );
+    for name in df.column_names() {
+        assert_eq!(round_tripped.get_column(&name), df.get_column(&name));
+    }
+}
+
+#[test]
+fn test_from_ndjson_reports_malformed_line() {
+    let path = "test_malformed.ndjson";
+    std::fs::write(path, "{\"id\": 1.5}\nnot json\n").unwrap();
+    let result = DataFrame::from_ndjson(path);
+    std::fs::remove_file(path).unwrap();
+    match result {
+        Err(VeloxxError::InvalidOperation(message)) => assert!(message.contains("line 2")),
+        other => panic!("expected InvalidOperation, got {:?}", other),
+    }
+}
@@ -218,3 +218,422 @@ fn test_join_nonexistent_column() {
     let result = df1.join(&df2, "nonexistent", JoinType::Inner);
     assert!(result.is_err());
 }
+
+#[test]
+fn test_join_rejects_shared_non_key_columns() {
+    use veloxx::dataframe::join::JoinAlgorithm;
+    use veloxx::VeloxxError;
+
+    let mut left = IndexMap::new();
+    left.insert(
+        "id".to_string(),
+        Series::new_i32("id", vec![Some(1), Some(2)]),
+    );
+    left.insert(
+        "amount".to_string(),
+        Series::new_f64("amount", vec![Some(1.5), Some(2.5)]),
+    );
+    let mut right = IndexMap::new();
+    right.insert(
+        "id".to_string(),
+        Series::new_i32("id", vec![Some(1), Some(2)]),
+    );
+    right.insert(
+        "amount".to_string(),
+        Series::new_f64("amount", vec![Some(10.0), Some(20.0)]),
+    );
+    let left = DataFrame::new(left);
+    let right = DataFrame::new(right);
+
+    for join_type in [
+        JoinType::Inner,
+        JoinType::Left,
+        JoinType::Right,
+        JoinType::Outer,
+    ] {
+        match left.join(&right, "id", join_type) {
+            Err(VeloxxError::InvalidOperation(message)) => assert!(message.contains("amount")),
+            other => panic!("expected a column collision error, got {other:?}"),
+        }
+    }
+    assert!(matches!(
+        left.join_with(&right, "id", JoinType::Inner, JoinAlgorithm::SortMerge),
+        Err(VeloxxError::InvalidOperation(_))
+    ));
+}
+
+fn sorted_frames() -> (DataFrame, DataFrame) {
+    let mut left = IndexMap::new();
+    left.insert(
+        "id".to_string(),
+        Series::new_i32("id", vec![None, Some(1), Some(2), Some(2), Some(5)]),
+    );
+    left.insert(
+        "name".to_string(),
+        Series::new_string(
+            "name",
+            vec![
+                Some("n".to_string()),
+                Some("a".to_string()),
+                Some("b".to_string()),
+                Some("c".to_string()),
+                Some("e".to_string()),
+            ],
+        ),
+    );
+    let mut right = IndexMap::new();
+    right.insert(
+        "id".to_string(),
+        Series::new_i32("id", vec![Some(2), Some(3), Some(5), Some(5)]),
+    );
+    right.insert(
+        "score".to_string(),
+        Series::new_i32("score", vec![Some(20), Some(30), Some(50), Some(51)]),
+    );
+    (DataFrame::new(left), DataFrame::new(right))
+}
+
+#[test]
+fn test_sort_merge_join_matches_hash_join() {
+    use veloxx::dataframe::join::JoinAlgorithm;
+
+    let (left, right) = sorted_frames();
+    let merged = left
+        .join_with(&right, "id", JoinType::Inner, JoinAlgorithm::SortMerge)
+        .unwrap();
+    let hashed = left
+        .join_with(&right, "id", JoinType::Inner, JoinAlgorithm::Hash)
+        .unwrap();
+    assert_eq!(merged.row_count(), 4);
+    for name in ["id", "name", "score"] {
+        assert_eq!(merged.get_column(name), hashed.get_column(name));
+    }
+    assert_eq!(
+        merged.get_column("score").unwrap().get_data_i32().unwrap(),
+        vec![Some(20), Some(20), Some(50), Some(51)]
+    );
+}
+
+#[test]
+fn test_sort_merge_left_and_outer_join() {
+    use veloxx::dataframe::join::JoinAlgorithm;
+
+    let (left, right) = sorted_frames();
+    let left_joined = left
+        .join_with(&right, "id", JoinType::Left, JoinAlgorithm::SortMerge)
+        .unwrap();
+    assert_eq!(
+        left_joined
+            .get_column("score")
+            .unwrap()
+            .get_data_i32()
+            .unwrap(),
+        vec![None, None, Some(20), Some(20), Some(50), Some(51)]
+    );
+
+    let outer = left
+        .join_with(&right, "id", JoinType::Outer, JoinAlgorithm::SortMerge)
+        .unwrap();
+    assert_eq!(
+        outer.get_column("id").unwrap().get_data_i32().unwrap(),
+        vec![None, Some(1), Some(2), Some(2), Some(3), Some(5), Some(5)]
+    );
+    assert_eq!(outer.get_column("name").unwrap().get_value(4), None);
+}
+
+#[test]
+fn test_sort_merge_join_requires_sorted_input() {
+    use veloxx::dataframe::join::JoinAlgorithm;
+
+    let (left, right) = sorted_frames();
+    let unsorted = left.sort(vec!["name".to_string()], false).unwrap();
+    assert!(unsorted
+        .join_with(&right, "id", JoinType::Inner, JoinAlgorithm::SortMerge)
+        .is_err());
+    // The hash join is unaffected by ordering.
+    assert!(unsorted
+        .join_with(&right, "id", JoinType::Inner, JoinAlgorithm::Hash)
+        .is_ok());
+}
+
+#[test]
+fn test_join_preserves_left_row_order() {
+    let n = 5_000;
+    let mut left = IndexMap::new();
+    // Keys in descending order so any reordering would be visible.
+    left.insert(
+        "id".to_string(),
+        Series::new_i32("id", (0..n).rev().map(|i| Some(i % 100)).collect()),
+    );
+    left.insert(
+        "row".to_string(),
+        Series::new_i32("row", (0..n).map(Some).collect()),
+    );
+    let mut right = IndexMap::new();
+    right.insert(
+        "id".to_string(),
+        Series::new_i32("id", (0..50).flat_map(|i| [Some(i), Some(i)]).collect()),
+    );
+    right.insert(
+        "match".to_string(),
+        Series::new_i32("match", (0..100).map(Some).collect()),
+    );
+    let left = DataFrame::new(left);
+    let right = DataFrame::new(right);
+
+    for join_type in [JoinType::Inner, JoinType::Left] {
+        let joined = left.join(&right, "id", join_type).unwrap();
+        let rows = joined.get_column("row").unwrap().get_data_i32().unwrap();
+        let matches = joined.get_column("match").unwrap().get_data_i32().unwrap();
+        for k in 1..rows.len() {
+            assert!(rows[k - 1] <= rows[k]);
+            if rows[k - 1] == rows[k] {
+                assert!(matches[k - 1] < matches[k]);
+            }
+        }
+    }
+}
+
+fn id_frames() -> (DataFrame, DataFrame) {
+    let mut left = IndexMap::new();
+    left.insert(
+        "id".to_string(),
+        Series::new_i32("id", vec![Some(1), Some(2), Some(3)]),
+    );
+    left.insert(
+        "name".to_string(),
+        Series::new_string(
+            "name",
+            vec![
+                Some("Alice".to_string()),
+                Some("Bob".to_string()),
+                Some("Charlie".to_string()),
+            ],
+        ),
+    );
+    let mut right = IndexMap::new();
+    right.insert(
+        "id".to_string(),
+        Series::new_i32("id", vec![Some(1), Some(2), Some(4)]),
+    );
+    right.insert(
+        "age".to_string(),
+        Series::new_i32("age", vec![Some(25), Some(30), Some(35)]),
+    );
+    (DataFrame::new(left), DataFrame::new(right))
+}
+
+#[test]
+fn test_left_semi_and_anti_join() {
+    use veloxx::dataframe::join::JoinAlgorithm;
+    use veloxx::types::Value;
+
+    let (left, right) = id_frames();
+    for algorithm in [JoinAlgorithm::Hash, JoinAlgorithm::SortMerge] {
+        let semi = left
+            .join_with(&right, "id", JoinType::LeftSemi, algorithm)
+            .unwrap();
+        assert_eq!(semi.row_count(), 2);
+        assert_eq!(semi.column_count(), 2);
+        assert!(semi.get_column("age").is_none());
+        let names = semi.get_column("name").unwrap();
+        assert_eq!(names.get_value(0), Some(Value::String("Alice".to_string())));
+        assert_eq!(names.get_value(1), Some(Value::String("Bob".to_string())));
+
+        let anti = left
+            .join_with(&right, "id", JoinType::LeftAnti, algorithm)
+            .unwrap();
+        assert_eq!(anti.row_count(), 1);
+        assert_eq!(anti.column_count(), 2);
+        assert_eq!(
+            anti.get_column("name").unwrap().get_value(0),
+            Some(Value::String("Charlie".to_string()))
+        );
+    }
+}
+
+#[test]
+fn test_sort_merge_join_rejects_mismatched_key_types() {
+    use veloxx::dataframe::join::JoinAlgorithm;
+    use veloxx::VeloxxError;
+
+    let mut left = IndexMap::new();
+    left.insert(
+        "id".to_string(),
+        Series::new_i32("id", vec![Some(1), Some(2)]),
+    );
+    let mut right = IndexMap::new();
+    right.insert(
+        "id".to_string(),
+        Series::new_i64("id", vec![Some(1), Some(2)]),
+    );
+    let left = DataFrame::new(left);
+    let right = DataFrame::new(right);
+
+    for join_type in [JoinType::Inner, JoinType::Outer, JoinType::LeftSemi] {
+        assert!(matches!(
+            left.join_with(&right, "id", join_type, JoinAlgorithm::SortMerge),
+            Err(VeloxxError::DataTypeMismatch(_))
+        ));
+    }
+}
+
+#[test]
+fn test_semi_and_anti_join_null_keys_never_match() {
+    use veloxx::types::Value;
+
+    let mut left = IndexMap::new();
+    left.insert("id".to_string(), Series::new_i32("id", vec![Some(1), None]));
+    let mut right = IndexMap::new();
+    right.insert("id".to_string(), Series::new_i32("id", vec![Some(1), None]));
+    let left = DataFrame::new(left);
+    let right = DataFrame::new(right);
+
+    let semi = left.join(&right, "id", JoinType::LeftSemi).unwrap();
+    assert_eq!(semi.row_count(), 1);
+    assert_eq!(
+        semi.get_column("id").unwrap().get_value(0),
+        Some(Value::I32(1))
+    );
+
+    let anti = left.join(&right, "id", JoinType::LeftAnti).unwrap();
+    assert_eq!(anti.row_count(), 1);
+    assert_eq!(anti.get_column("id").unwrap().get_value(0), None);
+}
+
+#[test]
+fn test_join_on_composite_key() {
+    use veloxx::types::Value;
+    use veloxx::VeloxxError;
+
+    let mut sales = IndexMap::new();
+    sales.insert(
+        "date".to_string(),
+        Series::new_string(
+            "date",
+            vec![
+                Some("2024-01-01".to_string()),
+                Some("2024-01-02".to_string()),
+            ],
+        ),
+    );
+    sales.insert(
+        "store_id".to_string(),
+        Series::new_i32("store_id", vec![Some(1), Some(1)]),
+    );
+    sales.insert(
+        "sales".to_string(),
+        Series::new_f64("sales", vec![Some(10.0), Some(20.0)]),
+    );
+    let sales = DataFrame::new(sales);
+
+    // Each row matches a sales row on one key column; only the first matches on both.
+    let mut staff = IndexMap::new();
+    staff.insert(
+        "date".to_string(),
+        Series::new_string(
+            "date",
+            vec![
+                Some("2024-01-01".to_string()),
+                Some("2024-01-02".to_string()),
+            ],
+        ),
+    );
+    staff.insert(
+        "store_id".to_string(),
+        Series::new_i32("store_id", vec![Some(1), Some(2)]),
+    );
+    staff.insert(
+        "staff".to_string(),
+        Series::new_i32("staff", vec![Some(4), Some(6)]),
+    );
+    let staff = DataFrame::new(staff);
+
+    let joined = sales
+        .join_on(&staff, &["date", "store_id"], JoinType::Inner)
+        .unwrap();
+    assert_eq!(joined.row_count(), 1);
+    assert_eq!(joined.column_count(), 4);
+    assert_eq!(
+        joined.get_column("date").unwrap().get_value(0),
+        Some(Value::String("2024-01-01".to_string()))
+    );
+    assert_eq!(
+        joined.get_column("staff").unwrap().get_value(0),
+        Some(Value::I32(4))
+    );
+
+    let mut mistyped = IndexMap::new();
+    mistyped.insert(
+        "date".to_string(),
+        Series::new_string("date", vec![Some("2024-01-01".to_string())]),
+    );
+    mistyped.insert(
+        "store_id".to_string(),
+        Series::new_i64("store_id", vec![Some(1)]),
+    );
+    let mistyped = DataFrame::new(mistyped);
+    assert!(matches!(
+        sales.join_on(&mistyped, &["date", "store_id"], JoinType::Inner),
+        Err(VeloxxError::DataTypeMismatch(_))
+    ));
+    assert!(matches!(
+        sales.join_on(&staff, &["date", "missing"], JoinType::Inner),
+        Err(VeloxxError::ColumnNotFound(_))
+    ));
+}
+
+#[test]
+fn test_join_with_suffixes_on_shared_column() {
+    use veloxx::types::Value;
+    use veloxx::VeloxxError;
+
+    let mut left = IndexMap::new();
+    left.insert(
+        "id".to_string(),
+        Series::new_i32("id", vec![Some(1), Some(2)]),
+    );
+    left.insert(
+        "score".to_string(),
+        Series::new_f64("score", vec![Some(0.5), Some(0.6)]),
+    );
+    let mut right = IndexMap::new();
+    right.insert(
+        "id".to_string(),
+        Series::new_i32("id", vec![Some(2), Some(3)]),
+    );
+    right.insert(
+        "score".to_string(),
+        Series::new_f64("score", vec![Some(0.9), Some(0.1)]),
+    );
+    let left = DataFrame::new(left);
+    let right = DataFrame::new(right);
+
+    let joined = left
+        .join_with_suffixes(&right, &["id"], JoinType::Left, ("_l", "_r"))
+        .unwrap();
+    assert_eq!(joined.column_names(), vec!["id", "score_l", "score_r"]);
+    assert_eq!(joined.row_count(), 2);
+    let score_l = joined.get_column("score_l").unwrap();
+    let score_r = joined.get_column("score_r").unwrap();
+    assert_eq!(score_l.name(), "score_l");
+    assert_eq!(score_l.get_value(1), Some(Value::F64(0.6)));
+    assert_eq!(score_r.get_value(0), None);
+    assert_eq!(score_r.get_value(1), Some(Value::F64(0.9)));
+
+    assert!(matches!(
+        left.join_with_suffixes(&right, &["id"], JoinType::Inner, ("_x", "_x")),
+        Err(VeloxxError::InvalidOperation(_))
+    ));
+
+    // Renaming `score` to `score_l` would overwrite the left frame's own `score_l`.
+    let mut crowded = left.clone();
+    crowded.columns.insert(
+        "score_l".to_string(),
+        Series::new_i32("score_l", vec![Some(1), Some(2)]),
+    );
+    assert!(matches!(
+        crowded.join_with_suffixes(&right, &["id"], JoinType::Inner, ("_l", "_r")),
+        Err(VeloxxError::InvalidOperation(_))
+    ));
+}
@@ -40,7 +40,7 @@ mod tests {
 
         // Pivot: index="date", columns="city", values="temp"
         let pivoted = df
-            .pivot("temp", vec!["date".to_string()], "city", "mean")
+            .pivot(&["date".to_string()], "city", &["temp"], "mean")
             .expect("Pivot failed");
 
         println!("{}", pivoted);
@@ -116,7 +116,7 @@ mod tests {
 
         // Pivot: index=["A", "B"], columns="C", values="D"
         let pivoted = df
-            .pivot("D", vec!["A".to_string(), "B".to_string()], "C", "sum")
+            .pivot(&["A".to_string(), "B".to_string()], "C", &["D"], "sum")
             .unwrap();
 
         // Expected rows: (foo, one), (foo, two), (bar, one)
@@ -132,4 +132,544 @@ mod tests {
         // foo -> two -> small=2
         // bar -> one -> large=3 (small=null)
     }
+
+    #[test]
+    fn test_pivot_sums_revenue_by_region_and_quarter() {
+        use veloxx::types::Value;
+        use veloxx::VeloxxError;
+
+        let mut columns = IndexMap::new();
+        columns.insert(
+            "region".to_string(),
+            Series::new_string(
+                "region",
+                ["East", "East", "West", "East", "West"]
+                    .iter()
+                    .map(|s| Some(s.to_string()))
+                    .collect(),
+            ),
+        );
+        columns.insert(
+            "quarter".to_string(),
+            Series::new_string(
+                "quarter",
+                ["Q1", "Q2", "Q1", "Q1", "Q3"]
+                    .iter()
+                    .map(|s| Some(s.to_string()))
+                    .collect(),
+            ),
+        );
+        columns.insert(
+            "revenue".to_string(),
+            Series::new_f64(
+                "revenue",
+                vec![Some(100.0), Some(50.0), Some(70.0), Some(25.5), Some(10.0)],
+            ),
+        );
+        let df = DataFrame::new(columns);
+        let index = vec!["region".to_string()];
+
+        let pivoted = df.pivot(&index, "quarter", &["revenue"], "sum").unwrap();
+        assert_eq!(
+            pivoted.column_names(),
+            vec!["region", "Q1", "Q2", "Q3"]
+                .into_iter()
+                .map(String::from)
+                .collect::<Vec<_>>()
+        );
+        let region = pivoted.get_column("region").unwrap();
+        assert_eq!(region.get_string(0).unwrap(), "East");
+        assert_eq!(region.get_string(1).unwrap(), "West");
+        let q1 = pivoted.get_column("Q1").unwrap();
+        assert_eq!(q1.get_f64(0), Some(125.5));
+        assert_eq!(q1.get_f64(1), Some(70.0));
+        let q2 = pivoted.get_column("Q2").unwrap();
+        assert_eq!(q2.get_f64(0), Some(50.0));
+        assert_eq!(q2.get_value(1), None);
+        let q3 = pivoted.get_column("Q3").unwrap();
+        assert_eq!(q3.get_value(0), None);
+        assert_eq!(q3.get_f64(1), Some(10.0));
+
+        let counts = df.pivot(&index, "quarter", &["revenue"], "count").unwrap();
+        assert_eq!(
+            counts.get_column("Q1").unwrap().get_value(0),
+            Some(Value::I32(2))
+        );
+
+        assert!(matches!(
+            df.pivot(&index, "quarter", &["revenue"], "median"),
+            Err(VeloxxError::Unsupported(_))
+        ));
+        assert!(matches!(
+            df.pivot(&index, "missing", &["revenue"], "sum"),
+            Err(VeloxxError::ColumnNotFound(_))
+        ));
+        assert!(matches!(
+            df.pivot(&["missing".to_string()], "quarter", &["revenue"], "sum"),
+            Err(VeloxxError::ColumnNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_pivot_table_aggregations() {
+        let mut columns = IndexMap::new();
+        columns.insert(
+            "region".to_string(),
+            Series::new_string(
+                "region",
+                vec![
+                    Some("North".to_string()),
+                    Some("North".to_string()),
+                    Some("South".to_string()),
+                    Some("North".to_string()),
+                ],
+            ),
+        );
+        columns.insert(
+            "year".to_string(),
+            Series::new_i32("year", vec![Some(2020), Some(2021), Some(2020), Some(2020)]),
+        );
+        columns.insert(
+            "sales".to_string(),
+            Series::new_i32("sales", vec![Some(10), Some(20), Some(5), Some(30)]),
+        );
+        let df = DataFrame::new(columns);
+
+        let table = df
+            .pivot_table(vec!["region".to_string()], "year", "sales", "sum")
+            .unwrap();
+        assert_eq!(
+            table.column_names(),
+            vec!["region".to_string(), "2020".to_string(), "2021".to_string()]
+        );
+        assert_eq!(table.row_count(), 2);
+        let region = table.get_column("region").unwrap();
+        assert_eq!(region.get_string(0).unwrap(), "North");
+        assert_eq!(region.get_string(1).unwrap(), "South");
+        let y2020 = table.get_column("2020").unwrap();
+        let y2021 = table.get_column("2021").unwrap();
+        assert_eq!(y2020.get_i32(0), Some(40));
+        assert_eq!(y2020.get_i32(1), Some(5));
+        assert_eq!(y2021.get_i32(0), Some(20));
+        // South has no 2021 rows, so the cell is null.
+        assert_eq!(y2021.get_value(1), None);
+
+        let means = df
+            .pivot_table(vec!["region".to_string()], "year", "sales", "mean")
+            .unwrap();
+        assert_eq!(means.get_column("2020").unwrap().get_f64(0), Some(20.0));
+
+        let maxes = df
+            .pivot_table(vec!["region".to_string()], "year", "sales", "max")
+            .unwrap();
+        assert_eq!(maxes.get_column("2020").unwrap().get_i32(0), Some(30));
+
+        assert!(df
+            .pivot_table(vec!["region".to_string()], "year", "sales", "bogus")
+            .is_err());
+    }
+
+    #[test]
+    fn test_pivot_table_orders_numeric_headers_and_rejects_clashes() {
+        let mut columns = IndexMap::new();
+        columns.insert(
+            "store".to_string(),
+            Series::new_string("store", vec![Some("a".to_string()); 3]),
+        );
+        columns.insert(
+            "week".to_string(),
+            Series::new_i32("week", vec![Some(10), Some(2), Some(1)]),
+        );
+        columns.insert(
+            "sales".to_string(),
+            Series::new_i32("sales", vec![Some(7), Some(8), Some(9)]),
+        );
+        let df = DataFrame::new(columns);
+
+        let table = df
+            .pivot_table(vec!["store".to_string()], "week", "sales", "sum")
+            .unwrap();
+        assert_eq!(table.column_names(), vec!["store", "1", "2", "10"]);
+
+        let mut columns = IndexMap::new();
+        columns.insert(
+            "id".to_string(),
+            Series::new_i32("id", vec![Some(1), Some(2)]),
+        );
+        columns.insert(
+            "key".to_string(),
+            Series::new_string("key", vec![Some("id".to_string()), Some("x".to_string())]),
+        );
+        columns.insert(
+            "v".to_string(),
+            Series::new_i32("v", vec![Some(1), Some(2)]),
+        );
+        let clash = DataFrame::new(columns);
+        assert!(matches!(
+            clash.pivot_table(vec!["id".to_string()], "key", "v", "sum"),
+            Err(veloxx::error::VeloxxError::InvalidOperation(_))
+        ));
+    }
+
+    #[test]
+    fn test_unpivot_preserve_types() {
+        use veloxx::types::{DataType, Value};
+
+        let mut columns = IndexMap::new();
+        columns.insert(
+            "id".to_string(),
+            Series::new_string("id", vec![Some("x".to_string()), Some("y".to_string())]),
+        );
+        columns.insert(
+            "q1".to_string(),
+            Series::new_i32("q1", vec![Some(1), Some(2)]),
+        );
+        columns.insert("q2".to_string(), Series::new_i32("q2", vec![Some(3), None]));
+        columns.insert(
+            "ratio".to_string(),
+            Series::new_f64("ratio", vec![Some(0.5), Some(1.5)]),
+        );
+        columns.insert(
+            "seen".to_string(),
+            Series::new_datetime("seen", vec![Some(100), Some(200)]),
+        );
+        let df = DataFrame::new(columns);
+        let ids = vec!["id".to_string()];
+
+        let long = df
+            .unpivot_preserve_types(&ids, &["q1".to_string(), "q2".to_string()])
+            .unwrap();
+        assert_eq!(long.row_count(), 4);
+        assert_eq!(
+            long.column_names(),
+            vec![
+                "id".to_string(),
+                "variable".to_string(),
+                "value".to_string()
+            ]
+        );
+        let value = long.get_column("value").unwrap();
+        assert_eq!(value.data_type(), DataType::I32);
+        assert_eq!(value.get_value(2), Some(Value::I32(3)));
+        assert_eq!(value.get_value(3), None);
+        let variable = long.get_column("variable").unwrap();
+        assert_eq!(variable.get_string(2).unwrap(), "q2");
+        assert_eq!(long.get_column("id").unwrap().get_string(3).unwrap(), "y");
+
+        let dates = df
+            .unpivot_preserve_types(&ids, &["seen".to_string()])
+            .unwrap();
+        assert_eq!(
+            dates.get_column("value").unwrap().data_type(),
+            DataType::DateTime
+        );
+
+        let numeric = df
+            .unpivot_preserve_types(&ids, &["q1".to_string(), "ratio".to_string()])
+            .unwrap();
+        let value = numeric.get_column("value").unwrap();
+        assert_eq!(value.data_type(), DataType::F64);
+        assert_eq!(value.get_value(0), Some(Value::F64(1.0)));
+
+        let mixed = df
+            .unpivot_preserve_types(&ids, &["q1".to_string(), "seen".to_string()])
+            .unwrap();
+        assert_eq!(
+            mixed.get_column("value").unwrap().data_type(),
+            DataType::String
+        );
+    }
+
+    #[test]
+    fn test_melt_wide_frame_to_long() {
+        use veloxx::types::{DataType, Value};
+        use veloxx::VeloxxError;
+
+        let mut columns = IndexMap::new();
+        columns.insert(
+            "city".to_string(),
+            Series::new_string(
+                "city",
+                vec![Some("Oslo".to_string()), Some("Rome".to_string())],
+            ),
+        );
+        columns.insert(
+            "jan".to_string(),
+            Series::new_f64("jan", vec![Some(-4.5), Some(8.0)]),
+        );
+        columns.insert(
+            "feb".to_string(),
+            Series::new_i32("feb", vec![Some(-3), None]),
+        );
+        columns.insert(
+            "note".to_string(),
+            Series::new_string("note", vec![Some("cold".to_string()), None]),
+        );
+        let df = DataFrame::new(columns);
+        let ids = vec!["city".to_string()];
+        let months = vec!["jan".to_string(), "feb".to_string()];
+
+        let long = df.melt(&ids, &months).unwrap();
+        assert_eq!(long.row_count(), df.row_count() * months.len());
+        assert_eq!(
+            long.column_names(),
+            vec![
+                "city".to_string(),
+                "variable".to_string(),
+                "value".to_string()
+            ]
+        );
+        let city = long.get_column("city").unwrap();
+        let variable = long.get_column("variable").unwrap();
+        let value = long.get_column("value").unwrap();
+        assert_eq!(value.data_type(), DataType::F64);
+        let rows: Vec<(String, String, Option<Value>)> = (0..long.row_count())
+            .map(|i| {
+                (
+                    city.get_string(i).unwrap().clone(),
+                    variable.get_string(i).unwrap().clone(),
+                    value.get_value(i),
+                )
+            })
+            .collect();
+        assert_eq!(
+            rows,
+            vec![
+                (
+                    "Oslo".to_string(),
+                    "jan".to_string(),
+                    Some(Value::F64(-4.5))
+                ),
+                ("Rome".to_string(), "jan".to_string(), Some(Value::F64(8.0))),
+                (
+                    "Oslo".to_string(),
+                    "feb".to_string(),
+                    Some(Value::F64(-3.0))
+                ),
+                ("Rome".to_string(), "feb".to_string(), None),
+            ]
+        );
+
+        let mixed = vec!["jan".to_string(), "note".to_string()];
+        assert!(matches!(
+            df.melt(&ids, &mixed),
+            Err(VeloxxError::DataTypeMismatch(_))
+        ));
+        assert!(matches!(
+            df.melt(&ids, &["mar".to_string()]),
+            Err(VeloxxError::ColumnNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_melt_with_custom_names() {
+        let mut columns = IndexMap::new();
+        columns.insert(
+            "sensor".to_string(),
+            Series::new_string("sensor", vec![Some("a".to_string()), Some("b".to_string())]),
+        );
+        columns.insert(
+            "temp".to_string(),
+            Series::new_f64("temp", vec![Some(20.5), Some(22.0)]),
+        );
+        columns.insert(
+            "humidity".to_string(),
+            Series::new_f64("humidity", vec![Some(0.3), None]),
+        );
+        let df = DataFrame::new(columns);
+        let ids = vec!["sensor".to_string()];
+        let values = vec!["temp".to_string(), "humidity".to_string()];
+
+        let long = df
+            .melt_with_names(&ids, &values, "metric", "reading")
+            .unwrap();
+        assert_eq!(long.row_count(), 4);
+        assert_eq!(
+            long.column_names(),
+            vec![
+                "sensor".to_string(),
+                "metric".to_string(),
+                "reading".to_string()
+            ]
+        );
+        let metric = long.get_column("metric").unwrap();
+        assert_eq!(metric.get_string(2).unwrap(), "humidity");
+        let reading = long.get_column("reading").unwrap();
+        assert_eq!(reading.get_f64(1), Some(22.0));
+        assert_eq!(reading.get_f64(3), None);
+
+        assert!(df
+            .melt_with_names(&ids, &values, "sensor", "reading")
+            .is_err());
+        assert!(df
+            .melt_with_names(&ids, &values, "metric", "sensor")
+            .is_err());
+        assert!(df
+            .melt_with_names(&ids, &values, "metric", "metric")
+            .is_err());
+
+        // Same type check as `melt`: a String column cannot share a value column with F64.
+        let mixed = vec!["temp".to_string(), "sensor".to_string()];
+        assert!(matches!(
+            df.melt_with_names(&[], &mixed, "metric", "reading"),
+            Err(veloxx::error::VeloxxError::DataTypeMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn test_pivot_multiple_value_columns() {
+        use veloxx::types::Value;
+
+        let strings = |name: &str, values: [&str; 4]| {
+            Series::new_string(name, values.iter().map(|s| Some(s.to_string())).collect())
+        };
+        let mut columns = IndexMap::new();
+        columns.insert(
+            "month".to_string(),
+            strings("month", ["Jan", "Jan", "Feb", "Jan"]),
+        );
+        columns.insert(
+            "region".to_string(),
+            strings("region", ["West", "East", "East", "West"]),
+        );
+        columns.insert(
+            "revenue".to_string(),
+            Series::new_f64(
+                "revenue",
+                vec![Some(10.0), Some(20.0), Some(30.0), Some(5.0)],
+            ),
+        );
+        columns.insert(
+            "units".to_string(),
+            Series::new_i32("units", vec![Some(1), Some(2), Some(3), Some(4)]),
+        );
+        let df = DataFrame::new(columns);
+
+        let pivoted = df
+            .pivot(
+                &["month".to_string()],
+                "region",
+                &["revenue", "units"],
+                "sum",
+            )
+            .unwrap();
+        assert_eq!(
+            pivoted.column_names(),
+            vec![
+                "month",
+                "revenue_East",
+                "revenue_West",
+                "units_East",
+                "units_West"
+            ]
+        );
+        assert_eq!(pivoted.row_count(), 2);
+        let revenue_west = pivoted.get_column("revenue_West").unwrap();
+        assert_eq!(revenue_west.name(), "revenue_West");
+        assert_eq!(revenue_west.get_value(0), Some(Value::F64(15.0)));
+        assert_eq!(revenue_west.get_value(1), None);
+        let units_east = pivoted.get_column("units_East").unwrap();
+        assert_eq!(units_east.get_value(0), Some(Value::I32(2)));
+        assert_eq!(units_east.get_value(1), Some(Value::I32(3)));
+
+        assert!(df
+            .pivot(&["month".to_string()], "region", &[], "sum")
+            .is_err());
+    }
+
+    /// Melts `wide` over `value_vars`, pivots it back with `agg` and checks every column of
+    /// `wide` is reconstructed, ignoring column order.
+    fn assert_melt_pivot_roundtrip(
+        wide: &DataFrame,
+        id_vars: &[String],
+        value_vars: &[String],
+        agg: &str,
+    ) {
+        let long = wide.melt(id_vars, value_vars).unwrap();
+        let back = long.pivot(id_vars, "variable", &["value"], agg).unwrap();
+        assert_eq!(back.column_count(), wide.column_count());
+        assert_eq!(back.row_count(), wide.row_count());
+        for name in wide.column_names() {
+            assert_eq!(
+                back.get_column(&name),
+                wide.get_column(&name),
+                "column {name}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_melt_then_pivot_reconstructs_frame() {
+        use veloxx::types::Value;
+
+        let mut columns = IndexMap::new();
+        columns.insert(
+            "city".to_string(),
+            Series::new_string(
+                "city",
+                vec![Some("Oslo".to_string()), None, Some("Lima".to_string())],
+            ),
+        );
+        columns.insert(
+            "year".to_string(),
+            Series::new_i32("year", vec![Some(2020), Some(2020), Some(2021)]),
+        );
+        columns.insert(
+            "low".to_string(),
+            Series::new_f64("low", vec![Some(f64::NEG_INFINITY), Some(-1.5), None]),
+        );
+        columns.insert(
+            "high".to_string(),
+            Series::new_f64("high", vec![Some(f64::INFINITY), None, None]),
+        );
+        let wide = DataFrame::new(columns);
+        let ids = vec!["city".to_string(), "year".to_string()];
+        let values = vec!["low".to_string(), "high".to_string()];
+
+        for agg in ["first", "min", "max", "sum"] {
+            assert_melt_pivot_roundtrip(&wide, &ids, &values, agg);
+        }
+
+        let mut columns = IndexMap::new();
+        columns.insert(
+            "id".to_string(),
+            Series::new_i32("id", vec![Some(1), Some(2)]),
+        );
+        columns.insert(
+            "name".to_string(),
+            Series::new_string("name", vec![Some("ann".to_string()), None]),
+        );
+        columns.insert(
+            "nickname".to_string(),
+            Series::new_string("nickname", vec![None, Some("bo".to_string())]),
+        );
+        let labels = DataFrame::new(columns);
+        assert_melt_pivot_roundtrip(
+            &labels,
+            &["id".to_string()],
+            &["name".to_string(), "nickname".to_string()],
+            "first",
+        );
+
+        // Mixed numeric value columns come back in the type melt widened them to.
+        let mut columns = IndexMap::new();
+        columns.insert(
+            "id".to_string(),
+            Series::new_i32("id", vec![Some(1), Some(2)]),
+        );
+        columns.insert("a".to_string(), Series::new_i32("a", vec![Some(3), None]));
+        columns.insert(
+            "b".to_string(),
+            Series::new_f64("b", vec![Some(0.5), Some(1.5)]),
+        );
+        let mixed = DataFrame::new(columns);
+        let back = mixed
+            .melt(&["id".to_string()], &["a".to_string(), "b".to_string()])
+            .unwrap()
+            .pivot(&["id".to_string()], "variable", &["value"], "first")
+            .unwrap();
+        let a = back.get_column("a").unwrap();
+        assert_eq!(a.get_value(0), Some(Value::F64(3.0)));
+        assert_eq!(a.get_value(1), None);
+    }
 }
@@ -0,0 +1,709 @@
+use veloxx::series::{Interpolation, RoundingMode, Series};
+use veloxx::types::{DataType, Value};
+
+#[test]
+fn test_is_in_mask() {
+    let ids = Series::new_i32("id", vec![Some(1), Some(2), None, Some(4), Some(2)]);
+    let whitelist = Series::new_i32("allowed", vec![Some(2), Some(4), None]);
+
+    let mask = ids.is_in(&whitelist).unwrap();
+    assert_eq!(mask.name(), "id");
+    assert_eq!(mask.get_value(0), Some(Value::Bool(false)));
+    assert_eq!(mask.get_value(1), Some(Value::Bool(true)));
+    assert_eq!(mask.get_value(2), None);
+    assert_eq!(mask.get_value(3), Some(Value::Bool(true)));
+    assert_eq!(mask.get_value(4), Some(Value::Bool(true)));
+
+    let filtered = ids.filter_by_mask(&mask).unwrap();
+    assert_eq!(filtered.len(), 3);
+}
+
+#[test]
+fn test_is_in_type_mismatch() {
+    let ids = Series::new_i32("id", vec![Some(1)]);
+    let names = Series::new_string("name", vec![Some("1".to_string())]);
+    assert!(ids.is_in(&names).is_err());
+}
+
+#[test]
+fn test_slice_clamps_to_bounds() {
+    let series = Series::new_string(
+        "s",
+        vec![
+            Some("a".to_string()),
+            None,
+            Some("c".to_string()),
+            Some("d".to_string()),
+        ],
+    );
+
+    let middle = series.slice(1, 2).unwrap();
+    assert_eq!(middle.name(), "s");
+    assert_eq!(middle.len(), 2);
+    assert_eq!(middle.get_value(0), None);
+    assert_eq!(middle.get_value(1), Some(Value::String("c".to_string())));
+
+    let tail = series.slice(2, 100).unwrap();
+    assert_eq!(tail.len(), 2);
+    assert_eq!(tail.get_value(1), Some(Value::String("d".to_string())));
+
+    let past_end = series.slice(10, 2).unwrap();
+    assert!(past_end.is_empty());
+}
+
+#[test]
+fn test_round_half_even() {
+    let amounts = Series::new_f64(
+        "amount",
+        vec![Some(2.5), Some(3.5), Some(-2.5), Some(0.125), None],
+    );
+
+    let even = amounts.round_half_even(0).unwrap();
+    assert_eq!(
+        even.get_data_f64().unwrap()[..3],
+        [Some(2.0), Some(4.0), Some(-2.0)]
+    );
+    assert_eq!(even.get_value(4), None);
+    assert_eq!(amounts.round_half_even(2).unwrap().get_f64(3), Some(0.12));
+
+    let default = amounts.round(0).unwrap();
+    assert_eq!(
+        default,
+        amounts
+            .round_with_mode(0, RoundingMode::HalfAwayFromZero)
+            .unwrap()
+    );
+    assert_eq!(
+        default.get_data_f64().unwrap()[..3],
+        [Some(3.0), Some(4.0), Some(-3.0)]
+    );
+
+    let labels = Series::new_string("label", vec![Some("a".to_string())]);
+    assert!(labels.round(0).is_err());
+}
+
+#[test]
+fn test_cast_f64_to_i32_truncates_and_nulls_out_of_range() {
+    let values = Series::new_f64(
+        "v",
+        vec![
+            Some(3.9),
+            Some(-3.9),
+            Some(f64::NAN),
+            Some(3e9),
+            Some(-3e9),
+            None,
+            Some(7.0),
+        ],
+    );
+    let cast = values.cast(veloxx::types::DataType::I32).unwrap();
+    assert_eq!(
+        cast.get_data_i32().unwrap(),
+        vec![Some(3), Some(-3), None, None, None, None, Some(7)]
+    );
+
+    let (_, lossy_rows) = values.try_cast(veloxx::types::DataType::I32).unwrap();
+    assert_eq!(lossy_rows, vec![0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn test_owned_and_borrowed_vec_getters() {
+    let names = Series::new_string("name", vec![Some("a".to_string()), None]);
+    assert_eq!(names.as_vec_string().unwrap(), vec![Some("a"), None]);
+    assert_eq!(
+        names.clone().into_vec_string().unwrap(),
+        vec![Some("a".to_string()), None]
+    );
+    assert!(names.as_vec_i32().is_err());
+
+    let ids = Series::new_i32("id", vec![None, Some(2)]);
+    assert_eq!(ids.as_vec_i32().unwrap(), vec![None, Some(2)]);
+    assert_eq!(ids.into_vec_i32().unwrap(), vec![None, Some(2)]);
+
+    let flags = Series::new_bool("flag", vec![Some(true), None]);
+    assert_eq!(flags.into_vec_bool().unwrap(), vec![Some(true), None]);
+    let stamps = Series::new_datetime("ts", vec![Some(10), None]);
+    assert!(stamps.clone().into_vec_f64().is_err());
+    assert_eq!(stamps.into_vec_datetime().unwrap(), vec![Some(10), None]);
+}
+
+#[test]
+fn test_elementwise_arithmetic_between_series() {
+    let a = Series::new_i32("a", vec![Some(6), Some(i32::MAX), None, Some(7)]);
+    let b = Series::new_i32("b", vec![Some(3), Some(1), Some(2), Some(0)]);
+    let x = Series::new_f64("x", vec![Some(0.5), Some(1.0), Some(1.0), Some(0.0)]);
+
+    let sum = a.add(&b).unwrap();
+    assert_eq!(sum.name(), "a_add_b");
+    // Overflow and null operands both yield null.
+    assert_eq!(
+        sum.get_data_i32().unwrap(),
+        vec![Some(9), None, None, Some(7)]
+    );
+    assert_eq!(
+        a.div(&b).unwrap().get_data_i32().unwrap(),
+        vec![Some(2), Some(i32::MAX), None, None]
+    );
+
+    let promoted = a.mul(&x).unwrap();
+    assert_eq!(
+        promoted.get_data_f64().unwrap(),
+        vec![Some(3.0), Some(i32::MAX as f64), None, Some(0.0)]
+    );
+    assert_eq!(a.div(&x).unwrap().get_value(3), None);
+    assert_eq!(b.sub(&x).unwrap().get_f64(0), Some(2.5));
+
+    let short = Series::new_i32("short", vec![Some(1)]);
+    assert!(a.add(&short).is_err());
+}
+
+#[test]
+fn test_series_modes() {
+    let series = Series::new_string(
+        "s",
+        vec![
+            Some("b".to_string()),
+            Some("a".to_string()),
+            Some("a".to_string()),
+            None,
+            Some("b".to_string()),
+            Some("c".to_string()),
+        ],
+    );
+    let modes = series.modes().unwrap();
+    assert_eq!(modes.name(), "s");
+    assert_eq!(modes.len(), 2);
+    assert_eq!(modes.get_value(0), Some(Value::String("b".to_string())));
+    assert_eq!(modes.get_value(1), Some(Value::String("a".to_string())));
+
+    let single = Series::new_f64("f", vec![Some(1.5), Some(2.5), Some(1.5)]);
+    let modes = single.modes().unwrap();
+    assert_eq!(modes.len(), 1);
+    assert_eq!(modes.get_value(0), Some(Value::F64(1.5)));
+
+    let all_null = Series::new_i32("n", vec![None, None]);
+    let modes = all_null.modes().unwrap();
+    assert_eq!(modes.len(), 0);
+    assert_eq!(modes.data_type(), all_null.data_type());
+}
+
+#[test]
+fn test_between_mask() {
+    let prices = Series::new_f64(
+        "price",
+        vec![Some(1.0), Some(2.5), None, Some(5.0), Some(7.0)],
+    );
+
+    let inclusive = prices
+        .between(&Value::F64(2.5), &Value::I32(5), true)
+        .unwrap();
+    let values: Vec<Option<Value>> = (0..5).map(|i| inclusive.get_value(i)).collect();
+    assert_eq!(
+        values,
+        vec![
+            Some(Value::Bool(false)),
+            Some(Value::Bool(true)),
+            None,
+            Some(Value::Bool(true)),
+            Some(Value::Bool(false)),
+        ]
+    );
+
+    let exclusive = prices
+        .between(&Value::F64(2.5), &Value::I32(5), false)
+        .unwrap();
+    assert_eq!(exclusive.get_value(1), Some(Value::Bool(false)));
+    assert_eq!(exclusive.get_value(3), Some(Value::Bool(false)));
+    assert_eq!(prices.filter_by_mask(&inclusive).unwrap().len(), 2);
+
+    let names = Series::new_string(
+        "name",
+        vec![Some("bob".to_string()), Some("zed".to_string())],
+    );
+    let mask = names
+        .between(
+            &Value::String("a".to_string()),
+            &Value::String("m".to_string()),
+            true,
+        )
+        .unwrap();
+    assert_eq!(mask.get_value(0), Some(Value::Bool(true)));
+    assert_eq!(mask.get_value(1), Some(Value::Bool(false)));
+
+    assert!(prices
+        .between(&Value::String("a".to_string()), &Value::I32(5), true)
+        .is_err());
+    assert!(names.between(&Value::I32(1), &Value::I32(5), true).is_err());
+}
+
+#[test]
+fn test_quantile_interpolation_odd_length() {
+    // Sorted: [10, 20, 30, 40, 50]; q=0.25 -> rank 1.0, q=0.75 -> rank 3.0.
+    let series = Series::new_i32(
+        "x",
+        vec![Some(50), Some(10), None, Some(40), Some(20), Some(30)],
+    );
+    assert_eq!(
+        series.quantile(0.25, Interpolation::Linear).unwrap(),
+        Some(Value::F64(20.0))
+    );
+    assert_eq!(
+        series.quantile(0.75, Interpolation::Linear).unwrap(),
+        Some(Value::F64(40.0))
+    );
+    assert_eq!(
+        series.quantile(0.75, Interpolation::Nearest).unwrap(),
+        Some(Value::I32(40))
+    );
+    assert_eq!(
+        series.quantile(0.5, Interpolation::Midpoint).unwrap(),
+        Some(Value::F64(30.0))
+    );
+}
+
+#[test]
+fn test_quantile_interpolation_even_length() {
+    // Sorted: [1, 2, 4, 8]; q=0.25 -> rank 0.75, q=0.75 -> rank 2.25.
+    let series = Series::new_f64("x", vec![Some(8.0), Some(1.0), Some(4.0), Some(2.0)]);
+    let at = |q, interpolation| series.quantile(q, interpolation).unwrap();
+    assert_eq!(at(0.25, Interpolation::Linear), Some(Value::F64(1.75)));
+    assert_eq!(at(0.25, Interpolation::Lower), Some(Value::F64(1.0)));
+    assert_eq!(at(0.25, Interpolation::Higher), Some(Value::F64(2.0)));
+    assert_eq!(at(0.25, Interpolation::Nearest), Some(Value::F64(2.0)));
+    assert_eq!(at(0.25, Interpolation::Midpoint), Some(Value::F64(1.5)));
+    assert_eq!(at(0.75, Interpolation::Linear), Some(Value::F64(5.0)));
+    assert_eq!(at(0.75, Interpolation::Nearest), Some(Value::F64(4.0)));
+    assert_eq!(at(0.5, Interpolation::Linear), Some(Value::F64(3.0)));
+}
+
+#[test]
+fn test_quantile_datetime_and_edge_cases() {
+    let times = Series::new_datetime("t", vec![Some(100), Some(200), Some(400)]);
+    assert_eq!(
+        times.quantile(0.75, Interpolation::Linear).unwrap(),
+        Some(Value::DateTime(300))
+    );
+
+    let empty = Series::new_i32("x", vec![None, None]);
+    assert_eq!(empty.quantile(0.5, Interpolation::Linear).unwrap(), None);
+    assert!(times.quantile(1.5, Interpolation::Linear).is_err());
+    assert!(times.quantile(-0.1, Interpolation::Lower).is_err());
+
+    let labels = Series::new_string("s", vec![Some("a".to_string())]);
+    assert!(labels.quantile(0.5, Interpolation::Linear).is_err());
+}
+
+#[test]
+fn test_cast_string_to_datetime() {
+    let series = Series::new_string(
+        "ts",
+        vec![
+            Some("2024-01-02".to_string()),
+            Some("2024-01-02T00:00:10".to_string()),
+            Some("2024-01-02 00:01:00".to_string()),
+            Some("Jan 2".to_string()),
+        ],
+    );
+    let (cast, lossy_rows) = series.try_cast(DataType::DateTime).unwrap();
+    assert_eq!(
+        cast.get_data_datetime().unwrap(),
+        vec![
+            Some(1_704_153_600),
+            Some(1_704_153_610),
+            Some(1_704_153_660),
+            None
+        ]
+    );
+    assert_eq!(lossy_rows, vec![3]);
+}
+
+#[test]
+fn test_i64_series_beyond_i32_range() {
+    let big = 5_000_000_000i64;
+    let series = Series::new_i64("bytes", vec![Some(big), None, Some(-big), Some(10)]);
+    assert_eq!(series.data_type(), DataType::I64);
+    assert_eq!(series.get_value(0), Some(Value::I64(big)));
+    assert_eq!(series.get_value(1), None);
+
+    assert_eq!(series.sum().unwrap(), Value::I64(10));
+    assert!(Series::new_i32("x", vec![Some(i32::MAX), Some(1)])
+        .sum()
+        .is_err());
+    assert_eq!(series.min().unwrap(), Value::I64(-big));
+    assert_eq!(series.max().unwrap(), Value::I64(big));
+    assert_eq!(series.median().unwrap(), Value::F64(10.0));
+
+    let filled = series.fill_nulls(&Value::I64(7)).unwrap();
+    assert_eq!(filled.get_value(1), Some(Value::I64(7)));
+
+    let picked = series.filter(&[0, 3]).unwrap();
+    assert_eq!(picked.get_data_i64().unwrap(), vec![Some(big), Some(10)]);
+
+    let appended = picked.append(&Series::new_i64("more", vec![None])).unwrap();
+    assert_eq!(
+        appended.get_data_i64().unwrap(),
+        vec![Some(big), Some(10), None]
+    );
+}
+
+#[test]
+fn test_i64_casts() {
+    let ints = Series::new_i32("n", vec![Some(i32::MAX), None, Some(-3)]);
+    let widened = ints.cast(DataType::I64).unwrap();
+    assert_eq!(
+        widened.get_data_i64().unwrap(),
+        vec![Some(i32::MAX as i64), None, Some(-3)]
+    );
+    assert_eq!(widened.to_vec_f64().unwrap(), vec![i32::MAX as f64, -3.0]);
+
+    let big = Series::new_i64("n", vec![Some(1 << 40), Some(42)]);
+    let floats = big.cast(DataType::F64).unwrap();
+    assert_eq!(floats.get_value(0), Some(Value::F64((1u64 << 40) as f64)));
+
+    // Values that do not fit in an i32 become null when narrowing.
+    let narrowed = big.cast(DataType::I32).unwrap();
+    assert_eq!(narrowed.get_value(0), None);
+    assert_eq!(narrowed.get_value(1), Some(Value::I32(42)));
+}
+
+#[test]
+fn test_encode_ordinal_uses_given_order() {
+    let priority = Series::new_string(
+        "priority",
+        vec![
+            Some("medium".to_string()),
+            Some("low".to_string()),
+            None,
+            Some("high".to_string()),
+            Some("urgent".to_string()),
+        ],
+    );
+    let codes = priority.encode_ordinal(&["low", "medium", "high"]).unwrap();
+    assert_eq!(codes.name(), "priority");
+    assert_eq!(
+        codes.get_data_i32().unwrap(),
+        vec![Some(1), Some(0), None, Some(2), None]
+    );
+
+    assert!(priority.encode_ordinal(&["low", "low"]).is_err());
+    let numbers = Series::new_i32("n", vec![Some(1)]);
+    assert!(numbers.encode_ordinal(&["1"]).is_err());
+}
+
+#[test]
+fn test_to_dummies_sorted_indicator_columns() {
+    let fruit = Series::new_string(
+        "fruit",
+        vec![
+            Some("pear".to_string()),
+            Some("apple".to_string()),
+            None,
+            Some("pear".to_string()),
+        ],
+    );
+    let dummies = fruit.to_dummies("is").unwrap();
+    assert_eq!(
+        dummies.column_names(),
+        vec!["is_apple".to_string(), "is_pear".to_string()]
+    );
+    assert_eq!(dummies.row_count(), 4);
+    assert_eq!(
+        dummies
+            .get_column("is_apple")
+            .unwrap()
+            .get_data_bool()
+            .unwrap(),
+        vec![Some(false), Some(true), Some(false), Some(false)]
+    );
+    assert_eq!(
+        dummies
+            .get_column("is_pear")
+            .unwrap()
+            .get_data_bool()
+            .unwrap(),
+        vec![Some(true), Some(false), Some(false), Some(true)]
+    );
+
+    let numbers = Series::new_i32("n", vec![Some(1)]);
+    assert!(numbers.to_dummies("n").is_err());
+}
+
+#[test]
+fn test_series_diff_first_differences() {
+    let series = Series::new_i32("x", vec![Some(10), Some(13), Some(9)]);
+    let diff = series.diff(1).unwrap();
+    assert_eq!(diff.data_type(), DataType::I32);
+    let values: Vec<Option<Value>> = (0..3).map(|i| diff.get_value(i)).collect();
+    assert_eq!(
+        values,
+        vec![None, Some(Value::I32(3)), Some(Value::I32(-4))]
+    );
+
+    let back = series.diff(-1).unwrap();
+    let values: Vec<Option<Value>> = (0..3).map(|i| back.get_value(i)).collect();
+    assert_eq!(
+        values,
+        vec![Some(Value::I32(-3)), Some(Value::I32(4)), None]
+    );
+
+    let floats = Series::new_f64("y", vec![Some(1.5), None, Some(4.0), Some(5.0)]);
+    let diff = floats.diff(2).unwrap();
+    let values: Vec<Option<Value>> = (0..4).map(|i| diff.get_value(i)).collect();
+    assert_eq!(values, vec![None, None, Some(Value::F64(2.5)), None]);
+
+    assert!(Series::new_bool("b", vec![Some(true)]).diff(1).is_err());
+}
+
+#[test]
+fn test_series_is_monotonic() {
+    let increasing = Series::new_i32("x", vec![Some(1), Some(2), Some(2), Some(5)]);
+    assert!(increasing.is_monotonic_increasing());
+    assert!(!increasing.is_monotonic_decreasing());
+
+    let decreasing = Series::new_f64("y", vec![Some(3.0), Some(1.5), Some(1.5)]);
+    assert!(decreasing.is_monotonic_decreasing());
+    assert!(!decreasing.is_monotonic_increasing());
+
+    let with_null = Series::new_i64("z", vec![Some(1), None, Some(3)]);
+    assert!(!with_null.is_monotonic_increasing());
+    assert!(!with_null.is_monotonic_decreasing());
+    assert!(!Series::new_f64("nan", vec![Some(1.0), Some(f64::NAN)]).is_monotonic_increasing());
+
+    let flags = Series::new_bool("b", vec![Some(false), Some(true), Some(true)]);
+    assert!(flags.is_monotonic_increasing());
+    let empty = Series::new_string("s", Vec::new());
+    assert!(empty.is_monotonic_increasing() && empty.is_monotonic_decreasing());
+}
+
+#[test]
+fn test_delta_encode_round_trips_with_nulls() {
+    let timestamps = Series::new_datetime(
+        "at",
+        vec![
+            None,
+            Some(1_700_000_000),
+            Some(1_700_000_060),
+            None,
+            Some(1_699_999_990),
+        ],
+    );
+    let encoded = timestamps.delta_encode().unwrap();
+    assert_eq!(encoded.get_value(0), None);
+    assert_eq!(encoded.get_value(1), Some(Value::DateTime(1_700_000_000)));
+    assert_eq!(encoded.get_value(2), Some(Value::DateTime(60)));
+    assert_eq!(encoded.get_value(3), None);
+    assert_eq!(encoded.get_value(4), Some(Value::DateTime(-70)));
+    assert_eq!(encoded.delta_decode().unwrap(), timestamps);
+
+    let extremes = Series::new_i32("x", vec![Some(i32::MIN), Some(i32::MAX), Some(0)]);
+    assert_eq!(
+        extremes.delta_encode().unwrap().delta_decode().unwrap(),
+        extremes
+    );
+
+    let floats = Series::new_f64("f", vec![Some(1.0)]);
+    assert!(matches!(
+        floats.delta_encode(),
+        Err(veloxx::VeloxxError::Unsupported(_))
+    ));
+}
+
+#[test]
+fn test_hash_bucket_is_stable_across_runs() {
+    // Pinned buckets: these must not change between runs or releases.
+    let ids = Series::new_i32("id", vec![Some(1), Some(2), Some(3), Some(100), None]);
+    let buckets = ids.hash_bucket(10).unwrap();
+    assert_eq!(buckets.name(), "id");
+    assert_eq!(buckets.data_type(), DataType::I32);
+    let values: Vec<Option<Value>> = (0..buckets.len()).map(|i| buckets.get_value(i)).collect();
+    assert_eq!(
+        values,
+        vec![
+            Some(Value::I32(5)),
+            Some(Value::I32(0)),
+            Some(Value::I32(1)),
+            Some(Value::I32(0)),
+            None
+        ]
+    );
+
+    let names = Series::new_string(
+        "user",
+        vec![Some("alice".to_string()), Some("bob".to_string())],
+    );
+    let buckets = names.hash_bucket(10).unwrap();
+    assert_eq!(buckets.get_value(0), Some(Value::I32(0)));
+    assert_eq!(buckets.get_value(1), Some(Value::I32(3)));
+
+    assert!(matches!(
+        ids.hash_bucket(0),
+        Err(veloxx::VeloxxError::InvalidOperation(_))
+    ));
+}
+
+#[test]
+fn test_rolling_apply_custom_window_function() {
+    let series = Series::new_i32("x", vec![Some(1), Some(4), None, Some(2), Some(8)]);
+
+    // Weighted window: the newest value counts double, skipping windows with nulls.
+    let weighted = series
+        .rolling_apply(2, |window| Some(window[0]? + 2.0 * window[1]?))
+        .unwrap();
+    assert_eq!(weighted.name(), "x_rolling_apply_2");
+    let values: Vec<Option<Value>> = (0..weighted.len()).map(|i| weighted.get_value(i)).collect();
+    assert_eq!(
+        values,
+        vec![
+            None,
+            Some(Value::F64(9.0)),
+            None,
+            None,
+            Some(Value::F64(18.0))
+        ]
+    );
+
+    // The closure sees nulls, so it can count them.
+    let nulls = series
+        .rolling_apply(3, |window| {
+            Some(window.iter().filter(|v| v.is_none()).count() as f64)
+        })
+        .unwrap();
+    assert_eq!(nulls.get_value(1), None);
+    assert_eq!(nulls.get_value(2), Some(Value::F64(1.0)));
+    assert_eq!(nulls.get_value(4), Some(Value::F64(1.0)));
+
+    assert!(series.rolling_apply(0, |_| None).is_err());
+    assert!(series.rolling_apply(6, |_| None).is_err());
+    let labels = Series::new_string("s", vec![Some("a".to_string())]);
+    assert!(matches!(
+        labels.rolling_apply(1, |_| None),
+        Err(veloxx::VeloxxError::Unsupported(_))
+    ));
+}
+
+#[test]
+fn test_bool_numeric_casts() {
+    let flags = Series::new_bool("flag", vec![Some(true), None, Some(false)]);
+    let numeric = flags.cast(DataType::F64).unwrap();
+    assert_eq!(numeric.name(), "flag");
+    assert_eq!(
+        numeric.get_data_f64().unwrap(),
+        vec![Some(1.0), None, Some(0.0)]
+    );
+
+    let counts = Series::new_i32("orders", vec![Some(0), Some(3), None, Some(-1)]);
+    assert_eq!(
+        counts
+            .cast(DataType::Bool)
+            .unwrap()
+            .get_data_bool()
+            .unwrap(),
+        vec![Some(false), Some(true), None, Some(true)]
+    );
+    let big = Series::new_i64("orders", vec![Some(1 << 40), Some(0)]);
+    assert_eq!(
+        big.cast(DataType::Bool).unwrap().get_data_bool().unwrap(),
+        vec![Some(true), Some(false)]
+    );
+
+    // NaN has no truth value, so it becomes null like any other lossy cast.
+    let scores = Series::new_f64("score", vec![Some(0.25), Some(-0.0), Some(f64::NAN), None]);
+    let (cast, lossy_rows) = scores.try_cast(DataType::Bool).unwrap();
+    assert_eq!(
+        cast.get_data_bool().unwrap(),
+        vec![Some(true), Some(false), None, None]
+    );
+    assert_eq!(lossy_rows, vec![2]);
+}
+
+#[test]
+fn test_approx_eq_tolerates_float_error() {
+    let mean = |values: &[f64]| values.iter().sum::<f64>() / values.len() as f64;
+    let computed = Series::new_f64(
+        "avg",
+        vec![
+            Some(mean(&[0.1, 0.2, 0.3])),
+            None,
+            Some(f64::NAN),
+            Some(f64::INFINITY),
+        ],
+    );
+    let expected = Series::new_f64(
+        "avg",
+        vec![Some(0.2), None, Some(f64::NAN), Some(f64::INFINITY)],
+    );
+    assert!(computed.approx_eq(&expected, 1e-9));
+
+    // A value outside the tolerance, a null against a value, and name or type differences.
+    let shifted = Series::new_f64(
+        "avg",
+        vec![Some(0.21), None, Some(f64::NAN), Some(f64::INFINITY)],
+    );
+    assert!(!computed.approx_eq(&shifted, 1e-9));
+    assert!(computed.approx_eq(&shifted, 0.1));
+    let with_value = Series::new_f64(
+        "avg",
+        vec![Some(0.2), Some(0.0), Some(f64::NAN), Some(f64::INFINITY)],
+    );
+    assert!(!computed.approx_eq(&with_value, 1e-9));
+    let renamed = Series::new_f64(
+        "mean",
+        vec![Some(0.2), None, Some(f64::NAN), Some(f64::INFINITY)],
+    );
+    assert!(!computed.approx_eq(&renamed, 1e-9));
+
+    let ints = Series::new_i32("n", vec![Some(1), None]);
+    assert!(ints.approx_eq(&Series::new_i32("n", vec![Some(1), None]), 0.5));
+    assert!(!ints.approx_eq(&Series::new_i32("n", vec![Some(2), None]), 5.0));
+    assert!(!ints.approx_eq(&Series::new_f64("n", vec![Some(1.0), None]), 0.5));
+}
+
+#[test]
+fn test_value_counts_frequency_table() {
+    let fruit = |names: &[&str]| names.iter().map(|s| Some(s.to_string())).collect();
+    let basket = Series::new_string(
+        "fruit",
+        fruit(&["pear", "apple", "pear", "fig", "apple", "pear", "kiwi"]),
+    );
+    let counts = basket.value_counts().unwrap();
+    assert_eq!(counts.column_names(), vec!["fruit", "count"]);
+    assert_eq!(
+        counts
+            .get_column("fruit")
+            .unwrap()
+            .get_data_string()
+            .unwrap(),
+        fruit(&["pear", "apple", "fig", "kiwi"])
+    );
+    assert_eq!(
+        counts.get_column("count").unwrap().get_data_i32().unwrap(),
+        vec![Some(3), Some(2), Some(1), Some(1)]
+    );
+}
+
+#[test]
+fn test_value_counts_with_nulls() {
+    let grades = Series::new_i32("grade", vec![None, Some(2), None, Some(1), Some(2), None]);
+
+    let dropped = grades.value_counts_opt(true).unwrap();
+    assert_eq!(dropped.row_count(), 2);
+    assert_eq!(
+        dropped.get_column("grade").unwrap().get_data_i32().unwrap(),
+        vec![Some(2), Some(1)]
+    );
+    let default = grades.value_counts().unwrap();
+    assert_eq!(default.get_column("count"), dropped.get_column("count"));
+
+    let kept = grades.value_counts_opt(false).unwrap();
+    assert_eq!(
+        kept.get_column("grade").unwrap().get_data_i32().unwrap(),
+        vec![None, Some(2), Some(1)]
+    );
+    assert_eq!(
+        kept.get_column("count").unwrap().get_data_i32().unwrap(),
+        vec![Some(3), Some(2), Some(1)]
+    );
+
+    let named_count = Series::new_i32("count", vec![Some(1)]);
+    assert!(named_count.value_counts().is_err());
+}
@@ -0,0 +1,85 @@
+use indexmap::IndexMap;
+use veloxx::dataframe::DataFrame;
+use veloxx::series::Series;
+use veloxx::types::Value;
+
+fn store_sales_df() -> DataFrame {
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "store".to_string(),
+        Series::new_string(
+            "store",
+            vec![
+                Some("b".to_string()),
+                Some("a".to_string()),
+                Some("b".to_string()),
+                Some("a".to_string()),
+                Some("b".to_string()),
+            ],
+        ),
+    );
+    columns.insert(
+        "day".to_string(),
+        Series::new_i32("day", vec![Some(3), Some(2), Some(1), Some(1), Some(2)]),
+    );
+    columns.insert(
+        "sales".to_string(),
+        Series::new_f64(
+            "sales",
+            vec![Some(30.0), Some(20.0), Some(10.0), Some(15.0), Some(25.0)],
+        ),
+    );
+    DataFrame::new(columns)
+}
+
+#[test]
+fn test_sort_within_groups_keeps_groups_contiguous() {
+    let df = store_sales_df();
+    let sorted = df
+        .sort_within_groups(&["store"], vec![("day".to_string(), true)])
+        .unwrap();
+
+    assert_eq!(sorted.row_count(), 5);
+    let store = sorted.get_column("store").unwrap();
+    let day = sorted.get_column("day").unwrap();
+
+    // Groups are emitted in order of first appearance: "b" then "a".
+    let stores: Vec<String> = (0..5)
+        .map(|i| store.get_string(i).unwrap().clone())
+        .collect();
+    assert_eq!(stores, vec!["b", "b", "b", "a", "a"]);
+    let days: Vec<Option<Value>> = (0..5).map(|i| day.get_value(i)).collect();
+    assert_eq!(
+        days,
+        vec![
+            Some(Value::I32(1)),
+            Some(Value::I32(2)),
+            Some(Value::I32(3)),
+            Some(Value::I32(1)),
+            Some(Value::I32(2)),
+        ]
+    );
+}
+
+#[test]
+fn test_sort_within_groups_descending() {
+    let df = store_sales_df();
+    let sorted = df
+        .sort_within_groups(&["store"], vec![("sales".to_string(), false)])
+        .unwrap();
+
+    let sales = sorted.get_column("sales").unwrap();
+    let values: Vec<f64> = (0..5).map(|i| sales.get_f64(i).unwrap()).collect();
+    assert_eq!(values, vec![30.0, 25.0, 10.0, 20.0, 15.0]);
+}
+
+#[test]
+fn test_sort_within_groups_missing_column() {
+    let df = store_sales_df();
+    assert!(df
+        .sort_within_groups(&["missing"], vec![("day".to_string(), true)])
+        .is_err());
+    assert!(df
+        .sort_within_groups(&["store"], vec![("missing".to_string(), true)])
+        .is_err());
+}
@@ -113,6 +113,16 @@ fn test_value_debug() {
     assert!(debug_str.contains("42"));
 }
 
+#[test]
+fn test_value_display_hides_variant() {
+    assert_eq!(Value::I32(20).to_string(), "20");
+    assert_eq!(Value::F64(1.0).to_string(), "1.0");
+    assert_eq!(Value::F64(2.5).to_string(), "2.5");
+    assert_eq!(Value::Bool(true).to_string(), "true");
+    assert_eq!(Value::String("raw".to_string()).to_string(), "raw");
+    assert_eq!(Value::Null.to_string(), "null");
+}
+
 #[test]
 fn test_value_ordering() {
     // Test partial ordering for same types
@@ -147,3 +157,93 @@ fn test_value_hash() {
     assert_eq!(map.get(&Value::Null), Some(&"nothing"));
     assert_eq!(map.get(&Value::I32(43)), None);
 }
+
+#[test]
+fn test_value_as_str() {
+    assert_eq!(Value::String("abc".to_string()).as_str(), Some("abc"));
+    assert_eq!(Value::Null.as_str(), None);
+    assert_eq!(Value::I32(1).as_str(), None);
+}
+
+#[test]
+fn test_value_try_from() {
+    assert_eq!(i32::try_from(Value::I32(7)).unwrap(), 7);
+    assert_eq!(f64::try_from(Value::F64(2.5)).unwrap(), 2.5);
+    assert!(bool::try_from(Value::Bool(true)).unwrap());
+    assert_eq!(
+        String::try_from(Value::String("x".to_string())).unwrap(),
+        "x".to_string()
+    );
+    assert_eq!(
+        i64::try_from(Value::DateTime(1_700_000_000)).unwrap(),
+        1_700_000_000
+    );
+
+    let converted: Result<i32, _> = Value::F64(1.0).try_into();
+    assert!(matches!(
+        converted,
+        Err(veloxx::error::VeloxxError::DataTypeMismatch(_))
+    ));
+    assert!(f64::try_from(Value::Null).is_err());
+}
+
+#[test]
+fn test_value_total_order() {
+    use std::cmp::Ordering;
+
+    // Null sorts before everything, including other types and NaN.
+    assert_eq!(Value::Null.cmp(&Value::I32(i32::MIN)), Ordering::Less);
+    assert_eq!(
+        Value::String(String::new()).cmp(&Value::Null),
+        Ordering::Greater
+    );
+    assert_eq!(Value::Null.cmp(&Value::Null), Ordering::Equal);
+    assert!(Value::Null < Value::F64(f64::NAN));
+
+    // Numbers compare across types by magnitude.
+    assert_eq!(Value::I32(3).cmp(&Value::F64(2.5)), Ordering::Greater);
+    assert_eq!(Value::F64(-1.5).cmp(&Value::I64(-1)), Ordering::Less);
+    let big = 1i64 << 53;
+    assert_eq!(
+        Value::I64(big + 1).cmp(&Value::F64(big as f64)),
+        Ordering::Greater
+    );
+    assert_eq!(
+        Value::F64(big as f64).cmp_numeric(&Value::I64(big)),
+        Some(Ordering::Equal)
+    );
+
+    // Numerically equal values that are not `==` still get a strict order.
+    assert_eq!(Value::I32(7).cmp(&Value::I64(7)), Ordering::Less);
+    assert_eq!(Value::F64(-0.0).cmp(&Value::F64(0.0)), Ordering::Less);
+    assert_eq!(
+        Value::I64(7).partial_cmp(&Value::I32(7)),
+        Some(Ordering::Greater)
+    );
+
+    // NaN is the greatest number and equal to itself.
+    assert_eq!(
+        Value::F64(f64::NAN).cmp(&Value::F64(f64::INFINITY)),
+        Ordering::Greater
+    );
+    assert_eq!(Value::I32(0).cmp(&Value::F64(f64::NAN)), Ordering::Less);
+    assert_eq!(
+        Value::F64(f64::NAN).cmp(&Value::F64(f64::NAN)),
+        Ordering::Equal
+    );
+
+    let mut words = vec![
+        Value::String("pear".to_string()),
+        Value::String("Apple".to_string()),
+        Value::String("apple".to_string()),
+    ];
+    words.sort();
+    assert_eq!(
+        words,
+        vec![
+            Value::String("Apple".to_string()),
+            Value::String("apple".to_string()),
+            Value::String("pear".to_string()),
+        ]
+    );
+}
@@ -1,7 +1,7 @@
 use indexmap::IndexMap;
 use veloxx::dataframe::DataFrame;
 use veloxx::series::Series;
-use veloxx::window_functions::{RankingFunction, WindowFunction, WindowSpec};
+use veloxx::window_functions::{RankingFunction, WindowFunc, WindowFunction, WindowSpec};
 
 #[test]
 fn test_rank() {
@@ -116,3 +116,209 @@ fn test_lead() {
     assert_eq!(lead_series.get_value(2), None);
     assert_eq!(lead_series.get_value(3), None);
 }
+
+fn store_sales_df() -> DataFrame {
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "store".to_string(),
+        Series::new_string(
+            "store",
+            vec![
+                Some("a".to_string()),
+                Some("b".to_string()),
+                Some("a".to_string()),
+                Some("b".to_string()),
+                Some("a".to_string()),
+            ],
+        ),
+    );
+    columns.insert(
+        "sales".to_string(),
+        Series::new_i32("sales", vec![Some(1), Some(10), Some(2), None, Some(3)]),
+    );
+    DataFrame::new(columns)
+}
+
+#[test]
+fn test_group_cumsum() {
+    let df = store_sales_df();
+    let running = df.group_cumsum(&["store"], "sales").unwrap();
+    assert_eq!(running.name(), "sales_cumsum");
+    assert_eq!(running.len(), 5);
+    assert_eq!(running.get_value(0), Some(veloxx::types::Value::I32(1)));
+    assert_eq!(running.get_value(1), Some(veloxx::types::Value::I32(10)));
+    assert_eq!(running.get_value(2), Some(veloxx::types::Value::I32(3)));
+    assert_eq!(running.get_value(3), None);
+    assert_eq!(running.get_value(4), Some(veloxx::types::Value::I32(6)));
+}
+
+#[test]
+fn test_group_cumsum_nulls_integer_overflow() {
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "store".to_string(),
+        Series::new_string(
+            "store",
+            vec![
+                Some("a".to_string()),
+                Some("a".to_string()),
+                Some("b".to_string()),
+                Some("a".to_string()),
+            ],
+        ),
+    );
+    columns.insert(
+        "sales".to_string(),
+        Series::new_i32("sales", vec![Some(i32::MAX), Some(1), Some(5), Some(-1)]),
+    );
+    columns.insert(
+        "views".to_string(),
+        Series::new_i64("views", vec![Some(i64::MAX - 1), Some(1), Some(7), Some(1)]),
+    );
+    let df = DataFrame::new(columns);
+
+    let sales = df.group_cumsum(&["store"], "sales").unwrap();
+    assert_eq!(
+        sales.get_value(0),
+        Some(veloxx::types::Value::I32(i32::MAX))
+    );
+    assert_eq!(sales.get_value(1), None);
+    assert_eq!(sales.get_value(2), Some(veloxx::types::Value::I32(5)));
+    assert_eq!(sales.get_value(3), None);
+
+    let views = df.group_cumsum(&["store"], "views").unwrap();
+    assert_eq!(
+        views.get_value(1),
+        Some(veloxx::types::Value::I64(i64::MAX))
+    );
+    assert_eq!(views.get_value(2), Some(veloxx::types::Value::I64(7)));
+    assert_eq!(views.get_value(3), None);
+}
+
+#[test]
+fn test_group_rolling_mean() {
+    let df = store_sales_df();
+    let rolling = df.group_rolling_mean(&["store"], "sales", 2).unwrap();
+    assert_eq!(rolling.name(), "sales_rolling_mean_2");
+    assert_eq!(rolling.get_value(0), None);
+    assert_eq!(rolling.get_value(1), None);
+    assert_eq!(rolling.get_value(2), Some(veloxx::types::Value::F64(1.5)));
+    // Window for store "b" is [10, null], so only the valid value is averaged.
+    assert_eq!(rolling.get_value(3), Some(veloxx::types::Value::F64(10.0)));
+    assert_eq!(rolling.get_value(4), Some(veloxx::types::Value::F64(2.5)));
+
+    assert!(df.group_rolling_mean(&["store"], "sales", 0).is_err());
+    assert!(df.group_rolling_mean(&["missing"], "sales", 2).is_err());
+}
+
+#[test]
+fn test_window_rank_over_partitions() {
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "region".to_string(),
+        Series::new_string(
+            "region",
+            vec![
+                Some("N".to_string()),
+                Some("S".to_string()),
+                Some("N".to_string()),
+                Some("N".to_string()),
+                Some("S".to_string()),
+                Some("N".to_string()),
+            ],
+        ),
+    );
+    columns.insert(
+        "sales".to_string(),
+        Series::new_i32(
+            "sales",
+            vec![Some(30), Some(5), Some(20), Some(30), Some(7), Some(10)],
+        ),
+    );
+    let df = DataFrame::new(columns);
+    let order_by = vec![("sales".to_string(), false)];
+
+    let values = |series: &Series| -> Vec<Option<veloxx::types::Value>> {
+        (0..series.len()).map(|i| series.get_value(i)).collect()
+    };
+    let i32s = |v: Vec<i32>| -> Vec<Option<veloxx::types::Value>> {
+        v.into_iter()
+            .map(|x| Some(veloxx::types::Value::I32(x)))
+            .collect()
+    };
+
+    let row_number = df
+        .window_rank(&["region"], order_by.clone(), WindowFunc::RowNumber)
+        .unwrap();
+    assert_eq!(row_number.name(), "row_number");
+    assert_eq!(values(&row_number), i32s(vec![1, 2, 3, 2, 1, 4]));
+
+    let rank = df
+        .window_rank(&["region"], order_by.clone(), WindowFunc::Rank)
+        .unwrap();
+    assert_eq!(values(&rank), i32s(vec![1, 2, 3, 1, 1, 4]));
+
+    let dense_rank = df
+        .window_rank(&["region"], order_by, WindowFunc::DenseRank)
+        .unwrap();
+    assert_eq!(values(&dense_rank), i32s(vec![1, 2, 2, 1, 1, 3]));
+}
+
+#[test]
+fn test_window_lag_lead_over_partitions() {
+    let mut columns = IndexMap::new();
+    columns.insert(
+        "customer".to_string(),
+        Series::new_string(
+            "customer",
+            vec![
+                Some("a".to_string()),
+                Some("b".to_string()),
+                Some("a".to_string()),
+                Some("a".to_string()),
+                Some("b".to_string()),
+            ],
+        ),
+    );
+    columns.insert(
+        "day".to_string(),
+        Series::new_i32("day", vec![Some(9), Some(4), Some(1), Some(5), Some(7)]),
+    );
+    let df = DataFrame::new(columns);
+    let order_by = vec![("day".to_string(), true)];
+
+    let lag = df
+        .window_lag(&["customer"], order_by.clone(), "day", 1)
+        .unwrap();
+    assert_eq!(lag.name(), "lag_day_1");
+    assert_eq!(lag.get_value(0), Some(veloxx::types::Value::I32(5)));
+    assert_eq!(lag.get_value(1), None);
+    assert_eq!(lag.get_value(2), None);
+    assert_eq!(lag.get_value(3), Some(veloxx::types::Value::I32(1)));
+    assert_eq!(lag.get_value(4), Some(veloxx::types::Value::I32(4)));
+
+    let lead = df.window_lead(&["customer"], order_by, "day", 2).unwrap();
+    assert_eq!(lead.name(), "lead_day_2");
+    assert_eq!(lead.get_value(0), None);
+    assert_eq!(lead.get_value(1), None);
+    assert_eq!(lead.get_value(2), Some(veloxx::types::Value::I32(9)));
+    assert_eq!(lead.get_value(3), None);
+    assert_eq!(lead.get_value(4), None);
+}
+
+#[test]
+fn test_group_cumcount() {
+    let df = store_sales_df();
+    let cumcount = df.group_cumcount(&["store"]).unwrap();
+    assert_eq!(cumcount.name(), "cumcount");
+    let values: Vec<Option<veloxx::types::Value>> =
+        (0..cumcount.len()).map(|i| cumcount.get_value(i)).collect();
+    assert_eq!(
+        values,
+        vec![0, 0, 1, 1, 2]
+            .into_iter()
+            .map(|x| Some(veloxx::types::Value::I32(x)))
+            .collect::<Vec<_>>()
+    );
+    assert!(df.group_cumcount(&["missing"]).is_err());
+}